@@ -0,0 +1,90 @@
+use crate::kanban::models::App;
+use chrono::Local;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The changelog that sits next to a board file, e.g. `work.txt` -> `work.log`
+pub fn log_path(board_path: &str) -> PathBuf {
+    Path::new(board_path).with_extension("log")
+}
+
+/// Append one timestamped entry to a changelog file
+pub fn append_entry(log_path: &Path, timestamp: &str, description: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)?;
+    writeln!(file, "[{}] {}", timestamp, description)
+}
+
+impl App {
+    /// Record a board edit in the changelog, if enabled in the config.
+    /// Logging is best effort: a failed write never interrupts editing.
+    pub fn log_change(&self, description: &str) {
+        if !self.config.changelog {
+            return;
+        }
+        if let Some(path) = &self.file_path {
+            let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            let _ = append_entry(&log_path(path), &timestamp, description);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::{Column, Task};
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_move_appends_log_line() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_changelog_board.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        let log_file = log_path(&file_path);
+        let _ = fs::remove_file(&log_file);
+
+        let mut app = App::new("Changelog Board");
+        app.file_path = Some(file_path.clone());
+        app.config.changelog = true;
+        app.columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![Task {
+                    id: 0,
+                    title: "Write docs".to_string(),
+                    description: None,
+                    priority: Some(Task::default_priority()),
+                    completed_at: None,
+                    tags: Vec::new(),
+                    due: None,
+                    created: None,
+                    focus_minutes: 0,
+                }],
+                selected_task: Some(0),
+                wip_limit: None,
+            },
+            Column {
+                title: "Done".to_string(),
+                tasks: Vec::new(),
+                selected_task: None,
+                wip_limit: None,
+            },
+        ];
+        app.active_column = 0;
+
+        app.move_task_to_column(1);
+
+        let log = fs::read_to_string(&log_file).unwrap();
+        let lines: Vec<&str> = log.lines().collect();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with('['));
+        assert!(lines[0].ends_with("] Moved task 'Write docs' from 'To Do' to 'Done'"));
+
+        fs::remove_file(&log_file).unwrap();
+        let _ = fs::remove_file(&file_path);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::kanban::models::{App, Column};
+use crate::ops::crud::PriorityFormula;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Clipboard tools tried in order; the first one that runs wins
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Put `text` on the system clipboard using whichever clipboard tool is
+/// installed (pbcopy, wl-copy, xclip, xsel or clip.exe).
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    for (program, args) in CLIPBOARD_COMMANDS {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        let written = child
+            .stdin
+            .take()
+            .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+        if child.wait().is_ok_and(|status| status.success()) && written {
+            return Ok(());
+        }
+    }
+    Err(no_clipboard_message())
+}
+
+// The error when no clipboard tool ran, naming every tool tried
+fn no_clipboard_message() -> String {
+    let tried: Vec<&str> = CLIPBOARD_COMMANDS
+        .iter()
+        .map(|(program, _)| *program)
+        .collect();
+    format!("No clipboard tool found (tried {})", tried.join(", "))
+}
+
+/// A column's tasks as a newline-separated list of `- title` lines, in
+/// board order. With `with_priority` each scored task gets its priority
+/// under `formula` appended.
+pub fn column_as_list(column: &Column, with_priority: bool, formula: PriorityFormula) -> String {
+    column
+        .tasks
+        .iter()
+        .map(|task| match task.computed_priority(formula) {
+            Some(score) if with_priority => format!("- {} ({:.2})", task.title, score),
+            _ => format!("- {}", task.title),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl App {
+    /// Copy the active column's tasks to the clipboard as a list
+    pub fn copy_column_to_clipboard(&mut self, with_priority: bool) {
+        let Some(column) = self.columns.get(self.active_column) else {
+            return;
+        };
+        if column.tasks.is_empty() {
+            self.status_message = Some(format!("'{}' has no tasks to copy", column.title));
+            return;
+        }
+        let text = column_as_list(column, with_priority, self.config.priority_formula);
+        self.status_message = Some(match copy_to_clipboard(&text) {
+            Ok(()) => format!(
+                "Copied {} task(s) from '{}'",
+                column.tasks.len(),
+                column.title
+            ),
+            Err(message) => message,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::Task;
+    use crate::ops::crud::Priority;
+
+    #[test]
+    fn test_no_clipboard_message_names_every_tool() {
+        assert_eq!(
+            no_clipboard_message(),
+            "No clipboard tool found (tried pbcopy, wl-copy, xclip, xsel, clip.exe)"
+        );
+    }
+
+    fn task(title: &str, priority: Option<Priority>) -> Task {
+        Task {
+            id: 0,
+            title: title.to_string(),
+            description: None,
+            priority,
+            completed_at: None,
+            tags: Vec::new(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn test_column_as_list() {
+        let scored = task(
+            "Fix crash",
+            Some(Priority {
+                impact: 8,
+                urgency: 6,
+                effort: 2,
+            }),
+        );
+        let formula = PriorityFormula::default();
+        let score = scored.computed_priority(formula).unwrap();
+        let column = Column {
+            title: "Doing".to_string(),
+            tasks: vec![scored, task("Write docs", None)],
+            selected_task: None,
+            wip_limit: None,
+        };
+
+        assert_eq!(
+            column_as_list(&column, false, formula),
+            "- Fix crash\n- Write docs"
+        );
+        assert_eq!(
+            column_as_list(&column, true, formula),
+            format!("- Fix crash ({:.2})\n- Write docs", score)
+        );
+    }
+}
@@ -0,0 +1,765 @@
+use crate::ops::crud::PriorityFormula;
+use ratatui::style::{Color, Modifier, Style};
+use std::fs;
+use std::path::Path;
+
+/// Name of the optional config file inside KANBAN_DIR.
+pub const CONFIG_FILE_NAME: &str = "kantui.conf";
+
+/// Default jump-label alphabet: a-z without the easily confused 'l' and 'o'.
+pub const DEFAULT_JUMP_LABELS: &str = "abcdefghijkmnpqrstuvwxyz";
+
+/// How a column scrolls to keep the selected task visible.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScrollMode {
+    /// Scroll only once the selection reaches the edge of the column
+    Edge,
+    /// Keep the selection in the middle of the column
+    Center,
+}
+
+impl ScrollMode {
+    fn parse(value: &str) -> Option<ScrollMode> {
+        match value.to_lowercase().as_str() {
+            "edge" => Some(ScrollMode::Edge),
+            "center" | "centered" => Some(ScrollMode::Center),
+            _ => None,
+        }
+    }
+}
+
+/// What happens when a task is moved into a column at its WIP limit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WipPolicy {
+    /// Refuse the move
+    Block,
+    /// Ask before moving
+    Confirm,
+    /// Move without asking
+    Allow,
+}
+
+/// How the board picker orders boards.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PickerSort {
+    /// By name
+    Alpha,
+    /// Most recently opened first, then most recently modified
+    Recent,
+    /// The order set by moving boards in the picker, then by name
+    Custom,
+}
+
+impl PickerSort {
+    fn parse(value: &str) -> Option<PickerSort> {
+        match value.to_lowercase().as_str() {
+            "alpha" | "alphabetical" | "name" => Some(PickerSort::Alpha),
+            "recent" => Some(PickerSort::Recent),
+            "custom" | "manual" => Some(PickerSort::Custom),
+            _ => None,
+        }
+    }
+}
+
+/// Whether RGB theme colors are drawn as they are or mapped to the 16 ANSI
+/// colors for terminals that can't show them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorMode {
+    /// Decide from `COLORTERM` and `TERM`
+    Auto,
+    /// Always draw RGB colors
+    TrueColor,
+    /// Always map to the 16 ANSI colors
+    Ansi16,
+}
+
+impl ColorMode {
+    fn parse(value: &str) -> Option<ColorMode> {
+        match value.to_lowercase().as_str() {
+            "auto" => Some(ColorMode::Auto),
+            "truecolor" | "24bit" | "rgb" => Some(ColorMode::TrueColor),
+            "ansi16" | "16" | "basic" => Some(ColorMode::Ansi16),
+            _ => None,
+        }
+    }
+}
+
+/// What Enter does to the selected task in Normal mode.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnterAction {
+    /// Show the task's details
+    Detail,
+    /// Start renaming the task
+    Rename,
+    /// Move the task into the done column, or back out of it
+    ToggleDone,
+    /// Pick a column to move the task to
+    Move,
+}
+
+impl EnterAction {
+    fn parse(value: &str) -> Option<EnterAction> {
+        match value.to_lowercase().replace(['-', '_'], "").as_str() {
+            "detail" | "details" => Some(EnterAction::Detail),
+            "rename" | "edit" => Some(EnterAction::Rename),
+            "toggledone" | "done" => Some(EnterAction::ToggleDone),
+            "move" => Some(EnterAction::Move),
+            _ => None,
+        }
+    }
+}
+
+impl WipPolicy {
+    fn parse(value: &str) -> Option<WipPolicy> {
+        match value.to_lowercase().as_str() {
+            "block" => Some(WipPolicy::Block),
+            "confirm" => Some(WipPolicy::Confirm),
+            "allow" => Some(WipPolicy::Allow),
+            _ => None,
+        }
+    }
+}
+
+/// Which destructive actions ask "are you sure?" first. Set in the
+/// `[confirmations]` section of the config file.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Confirmations {
+    /// Deleting the selected task with `dt` or Delete
+    pub delete_task: bool,
+    /// Deleting the active column with `dc`
+    pub delete_column: bool,
+    /// Removing every task from the active column with `dx`
+    pub clear_column: bool,
+    /// Quitting while there are changes that couldn't be saved
+    pub quit_unsaved: bool,
+}
+
+impl Default for Confirmations {
+    fn default() -> Self {
+        Confirmations {
+            delete_task: true,
+            delete_column: true,
+            clear_column: true,
+            quit_unsaved: true,
+        }
+    }
+}
+
+/// How the selected task is drawn. Set in the `[selection]` section of the
+/// config file: `fg` and `bg` take a color name, `#rrggbb` or a 0-255 index,
+/// and `modifier` a comma-separated list such as `bold, underlined`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectionStyle {
+    pub fg: Color,
+    pub bg: Color,
+    pub modifier: Modifier,
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        SelectionStyle {
+            fg: Color::White,
+            bg: Color::Blue,
+            modifier: Modifier::BOLD,
+        }
+    }
+}
+
+impl SelectionStyle {
+    pub fn style(&self) -> Style {
+        Style::default()
+            .fg(self.fg)
+            .bg(self.bg)
+            .add_modifier(self.modifier)
+    }
+}
+
+/// User-tunable settings.
+///
+/// The config file is a list of `key = value` lines; `#` starts a comment.
+/// A `[section]` line prefixes the keys after it, so `delete_task = off`
+/// under `[confirmations]` is the same as `confirmations.delete_task = off`.
+/// Unknown keys and unparsable values are ignored so a typo never keeps the
+/// app from starting.
+pub struct Config {
+    /// Maximum number of undo snapshots kept in memory
+    pub undo_depth: usize,
+    /// Show the colored priority dot above each task
+    pub show_priority_dot: bool,
+    /// Show a three-cell impact/urgency/effort bar above each task
+    pub show_priority_bar: bool,
+    /// Percentage of a column's inner width used for wrapping task text
+    pub task_text_width: u8,
+    /// Show the priority color legend in the title bar
+    pub show_legend: bool,
+    /// Append the `(i/total)` index to each column title
+    pub show_column_index: bool,
+    /// How columns scroll to follow the selected task
+    pub scroll_mode: ScrollMode,
+    /// Characters used as jump labels, in assignment order
+    pub jump_labels: String,
+    /// Columns a newly created board starts with
+    pub seed_columns: Vec<String>,
+    /// Column titles (case-insensitive) whose tasks count as finished
+    pub done_columns: Vec<String>,
+    /// Reload the board when its file is changed by another program
+    pub live_reload: bool,
+    /// How moves into a full column are handled
+    pub wip_policy: WipPolicy,
+    /// What Enter does to the selected task
+    pub enter_action: EnterAction,
+    /// Whether to draw RGB colors or their nearest ANSI colors
+    pub color_mode: ColorMode,
+    /// How boards are ordered in the board picker
+    pub picker_sort: PickerSort,
+    /// Append each board edit to a `<board>.log` file next to the board
+    pub changelog: bool,
+    /// Journal edits to a `<board>.journal` file until they are saved, so
+    /// they can be replayed if the program dies before the save finishes.
+    /// Only adding, moving, deleting and renaming tasks are journaled; other
+    /// edits (column changes, priorities, tags, splits, imports, trash
+    /// restores and WIP limits) are lost if the save never happens
+    pub journal: bool,
+    /// Stay in add mode after adding a task, for entering several in a row
+    pub sticky_add: bool,
+    /// Rows taken by the board title bar (1 to 3)
+    pub title_height: u16,
+    /// Blank columns on each side of a board column
+    pub column_margin: u16,
+    /// Draw a border around the active column
+    pub active_column_border: bool,
+    /// Draw each task card inside its own bordered box
+    pub boxed_tasks: bool,
+    /// Draw each task as a single row (priority dot and title) instead of a card
+    pub compact_tasks: bool,
+    /// Blank lines between task cards (0 to 5)
+    pub task_spacing: u16,
+    /// Center a column's tasks vertically when they don't fill the column
+    pub center_short_columns: bool,
+    /// Show a bar under each column title with how full the column is
+    pub show_load_bar: bool,
+    /// Moving past the last column or task goes round to the first
+    pub wrap_navigation: bool,
+    /// Use plain ASCII in place of emoji and symbols, for limited terminals
+    pub ascii_only: bool,
+    /// After moving a task, make its new column active with the task selected
+    pub follow_moved_task: bool,
+    /// Which destructive actions ask for confirmation
+    pub confirmations: Confirmations,
+    /// Colors and modifiers of the selected task
+    pub selection: SelectionStyle,
+    /// Days deleted tasks and columns stay in the trash (0 keeps them forever)
+    pub trash_retention_days: u32,
+    /// How a task's impact/urgency/effort become its priority score
+    pub priority_formula: PriorityFormula,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            undo_depth: 50,
+            show_priority_dot: true,
+            show_priority_bar: false,
+            task_text_width: 100,
+            show_legend: true,
+            show_column_index: true,
+            jump_labels: DEFAULT_JUMP_LABELS.to_string(),
+            scroll_mode: ScrollMode::Edge,
+            seed_columns: vec!["To Do".to_string()],
+            done_columns: vec!["Done".to_string()],
+            live_reload: true,
+            wip_policy: WipPolicy::Confirm,
+            enter_action: EnterAction::Detail,
+            color_mode: ColorMode::Auto,
+            picker_sort: PickerSort::Custom,
+            changelog: false,
+            journal: false,
+            sticky_add: false,
+            title_height: 3,
+            column_margin: 2,
+            active_column_border: true,
+            boxed_tasks: false,
+            compact_tasks: false,
+            task_spacing: 1,
+            center_short_columns: false,
+            show_load_bar: false,
+            wrap_navigation: false,
+            ascii_only: false,
+            follow_moved_task: false,
+            confirmations: Confirmations::default(),
+            selection: SelectionStyle::default(),
+            trash_retention_days: 30,
+            priority_formula: PriorityFormula::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the config from KANBAN_DIR, falling back to defaults
+    pub fn load() -> Config {
+        match std::env::var("KANBAN_DIR") {
+            Ok(dir) => Config::load_from_dir(Path::new(&dir)),
+            Err(_) => Config::default(),
+        }
+    }
+
+    /// Load the config file from a specific directory
+    pub fn load_from_dir(dir: &Path) -> Config {
+        match fs::read_to_string(dir.join(CONFIG_FILE_NAME)) {
+            Ok(contents) => Config::parse(&contents),
+            Err(_) => Config::default(),
+        }
+    }
+
+    /// Parse config file contents on top of the defaults
+    pub fn parse(contents: &str) -> Config {
+        let mut config = Config::default();
+        let mut section = String::new();
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                section = name.trim().to_lowercase();
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                let key = key.trim();
+                if section.is_empty() {
+                    config.apply(key, value.trim());
+                } else {
+                    config.apply(&format!("{}.{}", section, key), value.trim());
+                }
+            }
+        }
+
+        config
+    }
+
+    // Apply a single setting, ignoring anything we don't understand
+    fn apply(&mut self, key: &str, value: &str) {
+        match key {
+            "undo_depth" => self.undo_depth = value.parse().unwrap_or(self.undo_depth),
+            "trash_retention_days" => {
+                self.trash_retention_days = value.parse().unwrap_or(self.trash_retention_days)
+            }
+            "show_priority_dot" => {
+                self.show_priority_dot = parse_bool(value).unwrap_or(self.show_priority_dot)
+            }
+            "task_text_width" => {
+                if let Ok(percent @ 1..=100) = value.parse() {
+                    self.task_text_width = percent;
+                }
+            }
+            "show_priority_bar" => {
+                self.show_priority_bar = parse_bool(value).unwrap_or(self.show_priority_bar)
+            }
+            "show_legend" => self.show_legend = parse_bool(value).unwrap_or(self.show_legend),
+            "show_column_index" => {
+                self.show_column_index = parse_bool(value).unwrap_or(self.show_column_index)
+            }
+            "jump_labels" if is_valid_label_alphabet(value) => self.jump_labels = value.to_string(),
+            "scroll_mode" => {
+                self.scroll_mode = ScrollMode::parse(value).unwrap_or(self.scroll_mode)
+            }
+            "wip_policy" => self.wip_policy = WipPolicy::parse(value).unwrap_or(self.wip_policy),
+            "picker_sort" => {
+                self.picker_sort = PickerSort::parse(value).unwrap_or(self.picker_sort)
+            }
+            "color_mode" => self.color_mode = ColorMode::parse(value).unwrap_or(self.color_mode),
+            "enter_action" => {
+                self.enter_action = EnterAction::parse(value).unwrap_or(self.enter_action)
+            }
+            "priority_formula" => {
+                self.priority_formula =
+                    PriorityFormula::parse(value).unwrap_or(self.priority_formula)
+            }
+            "seed_columns" => {
+                let names = parse_list(value);
+                if !names.is_empty() {
+                    self.seed_columns = names;
+                }
+            }
+            "done_columns" => self.done_columns = parse_list(value),
+            "live_reload" => self.live_reload = parse_bool(value).unwrap_or(self.live_reload),
+            "changelog" => self.changelog = parse_bool(value).unwrap_or(self.changelog),
+            "journal" => self.journal = parse_bool(value).unwrap_or(self.journal),
+            "sticky_add" => self.sticky_add = parse_bool(value).unwrap_or(self.sticky_add),
+            "active_column_border" => {
+                self.active_column_border = parse_bool(value).unwrap_or(self.active_column_border)
+            }
+            "boxed_tasks" => self.boxed_tasks = parse_bool(value).unwrap_or(self.boxed_tasks),
+            "compact_tasks" => self.compact_tasks = parse_bool(value).unwrap_or(self.compact_tasks),
+            "center_short_columns" => {
+                self.center_short_columns = parse_bool(value).unwrap_or(self.center_short_columns)
+            }
+            "show_load_bar" => self.show_load_bar = parse_bool(value).unwrap_or(self.show_load_bar),
+            "wrap_navigation" => {
+                self.wrap_navigation = parse_bool(value).unwrap_or(self.wrap_navigation)
+            }
+            "ascii_only" => self.ascii_only = parse_bool(value).unwrap_or(self.ascii_only),
+            "follow_moved_task" => {
+                self.follow_moved_task = parse_bool(value).unwrap_or(self.follow_moved_task)
+            }
+            "confirmations.delete_task" => {
+                let confirm = &mut self.confirmations.delete_task;
+                *confirm = parse_bool(value).unwrap_or(*confirm)
+            }
+            "confirmations.delete_column" => {
+                let confirm = &mut self.confirmations.delete_column;
+                *confirm = parse_bool(value).unwrap_or(*confirm)
+            }
+            "confirmations.clear_column" => {
+                let confirm = &mut self.confirmations.clear_column;
+                *confirm = parse_bool(value).unwrap_or(*confirm)
+            }
+            "confirmations.quit_unsaved" => {
+                let confirm = &mut self.confirmations.quit_unsaved;
+                *confirm = parse_bool(value).unwrap_or(*confirm)
+            }
+            "selection.fg" => {
+                if let Ok(color) = value.parse() {
+                    self.selection.fg = color;
+                }
+            }
+            "selection.bg" => {
+                if let Ok(color) = value.parse() {
+                    self.selection.bg = color;
+                }
+            }
+            "selection.modifier" => {
+                if let Some(modifier) = parse_modifier(value) {
+                    self.selection.modifier = modifier;
+                }
+            }
+            "title_height" => {
+                if let Ok(rows @ 1..=3) = value.parse() {
+                    self.title_height = rows;
+                }
+            }
+            "task_spacing" => {
+                if let Ok(lines @ 0..=5) = value.parse() {
+                    self.task_spacing = lines;
+                }
+            }
+            "column_margin" => {
+                if let Ok(margin @ 0..=10) = value.parse() {
+                    self.column_margin = margin;
+                }
+            }
+            _ => {} // Unknown keys are ignored
+        }
+    }
+}
+
+// A label alphabet needs at least one character and no repeats or spaces
+fn is_valid_label_alphabet(value: &str) -> bool {
+    let mut seen = std::collections::HashSet::new();
+    !value.is_empty() && value.chars().all(|c| !c.is_whitespace() && seen.insert(c))
+}
+
+// Split a comma-separated setting, dropping empty entries
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+// Accept the usual spellings of a boolean setting
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Some(true),
+        "false" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+// Combine comma-separated text modifiers; `none` clears them all
+fn parse_modifier(value: &str) -> Option<Modifier> {
+    let mut modifier = Modifier::empty();
+    for name in parse_list(value) {
+        modifier |= match name.to_lowercase().as_str() {
+            "none" => Modifier::empty(),
+            "bold" => Modifier::BOLD,
+            "dim" => Modifier::DIM,
+            "italic" => Modifier::ITALIC,
+            "underlined" => Modifier::UNDERLINED,
+            "reversed" => Modifier::REVERSED,
+            _ => return None,
+        };
+    }
+    Some(modifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse("# comment\n\nundo_depth = 10\nunknown = 1\n");
+        assert_eq!(config.undo_depth, 10);
+
+        let config = Config::parse("undo_depth = lots");
+        assert_eq!(config.undo_depth, 50);
+
+        assert_eq!(Config::default().trash_retention_days, 30);
+        let config = Config::parse("trash_retention_days = 7");
+        assert_eq!(config.trash_retention_days, 7);
+    }
+
+    #[test]
+    fn test_parse_bool_settings() {
+        assert!(Config::default().show_priority_dot);
+        assert!(!Config::parse("show_priority_dot = off").show_priority_dot);
+        assert!(!Config::parse("show_priority_dot = false").show_priority_dot);
+        assert!(Config::parse("show_priority_dot = maybe").show_priority_dot);
+        assert!(!Config::parse("show_legend = no").show_legend);
+        assert!(!Config::default().show_priority_bar);
+        assert!(Config::parse("show_priority_bar = on").show_priority_bar);
+        assert!(Config::default().show_column_index);
+        assert!(!Config::parse("show_column_index = no").show_column_index);
+        assert!(!Config::parse("live_reload = off").live_reload);
+        assert!(!Config::default().changelog);
+        assert!(Config::parse("changelog = yes").changelog);
+        assert!(!Config::default().journal);
+        assert!(Config::parse("journal = on").journal);
+        assert!(!Config::default().sticky_add);
+        assert!(Config::parse("sticky_add = on").sticky_add);
+        assert!(Config::default().active_column_border);
+        assert!(!Config::parse("active_column_border = off").active_column_border);
+        assert!(!Config::default().boxed_tasks);
+        assert!(Config::parse("boxed_tasks = yes").boxed_tasks);
+        assert!(!Config::default().compact_tasks);
+        assert!(Config::parse("compact_tasks = on").compact_tasks);
+        assert!(!Config::default().center_short_columns);
+        assert!(Config::parse("center_short_columns = on").center_short_columns);
+        assert!(!Config::default().show_load_bar);
+        assert!(Config::parse("show_load_bar = on").show_load_bar);
+        assert!(!Config::default().wrap_navigation);
+        assert!(Config::parse("wrap_navigation = true").wrap_navigation);
+        assert!(!Config::default().ascii_only);
+        assert!(Config::parse("ascii_only = yes").ascii_only);
+        assert!(!Config::default().follow_moved_task);
+        assert!(Config::parse("follow_moved_task = on").follow_moved_task);
+    }
+
+    #[test]
+    fn test_parse_selection_section() {
+        assert_eq!(Config::default().selection, SelectionStyle::default());
+
+        let config = Config::parse(
+            "[selection]\n\
+             fg = black\n\
+             bg = #ffd75f\n\
+             modifier = italic, underlined\n",
+        );
+        assert_eq!(config.selection.fg, Color::Black);
+        assert_eq!(config.selection.bg, Color::Rgb(255, 215, 95));
+        assert_eq!(
+            config.selection.modifier,
+            Modifier::ITALIC | Modifier::UNDERLINED
+        );
+
+        // Bad values keep the defaults
+        let config = Config::parse("selection.bg = sky\nselection.modifier = shiny");
+        assert_eq!(config.selection, SelectionStyle::default());
+        let config = Config::parse("selection.modifier = none");
+        assert_eq!(config.selection.modifier, Modifier::empty());
+    }
+
+    #[test]
+    fn test_parse_confirmations_section() {
+        assert_eq!(Config::default().confirmations, Confirmations::default());
+
+        let config = Config::parse(
+            "show_legend = off\n\
+             [confirmations]\n\
+             delete_task = off\n\
+             clear_column = no\n\
+             show_legend = on\n",
+        );
+        assert!(!config.confirmations.delete_task);
+        assert!(!config.confirmations.clear_column);
+        assert!(config.confirmations.delete_column);
+        assert!(config.confirmations.quit_unsaved);
+        // Keys inside a section don't leak into the top level
+        assert!(!config.show_legend);
+
+        let config = Config::parse("confirmations.quit_unsaved = false");
+        assert!(!config.confirmations.quit_unsaved);
+    }
+
+    #[test]
+    fn test_parse_task_text_width() {
+        assert_eq!(Config::default().task_text_width, 100);
+        assert_eq!(Config::parse("task_text_width = 80").task_text_width, 80);
+        assert_eq!(Config::parse("task_text_width = 0").task_text_width, 100);
+        assert_eq!(Config::parse("task_text_width = 150").task_text_width, 100);
+    }
+
+    #[test]
+    fn test_parse_layout_settings() {
+        let config = Config::default();
+        assert_eq!((config.title_height, config.column_margin), (3, 2));
+
+        let config = Config::parse("title_height = 1\ncolumn_margin = 0");
+        assert_eq!((config.title_height, config.column_margin), (1, 0));
+
+        let config = Config::parse("title_height = 0\ncolumn_margin = 50");
+        assert_eq!((config.title_height, config.column_margin), (3, 2));
+
+        assert_eq!(Config::default().task_spacing, 1);
+        assert_eq!(Config::parse("task_spacing = 0").task_spacing, 0);
+        assert_eq!(Config::parse("task_spacing = 9").task_spacing, 1);
+    }
+
+    #[test]
+    fn test_parse_done_columns() {
+        assert_eq!(Config::default().done_columns, vec!["Done"]);
+        assert_eq!(
+            Config::parse("done_columns = Done, Shipped ,").done_columns,
+            vec!["Done", "Shipped"]
+        );
+    }
+
+    #[test]
+    fn test_parse_seed_columns() {
+        assert_eq!(Config::default().seed_columns, vec!["To Do"]);
+        assert_eq!(
+            Config::parse("seed_columns = Backlog, Doing, Done").seed_columns,
+            vec!["Backlog", "Doing", "Done"]
+        );
+        // An empty list keeps the default
+        assert_eq!(
+            Config::parse("seed_columns = ,").seed_columns,
+            vec!["To Do"]
+        );
+    }
+
+    #[test]
+    fn test_parse_jump_labels() {
+        assert_eq!(Config::default().jump_labels, DEFAULT_JUMP_LABELS);
+        assert_eq!(
+            Config::parse("jump_labels = asdfghjkl").jump_labels,
+            "asdfghjkl"
+        );
+        // Duplicates fall back to the default
+        assert_eq!(
+            Config::parse("jump_labels = asdfa").jump_labels,
+            DEFAULT_JUMP_LABELS
+        );
+        assert_eq!(
+            Config::parse("jump_labels =").jump_labels,
+            DEFAULT_JUMP_LABELS
+        );
+    }
+
+    #[test]
+    fn test_parse_scroll_mode() {
+        assert_eq!(Config::default().scroll_mode, ScrollMode::Edge);
+        assert_eq!(
+            Config::parse("scroll_mode = center").scroll_mode,
+            ScrollMode::Center
+        );
+        assert_eq!(
+            Config::parse("scroll_mode = sideways").scroll_mode,
+            ScrollMode::Edge
+        );
+    }
+
+    #[test]
+    fn test_parse_picker_sort() {
+        assert_eq!(Config::default().picker_sort, PickerSort::Custom);
+        assert_eq!(
+            Config::parse("picker_sort = recent").picker_sort,
+            PickerSort::Recent
+        );
+        assert_eq!(
+            Config::parse("picker_sort = Alpha").picker_sort,
+            PickerSort::Alpha
+        );
+        assert_eq!(
+            Config::parse("picker_sort = random").picker_sort,
+            PickerSort::Custom
+        );
+    }
+
+    #[test]
+    fn test_parse_color_mode() {
+        assert_eq!(Config::default().color_mode, ColorMode::Auto);
+        assert_eq!(
+            Config::parse("color_mode = ANSI16").color_mode,
+            ColorMode::Ansi16
+        );
+        assert_eq!(
+            Config::parse("color_mode = truecolor").color_mode,
+            ColorMode::TrueColor
+        );
+        assert_eq!(
+            Config::parse("color_mode = rainbow").color_mode,
+            ColorMode::Auto
+        );
+    }
+
+    #[test]
+    fn test_parse_enter_action() {
+        assert_eq!(Config::default().enter_action, EnterAction::Detail);
+        assert_eq!(
+            Config::parse("enter_action = toggle_done").enter_action,
+            EnterAction::ToggleDone
+        );
+        assert_eq!(
+            Config::parse("enter_action = Rename").enter_action,
+            EnterAction::Rename
+        );
+        assert_eq!(
+            Config::parse("enter_action = explode").enter_action,
+            EnterAction::Detail
+        );
+    }
+
+    #[test]
+    fn test_parse_wip_policy() {
+        assert_eq!(Config::default().wip_policy, WipPolicy::Confirm);
+        assert_eq!(
+            Config::parse("wip_policy = Block").wip_policy,
+            WipPolicy::Block
+        );
+        assert_eq!(
+            Config::parse("wip_policy = allow").wip_policy,
+            WipPolicy::Allow
+        );
+        assert_eq!(
+            Config::parse("wip_policy = sometimes").wip_policy,
+            WipPolicy::Confirm
+        );
+    }
+
+    #[test]
+    fn test_parse_priority_formula() {
+        assert_eq!(
+            Config::default().priority_formula,
+            PriorityFormula::Normalized
+        );
+        assert_eq!(
+            Config::parse("priority_formula = ice").priority_formula,
+            PriorityFormula::Ice
+        );
+        assert_eq!(
+            Config::parse("priority_formula = Weighted").priority_formula,
+            PriorityFormula::Weighted
+        );
+        assert_eq!(
+            Config::parse("priority_formula = magic").priority_formula,
+            PriorityFormula::Normalized
+        );
+    }
+}
@@ -0,0 +1,454 @@
+use crate::kanban::models::{App, InputMode, Task};
+use crate::ops::crud::PriorityFormula;
+use chrono::{Local, NaiveDate};
+
+/// A single word of a filter expression.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterTerm {
+    /// `tag:name` - the task carries this tag
+    Tag(String),
+    /// `priority>n` - the computed priority is above n
+    PriorityAbove(f32),
+    /// `priority<n` - the computed priority is below n
+    PriorityBelow(f32),
+    /// `due:today` - the task is due today
+    DueToday,
+    /// `due:YYYY-MM-DD` - the task is due on that date
+    DueOn(String),
+    /// `done` - the task sits in one of the configured done columns
+    Done,
+    /// Any other word matches against the title
+    Text(String),
+}
+
+/// A term, optionally negated with a leading `-`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilterCondition {
+    pub term: FilterTerm,
+    pub negated: bool,
+}
+
+/// A parsed filter expression such as `tag:bug priority>6 -done`.
+///
+/// Words are separated by whitespace and all of them must match. Filters
+/// only hide tasks from view; the board itself is never changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskFilter {
+    pub expression: String,
+    pub conditions: Vec<FilterCondition>,
+}
+
+impl TaskFilter {
+    pub fn parse(expression: &str) -> Result<TaskFilter, String> {
+        let conditions = expression
+            .split_whitespace()
+            .map(parse_condition)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TaskFilter {
+            expression: expression.trim().to_string(),
+            conditions,
+        })
+    }
+
+    /// Whether a task passes every condition. `today` is a `YYYY-MM-DD`
+    /// date so callers (and tests) decide what "today" means, and
+    /// `formula` scores the `priority>`/`priority<` terms.
+    pub fn matches(
+        &self,
+        task: &Task,
+        in_done_column: bool,
+        today: &str,
+        formula: PriorityFormula,
+    ) -> bool {
+        self.conditions.iter().all(|condition| {
+            condition.term.matches(task, in_done_column, today, formula) != condition.negated
+        })
+    }
+}
+
+impl FilterTerm {
+    fn matches(
+        &self,
+        task: &Task,
+        in_done_column: bool,
+        today: &str,
+        formula: PriorityFormula,
+    ) -> bool {
+        match self {
+            FilterTerm::Tag(tag) => task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            FilterTerm::PriorityAbove(value) => task
+                .computed_priority(formula)
+                .is_some_and(|score| score > *value),
+            FilterTerm::PriorityBelow(value) => task
+                .computed_priority(formula)
+                .is_some_and(|score| score < *value),
+            FilterTerm::DueToday => task.due.as_deref() == Some(today),
+            FilterTerm::DueOn(date) => task.due.as_deref() == Some(date.as_str()),
+            FilterTerm::Done => in_done_column,
+            FilterTerm::Text(text) => task.title.to_lowercase().contains(text.as_str()),
+        }
+    }
+}
+
+fn parse_condition(word: &str) -> Result<FilterCondition, String> {
+    match word.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => Ok(FilterCondition {
+            term: parse_term(rest)?,
+            negated: true,
+        }),
+        _ => Ok(FilterCondition {
+            term: parse_term(word)?,
+            negated: false,
+        }),
+    }
+}
+
+fn parse_term(word: &str) -> Result<FilterTerm, String> {
+    if let Some(tag) = word.strip_prefix("tag:") {
+        if tag.is_empty() {
+            return Err("Missing tag name after 'tag:'".to_string());
+        }
+        Ok(FilterTerm::Tag(tag.to_string()))
+    } else if let Some(value) = word.strip_prefix("priority>") {
+        parse_priority(word, value).map(FilterTerm::PriorityAbove)
+    } else if let Some(value) = word.strip_prefix("priority<") {
+        parse_priority(word, value).map(FilterTerm::PriorityBelow)
+    } else if let Some(date) = word.strip_prefix("due:") {
+        if date.eq_ignore_ascii_case("today") {
+            Ok(FilterTerm::DueToday)
+        } else if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok() {
+            Ok(FilterTerm::DueOn(date.to_string()))
+        } else {
+            Err(format!(
+                "Invalid date in '{}' (use today or YYYY-MM-DD)",
+                word
+            ))
+        }
+    } else if word.eq_ignore_ascii_case("done") {
+        Ok(FilterTerm::Done)
+    } else if let Some((key, _)) = word.split_once(':') {
+        Err(format!("Unknown filter '{}:'", key))
+    } else {
+        Ok(FilterTerm::Text(word.to_lowercase()))
+    }
+}
+
+fn parse_priority(word: &str, value: &str) -> Result<f32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number in '{}'", word))
+}
+
+/// Whether a task stays visible in a column folded with `za` around
+/// `threshold`. Tasks without a computed priority are folded away.
+pub fn meets_fold_threshold(task: &Task, threshold: f32, formula: PriorityFormula) -> bool {
+    task.computed_priority(formula)
+        .is_some_and(|score| score >= threshold)
+}
+
+impl App {
+    /// Open the filter prompt, starting from the current expression
+    pub fn prepare_filter(&mut self) {
+        self.input_text = self
+            .filter
+            .as_ref()
+            .map(|filter| filter.expression.clone())
+            .unwrap_or_default();
+        self.input_mode = InputMode::Filtering;
+    }
+
+    /// Apply the expression typed in the filter prompt. An empty expression
+    /// clears the filter; an invalid one keeps the prompt open.
+    pub fn apply_filter(&mut self) {
+        match TaskFilter::parse(&self.input_text) {
+            Ok(filter) => {
+                self.filter = if filter.conditions.is_empty() {
+                    None
+                } else {
+                    Some(filter)
+                };
+                self.input_mode = InputMode::Normal;
+                self.input_text.clear();
+                self.select_visible_task();
+            }
+            Err(message) => self.status_message = Some(message),
+        }
+    }
+
+    /// Whether a task passes its column's fold and the current filter
+    pub fn is_task_visible(&self, column_idx: usize, task_idx: usize) -> bool {
+        let Some(column) = self.columns.get(column_idx) else {
+            return false;
+        };
+        let Some(task) = column.tasks.get(task_idx) else {
+            return false;
+        };
+        let folded_away =
+            self.view_state
+                .column_folds
+                .get(&column.title)
+                .is_some_and(|&threshold| {
+                    !meets_fold_threshold(task, threshold, self.config.priority_formula)
+                });
+        if folded_away {
+            return false;
+        }
+        let Some(filter) = &self.filter else {
+            return true;
+        };
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        filter.matches(
+            task,
+            self.is_done_column(column_idx),
+            &today,
+            self.config.priority_formula,
+        )
+    }
+
+    /// Fold the active column down to the tasks at or above the selected
+    /// task's priority, or unfold it if it is already folded. Unlike the
+    /// filter this only affects the one column, and it is remembered in the
+    /// board's view state.
+    pub fn toggle_column_fold(&mut self) {
+        let Some(column) = self.columns.get(self.active_column) else {
+            return;
+        };
+        let title = column.title.clone();
+        if self.view_state.column_folds.remove(&title).is_some() {
+            self.status_message = Some(format!("Unfolded '{}'", title));
+        } else {
+            let Some(threshold) = self
+                .selected_task()
+                .and_then(|task| task.computed_priority(self.config.priority_formula))
+            else {
+                self.status_message =
+                    Some("Select a task with a priority to fold around".to_string());
+                return;
+            };
+            self.view_state
+                .column_folds
+                .insert(title.clone(), threshold);
+            self.status_message = Some(format!(
+                "Folded '{}' to priority {:.1} and above",
+                title, threshold
+            ));
+        }
+        self.save_view_state();
+        self.select_visible_task();
+    }
+
+    /// Indices of the tasks in a column that pass the current filter
+    pub fn visible_task_indices(&self, column_idx: usize) -> Vec<usize> {
+        let task_count = self.columns.get(column_idx).map_or(0, |c| c.tasks.len());
+        (0..task_count)
+            .filter(|&task_idx| self.is_task_visible(column_idx, task_idx))
+            .collect()
+    }
+
+    // Move the active column's selection off a task the filter now hides
+    fn select_visible_task(&mut self) {
+        let visible = self.visible_task_indices(self.active_column);
+        let Some(column) = self.columns.get_mut(self.active_column) else {
+            return;
+        };
+        if !column
+            .selected_task
+            .is_some_and(|idx| visible.contains(&idx))
+        {
+            column.selected_task = visible.first().copied();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::crud::Priority;
+
+    const TODAY: &str = "2025-03-24";
+    const FORMULA: PriorityFormula = PriorityFormula::Normalized;
+
+    // Impact and urgency are maxed out, so effort 1 scores 10.0 and
+    // effort 10 scores about 1.8
+    fn task(title: &str, effort: u8, tags: &[&str], due: Option<&str>) -> Task {
+        Task {
+            id: 0,
+            title: title.to_string(),
+            description: None,
+            priority: Some(Priority {
+                impact: 10,
+                urgency: 10,
+                effort,
+            }),
+            completed_at: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            due: due.map(str::to_string),
+            created: None,
+            focus_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_filter_terms() {
+        let filter = TaskFilter::parse("tag:bug priority>6 -done due:today login").unwrap();
+        let terms: Vec<(FilterTerm, bool)> = filter
+            .conditions
+            .into_iter()
+            .map(|condition| (condition.term, condition.negated))
+            .collect();
+        assert_eq!(
+            terms,
+            vec![
+                (FilterTerm::Tag("bug".to_string()), false),
+                (FilterTerm::PriorityAbove(6.0), false),
+                (FilterTerm::Done, true),
+                (FilterTerm::DueToday, false),
+                (FilterTerm::Text("login".to_string()), false),
+            ]
+        );
+
+        assert_eq!(
+            TaskFilter::parse("-tag:ui priority<2.5 due:2025-04-01")
+                .unwrap()
+                .conditions,
+            vec![
+                FilterCondition {
+                    term: FilterTerm::Tag("ui".to_string()),
+                    negated: true,
+                },
+                FilterCondition {
+                    term: FilterTerm::PriorityBelow(2.5),
+                    negated: false,
+                },
+                FilterCondition {
+                    term: FilterTerm::DueOn("2025-04-01".to_string()),
+                    negated: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_filter_errors() {
+        assert!(TaskFilter::parse("").unwrap().conditions.is_empty());
+        assert!(TaskFilter::parse("priority>high").is_err());
+        assert!(TaskFilter::parse("priority>=6").is_err());
+        assert!(TaskFilter::parse("tag:").is_err());
+        assert!(TaskFilter::parse("due:tomorrow").is_err());
+        assert!(TaskFilter::parse("owner:sam").is_err());
+        // A lone dash is just text
+        assert_eq!(
+            TaskFilter::parse("-").unwrap().conditions[0].term,
+            FilterTerm::Text("-".to_string())
+        );
+    }
+
+    #[test]
+    fn test_filter_matches_sample_tasks() {
+        let crash = task("Fix login crash", 1, &["bug"], Some(TODAY));
+        let typo = task("Fix typo", 10, &["Bug", "docs"], Some("2025-04-01"));
+        let feature = task("Dark mode", 1, &[], None);
+
+        let filter = TaskFilter::parse("tag:bug priority>6 -done").unwrap();
+        assert!(filter.matches(&crash, false, TODAY, FORMULA));
+        assert!(!filter.matches(&crash, true, TODAY, FORMULA));
+        assert!(!filter.matches(&typo, false, TODAY, FORMULA));
+        assert!(!filter.matches(&feature, false, TODAY, FORMULA));
+
+        let filter = TaskFilter::parse("tag:BUG priority<2").unwrap();
+        assert!(filter.matches(&typo, false, TODAY, FORMULA));
+
+        let filter = TaskFilter::parse("due:today").unwrap();
+        assert!(filter.matches(&crash, false, TODAY, FORMULA));
+        assert!(!filter.matches(&typo, false, TODAY, FORMULA));
+        assert!(!filter.matches(&feature, false, TODAY, FORMULA));
+
+        let filter = TaskFilter::parse("due:2025-04-01 -tag:bug").unwrap();
+        assert!(!filter.matches(&typo, false, TODAY, FORMULA));
+
+        let filter = TaskFilter::parse("fix -CRASH").unwrap();
+        assert!(filter.matches(&typo, false, TODAY, FORMULA));
+        assert!(!filter.matches(&crash, false, TODAY, FORMULA));
+
+        let filter = TaskFilter::parse("done").unwrap();
+        assert!(filter.matches(&feature, true, TODAY, FORMULA));
+        assert!(!filter.matches(&feature, false, TODAY, FORMULA));
+    }
+
+    #[test]
+    fn test_apply_filter_hides_tasks_and_moves_selection() {
+        let mut app = App::new("Filter Board");
+        app.columns.truncate(1);
+        app.columns[0].tasks = vec![
+            task("Dark mode", 1, &[], None),
+            task("Fix login crash", 1, &["bug"], None),
+        ];
+        app.columns[0].selected_task = Some(0);
+        app.active_column = 0;
+
+        app.prepare_filter();
+        app.input_text = "tag:bug".to_string();
+        app.apply_filter();
+
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(app.visible_task_indices(0), vec![1]);
+        assert_eq!(app.columns[0].selected_task, Some(1));
+
+        // A bad expression keeps the prompt open and explains why
+        app.prepare_filter();
+        assert_eq!(app.input_text, "tag:bug");
+        app.input_text = "priority>lots".to_string();
+        app.apply_filter();
+        assert!(app.input_mode == InputMode::Filtering);
+        assert!(app.status_message.is_some());
+
+        // Clearing the prompt removes the filter
+        app.input_text.clear();
+        app.apply_filter();
+        assert!(app.filter.is_none());
+        assert_eq!(app.visible_task_indices(0), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_meets_fold_threshold() {
+        let urgent = task("Fix login crash", 1, &[], None);
+        let slow = task("Rewrite docs", 10, &[], None);
+        let mut unscored = task("Someday", 1, &[], None);
+        unscored.priority = None;
+
+        assert!(meets_fold_threshold(&urgent, 10.0, FORMULA));
+        assert!(meets_fold_threshold(&slow, 1.5, FORMULA));
+        assert!(!meets_fold_threshold(&slow, 5.0, FORMULA));
+        assert!(!meets_fold_threshold(&unscored, 0.0, FORMULA));
+    }
+
+    #[test]
+    fn test_column_fold_only_hides_tasks_in_that_column() {
+        let mut app = App::new("Fold Board");
+        app.file_path = None;
+        let tasks = vec![
+            task("Rewrite docs", 10, &[], None),
+            task("Fix crash", 1, &[], None),
+        ];
+        app.columns.truncate(1);
+        app.columns[0].tasks = tasks.clone();
+        let mut other = app.columns[0].clone();
+        other.title = "Doing".to_string();
+        app.columns.push(other);
+        app.active_column = 0;
+        app.columns[0].selected_task = Some(1);
+
+        app.toggle_column_fold();
+
+        assert_eq!(app.view_state.column_folds.get("To Do"), Some(&10.0));
+        assert_eq!(app.visible_task_indices(0), vec![1]);
+        assert_eq!(app.visible_task_indices(1), vec![0, 1]);
+        // The board itself is untouched
+        assert_eq!(app.columns[0].tasks, tasks);
+
+        app.toggle_column_fold();
+        assert!(app.view_state.column_folds.is_empty());
+        assert_eq!(app.visible_task_indices(0), vec![0, 1]);
+    }
+}
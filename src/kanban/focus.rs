@@ -0,0 +1,176 @@
+use crate::kanban::models::App;
+use std::time::{Duration, Instant};
+
+/// Length of one focus session
+pub const FOCUS_DURATION: Duration = Duration::from_secs(25 * 60);
+
+/// A running focus session on one task, counting down from `duration`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusTimer {
+    /// ID of the task the session is for; it may move between columns
+    pub task_id: usize,
+    pub started: Instant,
+    pub duration: Duration,
+}
+
+impl FocusTimer {
+    /// Time left in the session at `now`
+    pub fn remaining(&self, now: Instant) -> Duration {
+        self.duration
+            .saturating_sub(now.saturating_duration_since(self.started))
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.remaining(now).is_zero()
+    }
+
+    /// Countdown for the title bar, e.g. `24:05`
+    pub fn countdown_label(&self, now: Instant) -> String {
+        let seconds = self.remaining(now).as_secs();
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+impl App {
+    /// Start a focus session on the selected task, or stop the running one
+    pub fn toggle_focus_timer(&mut self) {
+        if self.focus_timer.is_some() {
+            self.stop_focus_timer();
+        } else {
+            self.start_focus_timer(Instant::now());
+        }
+    }
+
+    /// Start a session on the selected task, replacing any running one
+    pub fn start_focus_timer(&mut self, now: Instant) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let message = format!(
+            "Focusing on '{}' for {} minutes",
+            task.title,
+            FOCUS_DURATION.as_secs() / 60
+        );
+        self.focus_timer = Some(FocusTimer {
+            task_id: task.id,
+            started: now,
+            duration: FOCUS_DURATION,
+        });
+        self.status_message = Some(message);
+    }
+
+    /// Abandon the running session; only finished sessions are logged
+    pub fn stop_focus_timer(&mut self) {
+        if self.focus_timer.take().is_some() {
+            self.status_message = Some("Focus session stopped".to_string());
+        }
+    }
+
+    /// Called from the event loop: once the session runs out, add its
+    /// length to the task's focus time and save
+    pub fn tick_focus_timer(&mut self, now: Instant) {
+        let Some(timer) = self.focus_timer.take_if(|timer| timer.is_finished(now)) else {
+            return;
+        };
+        // The task may have been deleted while the timer ran
+        let Some((column_idx, task_idx)) = self.find_task_by_id(timer.task_id) else {
+            return;
+        };
+        let minutes = (timer.duration.as_secs() / 60) as u32;
+        self.record_undo();
+        let task = &mut self.columns[column_idx].tasks[task_idx];
+        task.focus_minutes += minutes;
+        let description = format!(
+            "Finished a {} minute focus session on '{}' ({} minutes in total)",
+            minutes, task.title, task.focus_minutes
+        );
+
+        let _ = self.save_board();
+        self.log_change(&description);
+        self.status_message = Some(description);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::{Column, Task};
+
+    fn app_with_task() -> App {
+        let mut app = App::new("Focus Board");
+        app.file_path = None;
+        app.columns = vec![Column {
+            title: "Doing".to_string(),
+            tasks: vec![Task {
+                id: 4,
+                title: "Write report".to_string(),
+                description: None,
+                priority: None,
+                completed_at: None,
+                tags: Vec::new(),
+                due: None,
+                created: None,
+                focus_minutes: 10,
+            }],
+            selected_task: Some(0),
+            wip_limit: None,
+        }];
+        app.active_column = 0;
+        app
+    }
+
+    #[test]
+    fn test_focus_timer_counts_down() {
+        let start = Instant::now();
+        let timer = FocusTimer {
+            task_id: 1,
+            started: start,
+            duration: FOCUS_DURATION,
+        };
+
+        assert_eq!(timer.countdown_label(start), "25:00");
+        assert_eq!(
+            timer.countdown_label(start + Duration::from_secs(95)),
+            "23:25"
+        );
+        assert!(!timer.is_finished(start + Duration::from_secs(60)));
+        assert!(timer.is_finished(start + FOCUS_DURATION));
+        assert_eq!(timer.remaining(start + FOCUS_DURATION * 2), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_focus_timer_start_and_stop() {
+        let mut app = app_with_task();
+        let start = Instant::now();
+
+        app.start_focus_timer(start);
+        assert_eq!(app.focus_timer.as_ref().map(|timer| timer.task_id), Some(4));
+
+        // Ticking before the end changes nothing
+        app.tick_focus_timer(start + Duration::from_secs(60));
+        assert!(app.focus_timer.is_some());
+
+        // Stopping early logs nothing
+        app.stop_focus_timer();
+        assert!(app.focus_timer.is_none());
+        assert_eq!(app.columns[0].tasks[0].focus_minutes, 10);
+
+        // Nothing to time without a selected task
+        app.columns[0].selected_task = None;
+        app.start_focus_timer(start);
+        assert!(app.focus_timer.is_none());
+    }
+
+    #[test]
+    fn test_finished_session_adds_focus_time() {
+        let mut app = app_with_task();
+        let start = Instant::now();
+        app.start_focus_timer(start);
+
+        app.tick_focus_timer(start + FOCUS_DURATION);
+
+        assert!(app.focus_timer.is_none());
+        assert_eq!(app.columns[0].tasks[0].focus_minutes, 35);
+        assert!(app.dirty);
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+
+/// A bounded stack of snapshots used for undo.
+///
+/// Once more than `max_depth` snapshots are pushed the oldest ones are
+/// dropped, so a long session can't grow memory without limit.
+pub struct History<T> {
+    snapshots: VecDeque<T>,
+    max_depth: usize,
+}
+
+impl<T> History<T> {
+    /// Create an empty history holding at most `max_depth` snapshots
+    pub fn new(max_depth: usize) -> Self {
+        History {
+            snapshots: VecDeque::new(),
+            max_depth,
+        }
+    }
+
+    /// Record a snapshot, dropping the oldest ones past the depth limit
+    pub fn push(&mut self, snapshot: T) {
+        if self.max_depth == 0 {
+            return;
+        }
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > self.max_depth {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Take the most recent snapshot
+    pub fn pop(&mut self) -> Option<T> {
+        self.snapshots.pop_back()
+    }
+
+    /// The most recent snapshot, to add to what undoing it restores
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.snapshots.back_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_keeps_most_recent_snapshots() {
+        let mut history = History::new(3);
+        for i in 0..5 {
+            history.push(i);
+        }
+
+        assert_eq!(history.pop(), Some(4));
+        assert_eq!(history.pop(), Some(3));
+        // The oldest retained snapshot is still reachable
+        assert_eq!(history.pop(), Some(2));
+        assert_eq!(history.pop(), None);
+    }
+
+    #[test]
+    fn test_history_with_zero_depth_keeps_nothing() {
+        let mut history = History::new(0);
+        history.push(1);
+        assert_eq!(history.pop(), None);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::kanban::models::{App, InputMode, MAX_PRIORITY_VALUE, Task, sanitize_title};
+use crate::ops::crud::Priority;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+/// Split CSV text into records of fields. Fields may be quoted, in which
+/// case they can hold commas, newlines and `""` for a literal quote.
+/// Blank lines are skipped.
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => end_record(&mut records, &mut record, &mut field),
+            _ => field.push(c),
+        }
+    }
+    end_record(&mut records, &mut record, &mut field);
+    records
+}
+
+// Finish the current record, dropping it if the line was blank
+fn end_record(records: &mut Vec<Vec<String>>, record: &mut Vec<String>, field: &mut String) {
+    record.push(std::mem::take(field));
+    let record = std::mem::take(record);
+    if record.iter().any(|field| !field.trim().is_empty()) {
+        records.push(record);
+    }
+}
+
+/// Parse the priority cell: either `impact/urgency/effort` or a single
+/// impact value, with the rest taken from the default priority
+fn parse_priority(cell: &str) -> Result<Option<Priority>, String> {
+    let cell = cell.trim();
+    if cell.is_empty() {
+        return Ok(None);
+    }
+    let values = cell
+        .split('/')
+        .map(|part| match part.trim().parse::<u8>() {
+            Ok(value) if value <= MAX_PRIORITY_VALUE => Ok(value),
+            _ => Err(format!("Invalid priority '{}'", cell)),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    let default = Task::default_priority();
+    match values.as_slice() {
+        [impact] => Ok(Some(Priority {
+            impact: *impact,
+            ..default
+        })),
+        [impact, urgency, effort] => Ok(Some(Priority {
+            impact: *impact,
+            urgency: *urgency,
+            effort: *effort,
+        })),
+        _ => Err(format!("Invalid priority '{}'", cell)),
+    }
+}
+
+/// Turn CSV text with `title,priority,tags` columns into tasks (IDs are
+/// left at 0). A first row naming a `title` column is taken as a header
+/// and may list the columns in any order. Priority and tags are optional;
+/// tags are separated by `;` or, inside quotes, `,`.
+pub fn tasks_from_csv(text: &str) -> Result<Vec<Task>, String> {
+    let mut records = parse_csv(text).into_iter().peekable();
+
+    // Positions of the title, priority and tags columns
+    let header = records.next_if(|record| {
+        record
+            .iter()
+            .any(|cell| cell.trim().eq_ignore_ascii_case("title"))
+    });
+    let (title_col, priority_col, tags_col) = match header {
+        Some(header) => {
+            let find = |name: &str| {
+                header
+                    .iter()
+                    .position(|cell| cell.trim().eq_ignore_ascii_case(name))
+            };
+            (find("title").unwrap_or(0), find("priority"), find("tags"))
+        }
+        None => (0, Some(1), Some(2)),
+    };
+    let cell = |record: &[String], col: Option<usize>| -> String {
+        col.and_then(|col| record.get(col))
+            .map(|cell| cell.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut tasks = Vec::new();
+    for (row, record) in records.enumerate() {
+        let title = sanitize_title(&cell(&record, Some(title_col)));
+        if title.is_empty() {
+            return Err(format!("Row {} has no title", row + 1));
+        }
+        let priority = parse_priority(&cell(&record, priority_col))
+            .map_err(|message| format!("Row {}: {}", row + 1, message))?
+            .unwrap_or_else(Task::default_priority);
+        let tags = cell(&record, tags_col)
+            .split([';', ','])
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        tasks.push(Task {
+            id: 0,
+            title,
+            description: None,
+            priority: Some(priority),
+            completed_at: None,
+            tags,
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        });
+    }
+    Ok(tasks)
+}
+
+impl App {
+    /// Open the prompt for importing a CSV file of tasks
+    pub fn prepare_import_csv(&mut self) {
+        if !self.columns.is_empty() {
+            self.input_text.clear();
+            self.input_mode = InputMode::ImportingCsv;
+        }
+    }
+
+    /// Import the file typed in the prompt as `path` or `path: column`.
+    /// Without a column the tasks go into the active one.
+    pub fn import_csv_from_prompt(&mut self) {
+        let typed = self.input_text.trim().to_string();
+        let (path, column_idx) = match typed.rsplit_once(':') {
+            Some((path, name)) => match self
+                .columns
+                .iter()
+                .position(|column| column.title.eq_ignore_ascii_case(name.trim()))
+            {
+                Some(column_idx) => (path.trim().to_string(), column_idx),
+                None => (typed.clone(), self.active_column),
+            },
+            None => (typed.clone(), self.active_column),
+        };
+
+        match self.import_csv(Path::new(&path), column_idx) {
+            Ok(count) => {
+                self.status_message = Some(format!(
+                    "Imported {} task(s) into '{}'",
+                    count, self.columns[column_idx].title
+                ));
+                self.input_mode = InputMode::Normal;
+                self.input_text.clear();
+            }
+            // Keep the prompt open so the path can be corrected
+            Err(message) => self.status_message = Some(message),
+        }
+    }
+
+    /// Add every row of a CSV file as a new task at the end of a column,
+    /// as a single undoable edit. Returns how many tasks were added.
+    pub fn import_csv(&mut self, path: &Path, column_idx: usize) -> Result<usize, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Error reading {}: {}", path.display(), e))?;
+        let tasks = tasks_from_csv(&text)?;
+        self.add_imported_tasks(column_idx, tasks)
+    }
+
+    fn add_imported_tasks(&mut self, column_idx: usize, tasks: Vec<Task>) -> Result<usize, String> {
+        if column_idx >= self.columns.len() {
+            return Err("No column to import into".to_string());
+        }
+        if tasks.is_empty() {
+            return Err("The file has no tasks".to_string());
+        }
+        self.record_undo();
+        let first_id = self.next_task_id();
+        let count = tasks.len();
+        let column = &mut self.columns[column_idx];
+        for (offset, mut task) in tasks.into_iter().enumerate() {
+            task.id = first_id + offset;
+            column.tasks.push(task);
+        }
+        self.changed_task = Some((first_id + count - 1, Instant::now()));
+        let description = format!("Imported {} task(s) into '{}'", count, column.title);
+
+        let _ = self.save_board();
+        self.log_change(&description);
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_parse_csv_quoted_fields() {
+        let records = parse_csv("a,\"b, c\",\"say \"\"hi\"\"\"\r\n\n\"two\nlines\",x\n");
+        assert_eq!(
+            records,
+            vec![
+                vec![
+                    "a".to_string(),
+                    "b, c".to_string(),
+                    "say \"hi\"".to_string()
+                ],
+                vec!["two\nlines".to_string(), "x".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tasks_from_sample_csv() {
+        let csv = "title,priority,tags\n\
+                   Fix login,8/9/2,\"bug,auth\"\n\
+                   \"Write docs, part 2\",4,docs\n\
+                   Plan sprint\n";
+
+        let tasks = tasks_from_csv(csv).unwrap();
+
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].title, "Fix login");
+        assert_eq!(
+            tasks[0].priority,
+            Some(Priority {
+                impact: 8,
+                urgency: 9,
+                effort: 2,
+            })
+        );
+        assert_eq!(tasks[0].tags, vec!["bug", "auth"]);
+        assert_eq!(tasks[1].title, "Write docs, part 2");
+        assert_eq!(tasks[1].priority.as_ref().unwrap().impact, 4);
+        assert_eq!(tasks[1].tags, vec!["docs"]);
+        // Missing optional columns fall back to the defaults
+        assert_eq!(tasks[2].priority, Some(Task::default_priority()));
+        assert!(tasks[2].tags.is_empty());
+    }
+
+    #[test]
+    fn test_tasks_from_csv_header_order_and_errors() {
+        let tasks = tasks_from_csv("Tags,Title\nrelease;ops,Ship it\n").unwrap();
+        assert_eq!(tasks[0].title, "Ship it");
+        assert_eq!(tasks[0].tags, vec!["release", "ops"]);
+        assert_eq!(tasks[0].priority, Some(Task::default_priority()));
+
+        // Without a header the columns are title, priority, tags
+        let tasks = tasks_from_csv("Ship it,2/2/2\n").unwrap();
+        assert_eq!(tasks[0].priority.as_ref().unwrap().effort, 2);
+
+        assert!(tasks_from_csv("Ship it,high\n").is_err());
+        assert!(tasks_from_csv("Ship it,11\n").is_err());
+        assert!(tasks_from_csv(",5\n").is_err());
+    }
+
+    #[test]
+    fn test_import_csv_adds_tasks_with_fresh_ids() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_import.csv");
+        fs::write(&temp_path, "title,priority\nFirst,5\nSecond,6\n").unwrap();
+
+        let mut app = App::new("Import Board");
+        app.file_path = None;
+        app.columns.truncate(1);
+        let existing = app.columns[0].tasks.len();
+        let next_id = app.next_task_id();
+
+        assert_eq!(app.import_csv(&temp_path, 0), Ok(2));
+
+        let tasks = &app.columns[0].tasks;
+        assert_eq!(tasks.len(), existing + 2);
+        assert_eq!(tasks[existing].title, "First");
+        assert_eq!(tasks[existing].id, next_id);
+        assert_eq!(tasks[existing + 1].id, next_id + 1);
+
+        fs::remove_file(&temp_path).unwrap();
+        assert!(app.import_csv(&temp_path, 0).is_err());
+    }
+}
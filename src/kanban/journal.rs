@@ -0,0 +1,438 @@
+use crate::kanban::models::{App, Column, InputMode, Task};
+use crate::ops::crud::{escape_title, unescape_title};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The journal that sits next to a board file, e.g. `work.txt` -> `work.journal`
+pub fn journal_path(board_path: &str) -> PathBuf {
+    Path::new(board_path).with_extension("journal")
+}
+
+/// One board edit as recorded in the journal. Tasks are named by ID so an
+/// entry can be replayed onto a board that already has some of the edits:
+/// adding a task whose ID exists, moving a task to the position it already
+/// holds or deleting one that is gone all do nothing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JournalOp {
+    Add {
+        id: usize,
+        column: String,
+        title: String,
+    },
+    Move {
+        id: usize,
+        column: String,
+        // Position in the column; past the end means last
+        index: usize,
+    },
+    Delete {
+        id: usize,
+    },
+    Rename {
+        id: usize,
+        title: String,
+    },
+}
+
+impl JournalOp {
+    /// Parse one tab-separated journal line, e.g. `move\t4\tDone\t0`
+    pub fn parse(line: &str) -> Option<JournalOp> {
+        // The title is always the last field, so it may hold tabs of its own
+        let kind = line.split('\t').next()?;
+        let field_count = match kind {
+            "add" | "move" => 4,
+            "rename" => 3,
+            _ => 2,
+        };
+        let fields: Vec<&str> = line.splitn(field_count, '\t').collect();
+        let id = fields.get(1)?.parse().ok()?;
+        match fields.as_slice() {
+            ["add", _, column, title] => Some(JournalOp::Add {
+                id,
+                column: column.to_string(),
+                title: unescape_title(title),
+            }),
+            ["move", _, column, index] => Some(JournalOp::Move {
+                id,
+                column: column.to_string(),
+                index: index.parse().ok()?,
+            }),
+            // Journals from before moves had a position put the task last
+            ["move", _, column] => Some(JournalOp::Move {
+                id,
+                column: column.to_string(),
+                index: usize::MAX,
+            }),
+            ["delete", _] => Some(JournalOp::Delete { id }),
+            ["rename", _, title] => Some(JournalOp::Rename {
+                id,
+                title: unescape_title(title),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for JournalOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalOp::Add { id, column, title } => {
+                write!(f, "add\t{}\t{}\t{}", id, column, escape_title(title))
+            }
+            JournalOp::Move { id, column, index } => {
+                write!(f, "move\t{}\t{}\t{}", id, column, index)
+            }
+            JournalOp::Delete { id } => write!(f, "delete\t{}", id),
+            JournalOp::Rename { id, title } => write!(f, "rename\t{}\t{}", id, escape_title(title)),
+        }
+    }
+}
+
+/// Append one entry to a journal file
+pub fn append_op(path: &Path, op: &JournalOp) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", op)
+}
+
+/// Read a journal, skipping lines that don't parse. A missing journal
+/// reads as empty.
+pub fn read_journal(path: &Path) -> Vec<JournalOp> {
+    fs::read_to_string(path)
+        .map(|contents| contents.lines().filter_map(JournalOp::parse).collect())
+        .unwrap_or_default()
+}
+
+/// Apply journal entries to a board's columns in order. Entries naming a
+/// column or task that doesn't exist are skipped. Returns how many entries
+/// changed something.
+pub fn replay(columns: &mut [Column], ops: &[JournalOp]) -> usize {
+    let mut applied = 0;
+    for op in ops {
+        let position = |columns: &[Column], id: usize| {
+            columns.iter().enumerate().find_map(|(column_idx, column)| {
+                let task_idx = column.tasks.iter().position(|task| task.id == id)?;
+                Some((column_idx, task_idx))
+            })
+        };
+        let changed = match op {
+            JournalOp::Add { id, column, title } => {
+                let target = columns.iter().position(|col| &col.title == column);
+                match (target, position(columns, *id)) {
+                    (Some(target), None) => {
+                        columns[target].tasks.push(Task {
+                            id: *id,
+                            title: title.clone(),
+                            description: None,
+                            priority: Some(Task::default_priority()),
+                            completed_at: None,
+                            tags: Vec::new(),
+                            due: None,
+                            created: None,
+                            focus_minutes: 0,
+                        });
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            JournalOp::Move { id, column, index } => {
+                let target = columns.iter().position(|col| &col.title == column);
+                match (target, position(columns, *id)) {
+                    (Some(target), Some((from, task_idx))) => {
+                        let last = columns[target].tasks.len() - usize::from(from == target);
+                        let index = (*index).min(last);
+                        if from == target && task_idx == index {
+                            false
+                        } else {
+                            let task = columns[from].tasks.remove(task_idx);
+                            columns[target].tasks.insert(index, task);
+                            true
+                        }
+                    }
+                    _ => false,
+                }
+            }
+            JournalOp::Delete { id } => match position(columns, *id) {
+                Some((column_idx, task_idx)) => {
+                    columns[column_idx].tasks.remove(task_idx);
+                    true
+                }
+                None => false,
+            },
+            JournalOp::Rename { id, title } => match position(columns, *id) {
+                Some((column_idx, task_idx)) => {
+                    let task = &mut columns[column_idx].tasks[task_idx];
+                    let changed = task.title != *title;
+                    task.title = title.clone();
+                    changed
+                }
+                None => false,
+            },
+        };
+        if changed {
+            applied += 1;
+        }
+    }
+    applied
+}
+
+impl App {
+    /// Record an edit in the journal before it is saved, if enabled in the
+    /// config. Like the changelog this is best effort. The edit is also
+    /// counted in the summary of unsaved changes.
+    pub fn journal(&mut self, op: JournalOp) {
+        self.unsaved_changes.record(&op);
+        if !self.config.journal {
+            return;
+        }
+        if let Some(path) = &self.file_path {
+            let _ = append_op(&journal_path(path), &op);
+        }
+    }
+
+    /// Drop the journal once the board file holds everything in it
+    pub fn clear_journal(&self) {
+        if !self.config.journal {
+            return;
+        }
+        if let Some(path) = &self.file_path {
+            let _ = fs::remove_file(journal_path(path));
+        }
+    }
+
+    /// Entries in a journal left behind by a session that couldn't save
+    /// them: one that is still there and no older than the board file
+    pub fn pending_journal(&self) -> Vec<JournalOp> {
+        let Some(path) = &self.file_path else {
+            return Vec::new();
+        };
+        let journal = journal_path(path);
+        let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+        match (modified(&journal), modified(Path::new(path))) {
+            (Some(journal_time), Some(board_time)) if journal_time < board_time => Vec::new(),
+            (Some(_), _) => read_journal(&journal),
+            (None, _) => Vec::new(),
+        }
+    }
+
+    /// After opening a board, offer to replay any edits its journal has
+    /// that never made it into the board file
+    pub fn check_journal(&mut self) {
+        if !self.config.journal || self.pending_journal().is_empty() {
+            return;
+        }
+        self.input_mode = InputMode::ConfirmReplayJournal;
+    }
+
+    /// Apply the pending journal to the loaded board and save the result
+    pub fn replay_journal(&mut self) {
+        let ops = self.pending_journal();
+        self.record_undo();
+        let applied = replay(&mut self.columns, &ops);
+        for column in self.columns.iter_mut() {
+            column.selected_task = column
+                .selected_task
+                .filter(|&idx| idx < column.tasks.len())
+                .or((!column.tasks.is_empty()).then_some(0));
+        }
+        let _ = self.save_board();
+        self.status_message = Some(format!(
+            "Replayed {} of {} journal entries (only task adds, moves, deletes and renames are journaled)",
+            applied,
+            ops.len()
+        ));
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Throw away the pending journal without applying it
+    pub fn discard_journal(&mut self) {
+        if let Some(path) = &self.file_path {
+            let _ = fs::remove_file(journal_path(path));
+        }
+        self.input_mode = InputMode::Normal;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn column(title: &str, tasks: &[(usize, &str)]) -> Column {
+        Column {
+            title: title.to_string(),
+            tasks: tasks
+                .iter()
+                .map(|&(id, title)| Task {
+                    id,
+                    title: title.to_string(),
+                    description: None,
+                    priority: None,
+                    completed_at: None,
+                    tags: Vec::new(),
+                    due: None,
+                    created: None,
+                    focus_minutes: 0,
+                })
+                .collect(),
+            selected_task: None,
+            wip_limit: None,
+        }
+    }
+
+    fn summary(columns: &[Column]) -> Vec<Vec<(usize, String)>> {
+        columns
+            .iter()
+            .map(|column| {
+                column
+                    .tasks
+                    .iter()
+                    .map(|task| (task.id, task.title.clone()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_journal_line_round_trip() {
+        let ops = vec![
+            JournalOp::Add {
+                id: 3,
+                column: "To Do".to_string(),
+                title: "Two\nlines".to_string(),
+            },
+            JournalOp::Move {
+                id: 3,
+                column: "In Progress".to_string(),
+                index: 2,
+            },
+            JournalOp::Delete { id: 1 },
+            JournalOp::Rename {
+                id: 2,
+                title: "Tab\tinside".to_string(),
+            },
+        ];
+        for op in &ops {
+            assert_eq!(JournalOp::parse(&op.to_string()).as_ref(), Some(op));
+        }
+        assert_eq!(
+            JournalOp::parse("move\t3\tDone"),
+            Some(JournalOp::Move {
+                id: 3,
+                column: "Done".to_string(),
+                index: usize::MAX,
+            })
+        );
+        assert_eq!(JournalOp::parse("teleport\t1\tDone"), None);
+        assert_eq!(JournalOp::parse("delete\tx"), None);
+    }
+
+    #[test]
+    fn test_replay_reproduces_edits() {
+        let mut columns = vec![
+            column("To Do", &[(1, "Write docs"), (2, "Fix bug")]),
+            column("Done", &[]),
+        ];
+        let ops = vec![
+            JournalOp::Add {
+                id: 3,
+                column: "To Do".to_string(),
+                title: "Plan release".to_string(),
+            },
+            JournalOp::Move {
+                id: 2,
+                column: "Done".to_string(),
+                index: 0,
+            },
+            JournalOp::Rename {
+                id: 1,
+                title: "Write the docs".to_string(),
+            },
+            JournalOp::Delete { id: 3 },
+            JournalOp::Move {
+                id: 9,
+                column: "Done".to_string(),
+                index: 0,
+            },
+        ];
+
+        assert_eq!(replay(&mut columns, &ops), 4);
+        let expected = vec![
+            vec![(1, "Write the docs".to_string())],
+            vec![(2, "Fix bug".to_string())],
+        ];
+        assert_eq!(summary(&columns), expected);
+
+        // Entries that are already reflected on the board change nothing
+        assert_eq!(replay(&mut columns, &ops[1..3]), 0);
+        assert_eq!(summary(&columns), expected);
+    }
+
+    #[test]
+    fn test_replay_reorders_within_a_column() {
+        let mut columns = vec![column("To Do", &[(1, "A"), (2, "B"), (3, "C")])];
+        let ops = vec![JournalOp::Move {
+            id: 3,
+            column: "To Do".to_string(),
+            index: 0,
+        }];
+
+        assert_eq!(replay(&mut columns, &ops), 1);
+        let expected = vec![vec![
+            (3, "C".to_string()),
+            (1, "A".to_string()),
+            (2, "B".to_string()),
+        ]];
+        assert_eq!(summary(&columns), expected);
+        assert_eq!(replay(&mut columns, &ops), 0);
+    }
+
+    #[test]
+    fn test_unsaved_journal_is_replayed_on_load() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_journal_board.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        let journal_file = journal_path(&file_path);
+
+        let mut app = App::new("Journal Board");
+        app.file_path = Some(file_path.clone());
+        app.config.journal = true;
+        app.save_board().unwrap();
+        let first_id = app.columns[0].tasks[0].id;
+
+        // Edits journaled by a session that never got to save them
+        let new_id = app.next_task_id();
+        append_op(
+            &journal_file,
+            &JournalOp::Add {
+                id: new_id,
+                column: "To Do".to_string(),
+                title: "Recovered task".to_string(),
+            },
+        )
+        .unwrap();
+        append_op(&journal_file, &JournalOp::Delete { id: first_id }).unwrap();
+
+        let mut reopened = App::new("Journal Board");
+        reopened.file_path = Some(file_path.clone());
+        reopened.config.journal = true;
+        reopened.load_board().unwrap();
+        reopened.check_journal();
+        assert!(reopened.input_mode == InputMode::ConfirmReplayJournal);
+
+        reopened.replay_journal();
+        let titles: Vec<&str> = reopened.columns[0]
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+        assert!(titles.contains(&"Recovered task"));
+        assert!(!reopened.columns[0].tasks.iter().any(|t| t.id == first_id));
+        // Saving the replayed board flushes the journal
+        assert!(!journal_file.exists());
+
+        let _ = fs::remove_file(crate::kanban::storage::backup_path(&file_path));
+        fs::remove_file(&file_path).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use crate::kanban::models::App;
+use std::process::{Command, Stdio};
+
+// Program (and leading arguments) that hands a URL to the default browser.
+// The URL comes from board files that may be shared, so it must never pass
+// through a shell: `cmd /C start` would run whatever follows an `&` in it.
+#[cfg(target_os = "macos")]
+const OPEN_COMMAND: (&str, &[&str]) = ("open", &[]);
+#[cfg(target_os = "windows")]
+const OPEN_COMMAND: (&str, &[&str]) = ("rundll32", &["url.dll,FileProtocolHandler"]);
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const OPEN_COMMAND: (&str, &[&str]) = ("xdg-open", &[]);
+
+// Punctuation that ends a sentence rather than the URL it follows
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '\'', '"'];
+
+/// The first http(s) URL in `text`. The URL runs to the next whitespace or
+/// angle bracket; trailing sentence punctuation is dropped, and so is a
+/// closing parenthesis without a matching opening one, so both
+/// `(see https://a.b/c)` and markdown `[docs](https://a.b/c)` give
+/// `https://a.b/c`.
+pub fn first_url(text: &str) -> Option<&str> {
+    let start = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| {
+            text.match_indices(scheme).map(|(idx, _)| idx).find(|&idx| {
+                // Skip a scheme glued onto a word, as in "xhttps://"
+                !text[..idx]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric())
+            })
+        })
+        .min()?;
+    let rest = &text[start..];
+    let end = rest
+        .find(|c: char| c.is_whitespace() || c == '<' || c == '>')
+        .unwrap_or(rest.len());
+    let mut url = &rest[..end];
+    loop {
+        let trimmed = url.trim_end_matches(TRAILING_PUNCTUATION);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(inner) if inner.matches('(').count() < inner.matches(')').count() + 1 => inner,
+            _ => trimmed,
+        };
+        if trimmed == url {
+            break;
+        }
+        url = trimmed;
+    }
+    // A bare scheme is not a link
+    url.split_once("://")
+        .is_some_and(|(_, rest)| !rest.is_empty())
+        .then_some(url)
+}
+
+/// Open `url` in the default browser without waiting for it
+pub fn open_url(url: &str) -> Result<(), String> {
+    let (program, args) = OPEN_COMMAND;
+    let mut child = Command::new(program)
+        .args(args)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Couldn't run {}: {}", program, e))?;
+    // Reap the launcher in the background so it doesn't linger as a zombie
+    std::thread::spawn(move || child.wait());
+    Ok(())
+}
+
+impl App {
+    /// Open the first URL in the selected task's title, or failing that its
+    /// description
+    pub fn open_task_url(&mut self) {
+        let Some(task) = self.selected_task() else {
+            return;
+        };
+        let url = first_url(&task.title)
+            .or_else(|| task.description.as_deref().and_then(first_url))
+            .map(str::to_string);
+        self.status_message = Some(match url {
+            Some(url) => match open_url(&url) {
+                Ok(()) => format!("Opened {}", url),
+                Err(message) => message,
+            },
+            None => "The selected task has no URL".to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_url() {
+        assert_eq!(
+            first_url("Read https://example.com/docs first"),
+            Some("https://example.com/docs")
+        );
+        assert_eq!(
+            first_url("see http://a.io and https://b.io"),
+            Some("http://a.io")
+        );
+        assert_eq!(
+            first_url("Done, see https://example.com/x."),
+            Some("https://example.com/x")
+        );
+        assert_eq!(
+            first_url("(details at https://example.com/a)"),
+            Some("https://example.com/a")
+        );
+        assert_eq!(
+            first_url("[docs](https://example.com/a_(b))"),
+            Some("https://example.com/a_(b)")
+        );
+        assert_eq!(
+            first_url("<https://example.com>"),
+            Some("https://example.com")
+        );
+        assert_eq!(first_url("No link here"), None);
+        assert_eq!(first_url("Just https:// on its own"), None);
+        assert_eq!(first_url("xhttps://example.com"), None);
+    }
+
+    #[test]
+    fn test_urls_are_not_opened_through_a_shell() {
+        let (program, _) = OPEN_COMMAND;
+        assert!(!["cmd", "sh", "bash", "powershell"].contains(&program));
+        // Shell metacharacters stay part of the URL, passed as one argument
+        assert_eq!(
+            first_url("see https://x.y/?a=1&calc now"),
+            Some("https://x.y/?a=1&calc")
+        );
+    }
+}
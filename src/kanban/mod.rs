@@ -1,6 +1,18 @@
+pub mod changelog;
+pub mod clipboard;
+pub mod config;
+pub mod filter;
+pub mod focus;
+pub mod history;
+pub mod import;
+pub mod journal;
+pub mod links;
 pub mod models;
+pub mod stats;
 pub mod storage;
+pub mod trash;
 pub mod ui;
+pub mod view_state;
 
 // Re-export commonly used items for convenience
 pub use models::App;
@@ -1,15 +1,314 @@
+use crate::kanban::config::{Config, EnterAction, PickerSort, WipPolicy};
+use crate::kanban::filter::TaskFilter;
+use crate::kanban::focus::FocusTimer;
+use crate::kanban::history::History;
+use crate::kanban::journal::JournalOp;
+use crate::kanban::stats::BoardStats;
+use crate::kanban::storage::{UnsavedBoard, kanban_dir};
+use crate::kanban::trash::{TrashEntry, TrashKind};
+use crate::kanban::ui::task_formatter::TaskTextCache;
+use crate::kanban::view_state::{SortDirection, ViewState};
+use crate::ops::crud::{self, PriorityFormula};
+use crate::ops::error::KanbanError;
+use chrono::Local;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant, SystemTime};
+
+// How long a just-moved or just-created task stays highlighted
+pub const CHANGE_HIGHLIGHT_DURATION: Duration = Duration::from_millis(800);
+
+// Separates a board's category folder(s) from its name, e.g. "work / sprint 42"
+pub const CATEGORY_SEPARATOR: &str = " / ";
+
+// Sidecar file in KANBAN_DIR holding the user's custom board order
+pub const BOARD_ORDER_FILE_NAME: &str = ".board_order";
+
+// Sidecar file in KANBAN_DIR listing recently opened boards, newest first
+pub const RECENT_BOARDS_FILE_NAME: &str = ".recent_boards";
+
+// Sidecar file in KANBAN_DIR marking that the first-run help was dismissed
+pub const ONBOARDING_FLAG_FILE_NAME: &str = ".onboarded";
+
+// How many boards the recent list remembers
+pub const MAX_RECENT_BOARDS: usize = 10;
+
 // Define a structure for a task
+#[derive(Clone, Debug, PartialEq)]
 pub struct Task {
+    pub id: usize, // Stable ID saved as [ID:n]; 0 until one is assigned
     pub title: String,
     pub description: Option<String>,
-    pub priority: Option<u8>,
+    pub priority: Option<crud::Priority>, // Full impact/urgency/effort breakdown
+    pub completed_at: Option<String>,     // When the task entered a done column
+    pub tags: Vec<String>,
+    pub due: Option<String>,     // Due date as YYYY-MM-DD
+    pub created: Option<String>, // Creation date, stamped on first save
+    pub focus_minutes: u32,      // Time spent in completed focus sessions
+}
+
+impl Task {
+    // Priority given to new tasks (the values the storage layer used to default to)
+    pub fn default_priority() -> crud::Priority {
+        crud::Priority {
+            impact: 5,
+            urgency: 5,
+            effort: 3,
+        }
+    }
+
+    // Overall priority score under `formula`, if the task has a usable breakdown
+    pub fn computed_priority(&self, formula: PriorityFormula) -> Option<f32> {
+        self.priority
+            .as_ref()
+            .and_then(|prio| prio.computed_with(formula))
+    }
+}
+
+/// Description written to boards created in the app
+pub const DEFAULT_BOARD_DESCRIPTION: &str = "TUI Kanban Board";
+
+/// One row of the priority view: a task, where it really lives and its
+/// computed priority
+#[derive(Debug, PartialEq)]
+pub struct PrioritizedTask {
+    pub column_idx: usize,
+    pub task_idx: usize,
+    pub column: String,
+    pub score: Option<f32>,
+}
+
+/// Every task on the board, most important first, each annotated with its
+/// column. Tasks without a score come last; ties keep board order.
+pub fn tasks_by_priority(columns: &[Column], formula: PriorityFormula) -> Vec<PrioritizedTask> {
+    let mut tasks: Vec<PrioritizedTask> = columns
+        .iter()
+        .enumerate()
+        .flat_map(|(column_idx, column)| {
+            column
+                .tasks
+                .iter()
+                .enumerate()
+                .map(move |(task_idx, task)| PrioritizedTask {
+                    column_idx,
+                    task_idx,
+                    column: column.title.clone(),
+                    score: task.computed_priority(formula),
+                })
+        })
+        .collect();
+    tasks.sort_by(|a, b| {
+        let a_score = a.score.unwrap_or(f32::MIN);
+        let b_score = b.score.unwrap_or(f32::MIN);
+        b_score.total_cmp(&a_score)
+    });
+    tasks
+}
+
+// Heading of the group for tasks without tags in the tag view
+const UNTAGGED_GROUP: &str = "Untagged";
+
+/// Bucket the tasks by their first (primary) tag for the tag view. Groups
+/// are sorted by tag, case-insensitively, with untagged tasks last; each
+/// holds `(column, task)` indices in board order, so a task in a group can
+/// always be traced back to where it really lives.
+pub fn group_tasks_by_tag(columns: &[Column]) -> Vec<(String, Vec<(usize, usize)>)> {
+    let mut groups: Vec<(String, Vec<(usize, usize)>)> = Vec::new();
+    let mut untagged = Vec::new();
+    for (column_idx, column) in columns.iter().enumerate() {
+        for (task_idx, task) in column.tasks.iter().enumerate() {
+            let Some(tag) = task.tags.first() else {
+                untagged.push((column_idx, task_idx));
+                continue;
+            };
+            match groups.iter_mut().find(|(name, _)| name == tag) {
+                Some((_, tasks)) => tasks.push((column_idx, task_idx)),
+                None => groups.push((tag.clone(), vec![(column_idx, task_idx)])),
+            }
+        }
+    }
+    groups.sort_by_key(|(tag, _)| tag.to_lowercase());
+    if !untagged.is_empty() {
+        groups.push((UNTAGGED_GROUP.to_string(), untagged));
+    }
+    groups
+}
+
+// Step `delta` places from `current` in a list of `len` items, wrapping
+// around the ends or stopping at them
+fn step_index(current: usize, delta: isize, len: usize, wrap: bool) -> usize {
+    let next = current as isize + delta;
+    if wrap {
+        next.rem_euclid(len as isize) as usize
+    } else {
+        next.clamp(0, len as isize - 1) as usize
+    }
+}
+
+/// Which columns a typed destination name picks out
+#[derive(Debug, PartialEq)]
+pub enum ColumnMatch {
+    Unique(usize),
+    Ambiguous(Vec<usize>),
+    NoMatch,
+}
+
+/// Whether every character of `query` appears in `title` in order,
+/// ignoring case and spaces in the query
+pub fn is_fuzzy_match(title: &str, query: &str) -> bool {
+    let mut title_chars = title.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .all(|wanted| title_chars.any(|c| c == wanted))
+}
+
+/// Clean up a typed or pasted task title: tabs become spaces, and other
+/// control characters and zero-width spaces are dropped, since they throw
+/// off the width math when the task is drawn. Line breaks inside a
+/// multi-line title are kept.
+pub fn sanitize_title(title: &str) -> String {
+    title
+        .chars()
+        .filter_map(|c| match c {
+            '\t' => Some(' '),
+            '\n' => Some('\n'),
+            '\u{200B}' | '\u{2060}' | '\u{FEFF}' => None,
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect::<String>()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
 }
 
 // Define a structure for a column
+#[derive(Clone)]
 pub struct Column {
     pub title: String,
     pub tasks: Vec<Task>,
     pub selected_task: Option<usize>, // Will only matter for the active column
+    pub wip_limit: Option<usize>,     // Maximum number of tasks, if limited
+}
+
+// Label of the board picker's extra entry for creating a board
+pub const CREATE_NEW_BOARD_LABEL: &str = "[Create New Board]";
+
+// An entry in the board picker: a real board, or the option to create one
+#[derive(Debug, PartialEq)]
+pub enum BoardEntry<'a> {
+    Board(&'a str),
+    CreateNew,
+}
+
+// Board state captured before a change so it can be undone
+pub struct Snapshot {
+    pub columns: Vec<Column>,
+    pub active_column: usize,
+    // A trash entry the change took back out of the trash, returned to it
+    // on undo so the tasks aren't lost from both
+    pub restored: Option<TrashEntry>,
+}
+
+/// A tally of the edits made since the board was last saved, for the quit
+/// confirmation. Edits with no counter of their own count as `other`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeSummary {
+    pub edits: usize,
+    pub added: usize,
+    pub moved: usize,
+    pub deleted: usize,
+    pub renamed: usize,
+}
+
+impl ChangeSummary {
+    /// Count a task edit by its kind
+    pub fn record(&mut self, op: &JournalOp) {
+        match op {
+            JournalOp::Add { .. } => self.added += 1,
+            JournalOp::Move { .. } => self.moved += 1,
+            JournalOp::Delete { .. } => self.deleted += 1,
+            JournalOp::Rename { .. } => self.renamed += 1,
+        }
+    }
+
+    /// Edits that aren't adds, moves, deletes or renames
+    pub fn other(&self) -> usize {
+        self.edits
+            .saturating_sub(self.added + self.moved + self.deleted + self.renamed)
+    }
+
+    /// e.g. "3 tasks added, 1 task moved since last save"; None if nothing changed
+    pub fn describe(&self) -> Option<String> {
+        let counts = [
+            (self.added, "task", "added"),
+            (self.moved, "task", "moved"),
+            (self.deleted, "task", "deleted"),
+            (self.renamed, "task", "renamed"),
+            (self.other(), "other change", ""),
+        ];
+        let parts: Vec<String> = counts
+            .iter()
+            .filter(|(count, _, _)| *count > 0)
+            .map(|&(count, noun, verb)| {
+                let noun = if count == 1 {
+                    noun.to_string()
+                } else {
+                    format!("{}s", noun)
+                };
+                format!("{} {} {}", count, noun, verb)
+                    .trim_end()
+                    .to_string()
+            })
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("{} since last save", parts.join(", ")))
+        }
+    }
+}
+
+// Highest value accepted for impact, urgency or effort
+pub const MAX_PRIORITY_VALUE: u8 = 10;
+
+// One part of a task's impact/urgency/effort breakdown
+#[derive(Clone, Copy, PartialEq)]
+pub enum PriorityField {
+    Impact,
+    Urgency,
+    Effort,
+}
+
+impl PriorityField {
+    pub fn label(self) -> &'static str {
+        match self {
+            PriorityField::Impact => "Impact",
+            PriorityField::Urgency => "Urgency",
+            PriorityField::Effort => "Effort",
+        }
+    }
+
+    fn value(self, priority: &crud::Priority) -> u8 {
+        match self {
+            PriorityField::Impact => priority.impact,
+            PriorityField::Urgency => priority.urgency,
+            PriorityField::Effort => priority.effort,
+        }
+    }
+
+    fn value_mut(self, priority: &mut crud::Priority) -> &mut u8 {
+        match self {
+            PriorityField::Impact => &mut priority.impact,
+            PriorityField::Urgency => &mut priority.urgency,
+            PriorityField::Effort => &mut priority.effort,
+        }
+    }
 }
 
 // Define input modes
@@ -17,16 +316,43 @@ pub struct Column {
 pub enum InputMode {
     Normal,
     AddingColumn,
+    InsertingColumn(usize), // Adding a column at the given index
     AddingTask,
     MoveMode,
     ConfirmDeleteColumn,
-    BoardSelection, // New mode for board selection popup
-    AddingBoard,    // New mode for creating a new board
+    ConfirmDeleteTask,
+    // Confirm removing every task from the active column
+    ConfirmClearColumn,
+    DoneSummary, // Read-only list of finished tasks
+    // Offer to merge two same-named columns found on load
+    ConfirmMergeColumns(usize, usize),
+    ConfirmReplayJournal, // Offer to apply edits a crashed session never saved
+    ConfirmReload,        // The file changed on disk while there were unsaved changes
+    // Quitting with changes that couldn't be saved; holds the mode to go
+    // back to if the user changes their mind
+    ConfirmQuit(Box<InputMode>),
+    TrashViewer,      // Browsing deleted tasks and columns to restore
+    ConfirmRevert,    // Confirm reloading the board from its .bak backup
+    BoardInfo,        // Read-only popup describing the board file
+    KeyHelp,          // Read-only popup listing every key of the board view
+    BoardStats,       // Read-only popup with totals for the board
+    TaskDetail,       // Read-only popup showing everything about the selected task
+    MovingTaskByName, // Typing the name of the column to move the task to
+    BoardSelection,   // New mode for board selection popup
+    AddingBoard,      // New mode for creating a new board
     ColumnSelectionMode,
     JumpToColumnMode,
     JumpToTaskMode,
     RenamingColumn,
     RenamingTask,
+    EditingPriority(PriorityField), // Typing a new value for one priority field
+    EditingWipLimit,                // Typing the active column's WIP limit; empty clears it
+    ConfirmWipOverride(usize),      // Confirm moving a task into a full column
+    Filtering,                      // Typing a filter expression
+    SendingTaskToBoard,             // Typing "board: column" to send the task to
+    JumpToTaskId,                   // Typing the ID of a task to select
+    SplittingTask,                  // Typing the title of the second half of a task
+    ImportingCsv,                   // Typing "path" or "path: column" of a CSV to import
 }
 
 // Define the application structure with added storage fields
@@ -34,190 +360,397 @@ pub struct App {
     pub title: String,
     pub columns: Vec<Column>,
     pub active_column: usize,
-    pub scroll_offset: usize,
+    pub scroll_offset: usize, // Position of the leftmost visible column in shown_columns()
+    // Active column when the user last scrolled by hand; the view stays
+    // where they put it until the active column changes
+    pub manual_scroll: Option<usize>,
     pub input_mode: InputMode,
     pub input_text: String,
     pub start_index: usize,
     // Storage fields
     pub file_path: Option<String>,
     // Board selection fields
-    pub available_boards: Vec<String>,
+    pub available_boards: Vec<String>, // Real boards only; see board_entries()
+    pub recent_boards: Vec<String>,    // Most recently opened first
     pub selected_board_index: Option<usize>,
+    // Transient highlight for the last moved/created task (task ID, when),
+    // by ID so it follows the task through sorts, filters and other moves
+    pub changed_task: Option<(usize, Instant)>,
+    // One-line feedback shown in the status line until the next key press
+    pub status_message: Option<String>,
+    // When the board was last explicitly saved, used to flash the title bar
+    pub saved_at: Option<Instant>,
+    // User settings from the config file
+    pub config: Config,
+    // Snapshots of earlier board states for undo
+    pub history: History<Snapshot>,
+    // Tab-completion state for column names: (typed prefix, candidate index)
+    pub completion: Option<(String, usize)>,
+    // Modification time of the board file as of our last load or save
+    pub file_modified: Option<SystemTime>,
+    // Whether there are changes that haven't been written to the file yet
+    pub dirty: bool,
+    // Per-board display settings kept next to the board file
+    pub view_state: ViewState,
+    // Hides tasks that don't match; never changes the board
+    pub filter: Option<TaskFilter>,
+    // Show tasks grouped by their first tag instead of by column
+    pub tag_view: bool,
+    // Show every task in one list ordered by priority instead of by column
+    pub priority_view: bool,
+    // Leave columns without tasks out of the layout (the active one stays)
+    pub hide_empty_columns: bool,
+    // Set for boards piped in on stdin, which have nowhere to be saved
+    pub read_only: bool,
+    // The board file's Description line, kept so saving doesn't replace it
+    pub description: String,
+    // Format version of the board file as it was last read or written
+    pub format_version: u32,
+    // Task text formatted in earlier frames, reused while unchanged
+    pub task_text_cache: TaskTextCache,
+    // First task drawn in each column, by column index, kept between frames
+    // so a column only scrolls when its selection leaves the view
+    pub task_scroll: RefCell<HashMap<usize, usize>>,
+    // The same for each group of the tag view, by tag
+    pub tag_scroll: RefCell<HashMap<String, usize>>,
+    // First line shown by the info or key help popup, for long contents
+    pub info_scroll: usize,
+    // Statistics shown by the stats popup, computed when it opens
+    pub board_stats: Option<BoardStats>,
+    // Running focus session, ticked by the event loop
+    pub focus_timer: Option<FocusTimer>,
+    // Entries shown by the trash viewer, newest first, and the selected one
+    pub trash: Vec<TrashEntry>,
+    pub selected_trash_index: usize,
+    // First-run help drawn over the board picker until a key dismisses it
+    pub show_onboarding: bool,
+    // Boards switched away from with changes that couldn't be saved
+    pub unsaved_boards: Vec<UnsavedBoard>,
+    // What changed since the last save, shown when quitting with unsaved changes
+    pub unsaved_changes: ChangeSummary,
 }
 
 impl App {
     pub fn new(title: &str) -> App {
+        let config = Config::load();
+        let history = History::new(config.undo_depth);
+
         let mut app = App {
             title: title.to_string(),
             columns: vec![Column {
                 title: "To Do".to_string(),
                 tasks: vec![
                     Task {
+                        id: 1,
                         title: "Implement UI".to_string(),
                         description: None,
-                        priority: Some(5),
+                        priority: Some(Task::default_priority()),
+                        completed_at: None,
+                        tags: Vec::new(),
+                        due: None,
+                        created: None,
+                        focus_minutes: 0,
                     },
                     Task {
+                        id: 2,
                         title: "Add task functionality".to_string(),
                         description: None,
-                        priority: Some(5),
+                        priority: Some(Task::default_priority()),
+                        completed_at: None,
+                        tags: Vec::new(),
+                        due: None,
+                        created: None,
+                        focus_minutes: 0,
                     },
                 ],
                 selected_task: Some(0), // Select the first task by default
+                wip_limit: None,
             }],
             active_column: 0,
             start_index: 0,
             scroll_offset: 0,
+            manual_scroll: None,
             input_mode: InputMode::BoardSelection, // Start in board selection mode
             input_text: String::new(),
             file_path: None,
             available_boards: Vec::new(),
+            recent_boards: Vec::new(),
             selected_board_index: Some(0), // Select first board by default
+            changed_task: None,
+            status_message: None,
+            saved_at: None,
+            config,
+            history,
+            completion: None,
+            file_modified: None,
+            dirty: false,
+            view_state: ViewState::default(),
+            filter: None,
+            tag_view: false,
+            priority_view: false,
+            hide_empty_columns: false,
+            read_only: false,
+            description: DEFAULT_BOARD_DESCRIPTION.to_string(),
+            format_version: crud::FORMAT_VERSION,
+            task_text_cache: TaskTextCache::default(),
+            task_scroll: RefCell::new(HashMap::new()),
+            tag_scroll: RefCell::new(HashMap::new()),
+            info_scroll: 0,
+            board_stats: None,
+            focus_timer: None,
+            trash: Vec::new(),
+            selected_trash_index: 0,
+            show_onboarding: false,
+            unsaved_boards: Vec::new(),
+            unsaved_changes: ChangeSummary::default(),
         };
 
-        // Initialize board selection
-        app.scan_available_boards();
+        // Initialize board selection. Without KANBAN_DIR there is simply
+        // nothing to list, but a bad KANBAN_DIR is worth pointing out.
+        if let Err(e @ KanbanError::NotADirectory(_)) = app.scan_available_boards() {
+            app.status_message = Some(e.to_string());
+        }
+        let dismissed = kanban_dir().is_ok_and(|dir| dir.join(ONBOARDING_FLAG_FILE_NAME).exists());
+        app.show_onboarding = should_show_onboarding(&app.available_boards, dismissed);
 
         app
     }
 
+    /// Hide the first-run help for good. The flag file lives in KANBAN_DIR,
+    /// so without one the help is only hidden for this session.
+    pub fn dismiss_onboarding(&mut self) {
+        self.show_onboarding = false;
+        if let Ok(dir) = kanban_dir() {
+            let _ = std::fs::write(dir.join(ONBOARDING_FLAG_FILE_NAME), "");
+        }
+    }
+
     // Scan for available board files in KANBAN_DIR
-    pub fn scan_available_boards(&mut self) -> Result<(), std::io::Error> {
+    pub fn scan_available_boards(&mut self) -> Result<(), KanbanError> {
         self.available_boards.clear();
 
-        // Check for KANBAN_DIR environment variable
-        let kanban_dir = match std::env::var("KANBAN_DIR") {
-            Ok(dir) => dir,
-            Err(_) => {
-                // If KANBAN_DIR is not set, return without scanning
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "KANBAN_DIR environment variable not set",
-                ));
-            }
-        };
+        // If KANBAN_DIR is not set or unusable, return without scanning
+        let kanban_dir = kanban_dir()?;
+        let dir_path = kanban_dir.as_path();
 
-        // Create directory if it doesn't exist
-        let dir_path = std::path::Path::new(&kanban_dir);
-        if !dir_path.exists() {
-            std::fs::create_dir_all(dir_path)?;
-        }
+        // Scan directory for board files
+        self.available_boards = scan_board_dir(dir_path)?;
 
-        // Scan directory for .txt files
-        for entry in std::fs::read_dir(dir_path)? {
-            let entry = entry?;
-            let path = entry.path();
+        self.recent_boards = read_recent_boards(dir_path);
+        sort_boards(
+            &mut self.available_boards,
+            dir_path,
+            &self.recent_boards,
+            self.config.picker_sort,
+        );
 
-            // Check if it's a file with .txt extension
-            if path.is_file() && path.extension().map_or(false, |ext| ext == "txt") {
-                // Extract board name from filename
-                if let Some(file_name) = path.file_stem() {
-                    if let Some(name) = file_name.to_str() {
-                        // Convert from snake_case to a readable format
-                        let display_name = name.replace("_", " ").to_string();
-                        self.available_boards.push(display_name);
-                    }
-                }
-            }
-        }
+        // Reset the selection; there is always at least the create entry
+        self.selected_board_index = Some(0);
 
-        // Sort boards alphabetically
-        self.available_boards.sort();
+        Ok(())
+    }
 
-        // Add a "Create New Board" option at the end
-        self.available_boards.push("[Create New Board]".to_string());
+    /// Empty columns a new board starts with, named by the config
+    fn seed_columns(&self) -> Vec<Column> {
+        self.config
+            .seed_columns
+            .iter()
+            .map(|title| Column {
+                title: title.clone(),
+                tasks: Vec::new(),
+                selected_task: None,
+                wip_limit: None,
+            })
+            .collect()
+    }
 
-        // Reset selected index to 0 if there are boards
-        if !self.available_boards.is_empty() {
-            self.selected_board_index = Some(0);
-        } else {
-            self.selected_board_index = None;
-        }
+    /// Everything shown in the board picker: the boards, then the option to
+    /// create a new one
+    pub fn board_entries(&self) -> Vec<BoardEntry<'_>> {
+        self.available_boards
+            .iter()
+            .map(|name| BoardEntry::Board(name))
+            .chain(std::iter::once(BoardEntry::CreateNew))
+            .collect()
+    }
 
-        Ok(())
+    /// The picker entry under the selection, if any
+    pub fn selected_board_entry(&self) -> Option<BoardEntry<'_>> {
+        let index = self.selected_board_index?;
+        match self.available_boards.get(index) {
+            Some(name) => Some(BoardEntry::Board(name)),
+            None if index == self.available_boards.len() => Some(BoardEntry::CreateNew),
+            None => None,
+        }
     }
 
     // Create and load a new board
-    pub fn create_new_board(&mut self, title: &str) -> Result<(), std::io::Error> {
+    pub fn create_new_board(&mut self, title: &str) -> Result<(), KanbanError> {
+        validate_board_name(title)?;
+        let kanban_dir = kanban_dir()?;
+
+        // A "category / name" title places the board in a subfolder
+        let dir_path = kanban_dir.as_path();
+        let file_path = board_file_path(dir_path, title)?;
+        // Never overwrite an existing board with an empty one
+        if file_path.exists() {
+            return Err(KanbanError::Collision(format!("Board '{}'", title)));
+        }
+        self.save_selection();
+        self.keep_unsaved_board();
+
         self.title = title.to_string();
 
-        // Reset to default columns
-        self.columns = vec![Column {
-            title: "To Do".to_string(),
-            tasks: Vec::new(),
-            selected_task: None,
-        }];
+        // Reset to the configured seed columns
+        self.columns = self.seed_columns();
+        self.reset_history();
+        self.task_text_cache.clear();
+        self.view_state = ViewState::default();
 
         self.active_column = 0;
 
-        // Create filename from board title
-        let kanban_dir = std::env::var("KANBAN_DIR").map_err(|_| {
-            std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "KANBAN_DIR environment variable not set",
-            )
-        })?;
-
-        let dir_path = std::path::Path::new(&kanban_dir);
-        let file_name = format!("{}.txt", title.replace(" ", "_").to_lowercase());
-        let file_path = dir_path.join(file_name);
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
 
         // Store the full file path
         self.file_path = Some(file_path.to_string_lossy().to_string());
+        self.read_only = false;
+        self.focus_timer = None;
 
         // Save the new board
         self.save_board()?;
+        self.remember_board(dir_path, title);
 
         Ok(())
     }
 
     // Load selected board
-    pub fn load_selected_board(&mut self) -> Result<(), std::io::Error> {
-        if let Some(index) = self.selected_board_index {
-            // Check if it's the "Create New Board" option
-            if index == self.available_boards.len() - 1 {
+    pub fn load_selected_board(&mut self) -> Result<(), KanbanError> {
+        match self.selected_board_entry() {
+            None => {}
+            Some(BoardEntry::CreateNew) => {
                 // Switch to board creation mode
                 self.input_mode = InputMode::AddingBoard;
                 self.input_text.clear();
-                return Ok(());
             }
-
-            // Get selected board name
-            if let Some(board_name) = self.available_boards.get(index) {
-                // Convert display name back to filename
-                let file_name = format!("{}.txt", board_name.replace(" ", "_").to_lowercase());
+            Some(BoardEntry::Board(board_name)) => {
+                let board_name = board_name.to_string();
 
                 // Get KANBAN_DIR
-                let kanban_dir = std::env::var("KANBAN_DIR").map_err(|_| {
-                    std::io::Error::new(
-                        std::io::ErrorKind::NotFound,
-                        "KANBAN_DIR environment variable not set",
-                    )
-                })?;
+                let kanban_dir = kanban_dir()?;
 
-                let dir_path = std::path::Path::new(&kanban_dir);
-                let file_path = dir_path.join(file_name);
+                // Convert display name back to filename, including any category folders
+                let dir_path = kanban_dir.as_path();
+                let file_path = board_file_path(dir_path, &board_name)?;
+
+                // Leave the current board where it can be picked up again
+                self.save_selection();
+                self.keep_unsaved_board();
 
                 // Store the full file path
                 self.file_path = Some(file_path.to_string_lossy().to_string());
+                self.focus_timer = None;
 
                 // Update the title
-                self.title = board_name.clone();
+                self.title = board_name;
 
-                // Load the board
+                // Load the board, which asks to merge any duplicate columns
+                self.input_mode = InputMode::Normal;
                 self.load_board()?;
+                self.restore_selection();
+                self.remember_board(dir_path, &self.title.clone());
 
-                // Switch to normal mode
-                self.input_mode = InputMode::Normal;
+                if self.input_mode == InputMode::Normal {
+                    self.check_journal();
+                }
             }
         }
 
         Ok(())
     }
 
-    // Rest of the App implementation...
-    pub fn add_column(&mut self, title: &str) {
-        // Ensure the column name is unique
+    // Put a board at the front of the recent list and persist the list.
+    // Failing to write it only costs the history, so errors are ignored.
+    fn remember_board(&mut self, dir_path: &std::path::Path, board_name: &str) {
+        push_recent_board(&mut self.recent_boards, board_name);
+        let _ = write_board_list(&dir_path.join(RECENT_BOARDS_FILE_NAME), &self.recent_boards);
+    }
+
+    /// Save the current board and open the one that was open before it,
+    /// like vim's alternate file
+    pub fn switch_to_previous_board(&mut self) -> Result<(), KanbanError> {
+        let Some(previous) = previous_board(&self.recent_boards, &self.title) else {
+            self.status_message = Some("No previous board to switch to".to_string());
+            return Ok(());
+        };
+        let previous = previous.to_string();
+        if self.dirty && self.file_path.is_some() {
+            self.save_board()?;
+        }
+
+        self.scan_available_boards()?;
+        let Some(index) = self
+            .available_boards
+            .iter()
+            .position(|name| *name == previous)
+        else {
+            return Err(KanbanError::NotFound(format!("Board '{}'", previous)));
+        };
+        self.selected_board_index = Some(index);
+        self.load_selected_board()
+    }
+
+    /// The first pair of columns sharing a name, in board order
+    pub fn find_duplicate_columns(&self) -> Option<(usize, usize)> {
+        for (first, column) in self.columns.iter().enumerate() {
+            if let Some(offset) = self.columns[first + 1..]
+                .iter()
+                .position(|other| other.title == column.title)
+            {
+                return Some((first, first + 1 + offset));
+            }
+        }
+        None
+    }
+
+    /// Move all tasks of column `from` to the end of column `into` and
+    /// remove `from`. Returns false if the indices don't name two columns.
+    pub fn merge_columns(&mut self, into: usize, from: usize) -> bool {
+        if into == from || into >= self.columns.len() || from >= self.columns.len() {
+            return false;
+        }
+        self.record_undo();
+
+        let removed = self.columns.remove(from);
+        let into = if from < into { into - 1 } else { into };
+        let target = &mut self.columns[into];
+        target.tasks.extend(removed.tasks);
+        if target.selected_task.is_none() && !target.tasks.is_empty() {
+            target.selected_task = Some(0);
+        }
+        let description = format!("Merged column '{}' into '{}'", removed.title, target.title);
+
+        // Keep the same column active, following it to its new index
+        if self.active_column == from {
+            self.active_column = into;
+        } else if self.active_column > from {
+            self.active_column -= 1;
+        }
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if i != self.active_column {
+                column.selected_task = None;
+            }
+        }
+
+        // Save changes to file
+        let _ = self.save_board();
+        self.log_change(&description);
+        true
+    }
+
+    // Ensure the column name is unique by appending a counter if needed
+    fn unique_column_name(&self, title: &str) -> String {
         let mut unique_name = title.to_string();
         let mut counter = 1;
 
@@ -226,48 +759,245 @@ impl App {
             counter += 1;
         }
 
+        unique_name
+    }
+
+    // Rest of the App implementation...
+    pub fn add_column(&mut self, title: &str) {
+        self.record_undo();
+        let unique_name = self.unique_column_name(title);
+
         self.columns.push(Column {
-            title: unique_name,
+            title: unique_name.clone(),
             tasks: Vec::new(),
             selected_task: None, // No tasks selected in a new empty column
+            wip_limit: None,
         });
 
         // Save changes to file
         let _ = self.save_board();
+        self.log_change(&format!("Added column '{}'", unique_name));
+
+        // Exit input mode
+        self.input_mode = InputMode::Normal;
+        self.input_text.clear();
+    }
+
+    /// Insert a new column at `index` (clamped to the end) and make it active
+    pub fn insert_column(&mut self, index: usize, title: &str) {
+        self.record_undo();
+        let unique_name = self.unique_column_name(title);
+        let index = index.min(self.columns.len());
+
+        self.columns.insert(
+            index,
+            Column {
+                title: unique_name.clone(),
+                tasks: Vec::new(),
+                selected_task: None,
+                wip_limit: None,
+            },
+        );
+        self.active_column = index;
+
+        // Clear selection in all non-active columns
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if i != self.active_column {
+                column.selected_task = None;
+            }
+        }
+
+        // Save changes to file
+        let _ = self.save_board();
+        self.log_change(&format!(
+            "Inserted column '{}' at position {}",
+            unique_name,
+            index + 1
+        ));
 
         // Exit input mode
         self.input_mode = InputMode::Normal;
         self.input_text.clear();
     }
 
+    /// Insert a copy of the active column, tasks included, right after it
+    /// and make the copy active. The copied tasks get fresh IDs so they stay
+    /// distinct from the originals.
+    pub fn duplicate_column(&mut self) {
+        let Some(source) = self.columns.get(self.active_column) else {
+            return;
+        };
+        let source_title = source.title.clone();
+        let mut copy = source.clone();
+        copy.title = self.unique_column_name(&format!("{} (copy)", source_title));
+        let first_id = self.next_task_id();
+        for (offset, task) in copy.tasks.iter_mut().enumerate() {
+            task.id = first_id + offset;
+        }
+
+        self.record_undo();
+        let index = self.active_column + 1;
+        let description = format!("Duplicated column '{}' as '{}'", source_title, copy.title);
+        self.columns.insert(index, copy);
+        self.active_column = index;
+
+        // Clear selection in all non-active columns
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if i != self.active_column {
+                column.selected_task = None;
+            }
+        }
+
+        let _ = self.save_board();
+        self.log_change(&description);
+    }
+
+    /// Open the add-task prompt, unless there is no column to add to
+    pub fn start_adding_task(&mut self) {
+        if self.columns.is_empty() {
+            self.status_message = Some("No columns yet - add one with 'ac' first".to_string());
+            return;
+        }
+        self.input_mode = InputMode::AddingTask;
+    }
+
     pub fn add_task(&mut self, title: &str) {
+        let title = &sanitize_title(title);
+        if self.columns.get(self.active_column).is_some() {
+            self.record_undo();
+        }
+        let id = self.next_task_id();
         if let Some(column) = self.columns.get_mut(self.active_column) {
             let new_task = Task {
+                id,
                 title: title.to_string(),
                 description: None,
-                priority: Some(5),
+                priority: Some(Task::default_priority()),
+                completed_at: None,
+                tags: Vec::new(),
+                due: None,
+                created: None,
+                focus_minutes: 0,
             };
 
             column.tasks.push(new_task);
 
             // Select the newly added task in the active column
             column.selected_task = Some(column.tasks.len() - 1);
+            self.changed_task = Some((id, Instant::now()));
+            let description = format!("Added task '{}' to '{}'", title, column.title);
+            let op = JournalOp::Add {
+                id,
+                column: column.title.clone(),
+                title: title.to_string(),
+            };
 
             // Save changes to file
+            self.journal(op);
             let _ = self.save_board();
+            self.log_change(&description);
 
-            // Exit input mode
-            self.input_mode = InputMode::Normal;
+            // Exit input mode, unless the user wants to keep adding tasks
+            if !self.config.sticky_add {
+                self.input_mode = InputMode::Normal;
+            }
             self.input_text.clear();
         }
     }
 
+    /// Handle text pasted from the terminal. In add-task mode every
+    /// non-empty line becomes its own task; in other text prompts the
+    /// paste is appended as a single line.
+    pub fn paste_text(&mut self, text: &str) {
+        match self.input_mode {
+            InputMode::AddingTask if text.contains('\n') => {
+                let combined = format!("{}{}", self.input_text, text);
+                let titles: Vec<&str> = combined
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .collect();
+                self.add_tasks(&titles);
+            }
+            InputMode::AddingTask
+            | InputMode::AddingBoard
+            | InputMode::AddingColumn
+            | InputMode::InsertingColumn(_)
+            | InputMode::RenamingColumn
+            | InputMode::RenamingTask
+            | InputMode::MovingTaskByName
+            | InputMode::Filtering
+            | InputMode::SendingTaskToBoard
+            | InputMode::SplittingTask
+            | InputMode::ImportingCsv => {
+                let line = text.lines().collect::<Vec<_>>().join(" ");
+                self.input_text.push_str(&line);
+            }
+            _ => {}
+        }
+    }
+
+    // Add several tasks to the active column as a single undoable edit
+    fn add_tasks(&mut self, titles: &[&str]) {
+        let titles: Vec<String> = titles
+            .iter()
+            .map(|title| sanitize_title(title))
+            .filter(|title| !title.is_empty())
+            .collect();
+        if titles.is_empty() || self.columns.get(self.active_column).is_none() {
+            return;
+        }
+        self.record_undo();
+        let first_id = self.next_task_id();
+        let column = &mut self.columns[self.active_column];
+        for (offset, title) in titles.iter().enumerate() {
+            column.tasks.push(Task {
+                id: first_id + offset,
+                title: title.clone(),
+                description: None,
+                priority: Some(Task::default_priority()),
+                completed_at: None,
+                tags: Vec::new(),
+                due: None,
+                created: None,
+                focus_minutes: 0,
+            });
+        }
+        column.selected_task = Some(column.tasks.len() - 1);
+        self.changed_task = Some((first_id + titles.len() - 1, Instant::now()));
+        let descriptions: Vec<String> = titles
+            .iter()
+            .map(|title| format!("Added task '{}' to '{}'", title, column.title))
+            .collect();
+        let column_title = column.title.clone();
+
+        for (offset, title) in titles.iter().enumerate() {
+            self.journal(JournalOp::Add {
+                id: first_id + offset,
+                column: column_title.clone(),
+                title: title.clone(),
+            });
+        }
+        let _ = self.save_board();
+        for description in &descriptions {
+            self.log_change(description);
+        }
+
+        if !self.config.sticky_add {
+            self.input_mode = InputMode::Normal;
+        }
+        self.input_text.clear();
+    }
+
     pub fn delete_current_task(&mut self) {
+        self.record_undo();
         if let Some(column) = self.columns.get_mut(self.active_column) {
             if let Some(task_idx) = column.selected_task {
                 if task_idx < column.tasks.len() {
                     // Remove the task
-                    column.tasks.remove(task_idx);
+                    let task = column.tasks.remove(task_idx);
+                    let description =
+                        format!("Deleted task '{}' from '{}'", task.title, column.title);
 
                     // Adjust the selection
                     if column.tasks.is_empty() {
@@ -277,108 +1007,436 @@ impl App {
                         column.selected_task = Some(column.tasks.len() - 1);
                     }
 
+                    let column_title = column.title.clone();
+
                     // Save changes to file
+                    self.journal(JournalOp::Delete { id: task.id });
                     let _ = self.save_board();
+                    self.log_change(&description);
+                    self.move_to_trash(TrashKind::Tasks, &column_title, None, vec![task]);
                 }
             }
         }
     }
 
-    pub fn delete_current_column(&mut self) {
-        if self.columns.is_empty() {
-            return;
+    /// Open the prompt for sending the selected task to another board
+    pub fn prepare_send_task(&mut self) {
+        if self.selected_task().is_some() {
+            self.input_text.clear();
+            self.input_mode = InputMode::SendingTaskToBoard;
         }
-        // Remove the active column.
-        self.columns.remove(self.active_column);
-        // Adjust active_column if needed.
-        if self.active_column >= self.columns.len() && !self.columns.is_empty() {
-            self.active_column = self.columns.len() - 1;
+    }
+
+    /// Open the prompt for splitting the selected task in two
+    pub fn prepare_split_task(&mut self) {
+        if self.selected_task().is_some() {
+            self.input_text.clear();
+            self.input_mode = InputMode::SplittingTask;
+        }
+    }
+
+    /// Split the selected task: a new task called `new_title` is inserted
+    /// right after it, with the same priority, tags and due date, and
+    /// becomes the selection. The original keeps its title.
+    pub fn split_task(&mut self, new_title: &str) {
+        let new_title = &sanitize_title(new_title);
+        if new_title.is_empty() {
+            self.status_message = Some("The new task needs a title".to_string());
+            return;
         }
+        let Some(original) = self.selected_task().cloned() else {
+            return;
+        };
+        self.record_undo();
+        let new_task = Task {
+            id: self.next_task_id(),
+            title: new_title.to_string(),
+            description: None,
+            priority: original.priority.clone(),
+            completed_at: None,
+            tags: original.tags.clone(),
+            due: original.due.clone(),
+            created: None,
+            focus_minutes: 0,
+        };
+
+        let column = &mut self.columns[self.active_column];
+        let new_idx = column
+            .selected_task
+            .map_or(column.tasks.len(), |idx| idx + 1);
+        column.tasks.insert(new_idx, new_task);
+        column.selected_task = Some(new_idx);
+        self.changed_task = Some((column.tasks[new_idx].id, Instant::now()));
+        let description = format!(
+            "Split task '{}' into '{}' in '{}'",
+            original.title, new_title, column.title
+        );
 
-        // Save changes to file
         let _ = self.save_board();
+        self.log_change(&description);
+
+        self.input_mode = InputMode::Normal;
+        self.input_text.clear();
     }
 
-    pub fn select_prev_column(&mut self) {
-        if self.active_column > 0 {
-            self.active_column -= 1;
+    /// Delete the selected task, if any, asking first unless the config
+    /// turns that confirmation off
+    pub fn request_delete_task(&mut self) {
+        if self.selected_task_title().is_none() {
+            return;
+        }
+        if self.config.confirmations.delete_task {
+            self.input_mode = InputMode::ConfirmDeleteTask;
+        } else {
+            self.delete_current_task();
+        }
+    }
 
-            // Clear selection in all non-active columns
-            for (i, column) in self.columns.iter_mut().enumerate() {
-                if i != self.active_column {
-                    column.selected_task = None;
-                }
-            }
+    /// Delete the active column, asking first unless the config turns that
+    /// confirmation off
+    pub fn request_delete_column(&mut self) {
+        if self.columns.is_empty() {
+            return;
+        }
+        if self.config.confirmations.delete_column {
+            self.input_mode = InputMode::ConfirmDeleteColumn;
+        } else {
+            self.delete_current_column();
         }
     }
 
-    pub fn select_next_column(&mut self) {
-        if self.active_column < self.columns.len().saturating_sub(1) {
-            self.active_column += 1;
+    /// Empty the active column, if it has any tasks, asking first unless
+    /// the config turns that confirmation off
+    pub fn request_clear_column(&mut self) {
+        if self
+            .columns
+            .get(self.active_column)
+            .is_none_or(|column| column.tasks.is_empty())
+        {
+            return;
+        }
+        if self.config.confirmations.clear_column {
+            self.input_mode = InputMode::ConfirmClearColumn;
+        } else {
+            self.clear_column();
+        }
+    }
 
-            // Clear selection in all non-active columns
-            for (i, column) in self.columns.iter_mut().enumerate() {
-                if i != self.active_column {
-                    column.selected_task = None;
-                }
-            }
+    /// What the quit confirmation says is unsaved: this board's changes
+    /// since its last save, then any other boards left with unsaved changes
+    pub fn unsaved_summary(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.dirty {
+            lines.push(
+                self.unsaved_changes
+                    .describe()
+                    .unwrap_or_else(|| "Unsaved changes to this board".to_string()),
+            );
         }
+        match self.unsaved_boards.len() {
+            0 => {}
+            1 => lines.push("1 other board with unsaved changes".to_string()),
+            n => lines.push(format!("{} other boards with unsaved changes", n)),
+        }
+        lines
     }
 
-    // Board selection navigation
-    pub fn select_prev_board(&mut self) {
-        if let Some(index) = self.selected_board_index {
-            if index > 0 {
-                self.selected_board_index = Some(index - 1);
-            }
+    /// Whether the app can quit right away. Edits are saved as they happen,
+    /// so this only asks when some of them couldn't be written.
+    pub fn request_quit(&mut self) -> bool {
+        let unsaved = self.dirty || !self.unsaved_boards.is_empty();
+        if unsaved && self.config.confirmations.quit_unsaved {
+            let previous = std::mem::replace(&mut self.input_mode, InputMode::Normal);
+            self.input_mode = InputMode::ConfirmQuit(Box::new(previous));
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Leave the quit prompt for the mode that asked to quit
+    pub fn cancel_quit(&mut self) {
+        self.input_mode = match std::mem::replace(&mut self.input_mode, InputMode::Normal) {
+            InputMode::ConfirmQuit(previous) => *previous,
+            mode => mode,
+        };
+    }
+
+    /// Remove every task from the active column, keeping the column itself
+    pub fn clear_column(&mut self) {
+        let Some(column) = self.columns.get(self.active_column) else {
+            return;
+        };
+        if column.tasks.is_empty() {
+            return;
+        }
+        self.record_undo();
+        let column = &mut self.columns[self.active_column];
+        let description = format!(
+            "Cleared {} task(s) from '{}'",
+            column.tasks.len(),
+            column.title
+        );
+        let tasks = std::mem::take(&mut column.tasks);
+        let column_title = column.title.clone();
+        column.selected_task = None;
+
+        let _ = self.save_board();
+        self.log_change(&description);
+        self.move_to_trash(TrashKind::Tasks, &column_title, None, tasks);
+    }
+
+    /// Title of the selected task in the active column
+    pub fn selected_task_title(&self) -> Option<&str> {
+        let column = self.columns.get(self.active_column)?;
+        let task = column.tasks.get(column.selected_task?)?;
+        Some(&task.title)
+    }
+
+    pub fn delete_current_column(&mut self) {
+        if self.columns.is_empty() {
+            return;
+        }
+        self.record_undo();
+        // Remove the active column.
+        let removed = self.columns.remove(self.active_column);
+        // Adjust active_column if needed.
+        if self.active_column >= self.columns.len() && !self.columns.is_empty() {
+            self.active_column = self.columns.len() - 1;
+        }
+
+        // Save changes to file
+        let _ = self.save_board();
+        self.log_change(&format!("Deleted column '{}'", removed.title));
+        self.move_to_trash(
+            TrashKind::Column,
+            &removed.title,
+            removed.wip_limit,
+            removed.tasks,
+        );
+    }
+
+    pub fn select_prev_column(&mut self) {
+        let shown = self.shown_columns();
+        let position = self.shown_position(&shown);
+        let wrap = self.config.wrap_navigation && shown.len() > 1;
+        let target = match position.checked_sub(1) {
+            Some(prev) => shown[prev],
+            None if wrap => shown[shown.len() - 1],
+            None => return,
+        };
+        self.activate_column(target);
+    }
+
+    pub fn select_next_column(&mut self) {
+        let shown = self.shown_columns();
+        let position = self.shown_position(&shown);
+        let wrap = self.config.wrap_navigation && shown.len() > 1;
+        let target = if position + 1 < shown.len() {
+            shown[position + 1]
+        } else if wrap {
+            shown[0]
+        } else {
+            return;
+        };
+        self.activate_column(target);
+    }
+
+    /// Indices of the columns drawn on the board, left to right. With empty
+    /// columns hidden this skips columns without tasks, except the active
+    /// one; a hidden column comes back as soon as a task is added to it.
+    pub fn shown_columns(&self) -> Vec<usize> {
+        (0..self.columns.len())
+            .filter(|&idx| {
+                !self.hide_empty_columns
+                    || idx == self.active_column
+                    || !self.columns[idx].tasks.is_empty()
+            })
+            .collect()
+    }
+
+    // Where the active column sits in `shown`
+    fn shown_position(&self, shown: &[usize]) -> usize {
+        shown
+            .iter()
+            .position(|&idx| idx == self.active_column)
+            .unwrap_or(0)
+    }
+
+    /// Hide or show the columns that have no tasks
+    pub fn toggle_hide_empty_columns(&mut self) {
+        self.hide_empty_columns = !self.hide_empty_columns;
+        self.status_message = Some(if self.hide_empty_columns {
+            "Hiding empty columns".to_string()
+        } else {
+            "Showing all columns".to_string()
+        });
+    }
+
+    /// Switch between one row per task and full task cards
+    pub fn toggle_compact_tasks(&mut self) {
+        self.config.compact_tasks = !self.config.compact_tasks;
+        self.status_message = Some(if self.config.compact_tasks {
+            "Showing one line per task".to_string()
+        } else {
+            "Showing task cards".to_string()
+        });
+    }
+
+    /// Jump to the leftmost column with any (visible) tasks and select its
+    /// first one. On a board with no tasks anywhere nothing changes.
+    pub fn focus_first_nonempty_column(&mut self) {
+        let Some(target) =
+            (0..self.columns.len()).find(|&idx| !self.visible_task_indices(idx).is_empty())
+        else {
+            self.status_message = Some("Every column is empty".to_string());
+            return;
+        };
+        let first_task = self.visible_task_indices(target)[0];
+        self.activate_column(target);
+        let column = &mut self.columns[target];
+        if column.selected_task.is_none() {
+            column.selected_task = Some(first_task);
+        }
+    }
+
+    /// Scroll the columns left (negative) or right without changing the
+    /// active column
+    pub fn scroll_columns(&mut self, delta: isize) {
+        let last = self.shown_columns().len().saturating_sub(1);
+        self.scroll_offset = self.scroll_offset.saturating_add_signed(delta).min(last);
+        self.manual_scroll = Some(self.active_column);
+    }
+
+    /// Settle `scroll_offset` before drawing, given how many columns fit.
+    /// A manual scroll is kept while the same column stays active; after
+    /// that the view only moves as far as needed to show the active column.
+    pub fn sync_scroll(&mut self, max_visible: usize) {
+        let shown = self.shown_columns();
+        let max_offset = shown.len().saturating_sub(max_visible);
+        if self.manual_scroll != Some(self.active_column) {
+            self.manual_scroll = None;
+            let position = self.shown_position(&shown);
+            if position >= self.scroll_offset + max_visible {
+                self.scroll_offset = position + 1 - max_visible;
+            } else if position < self.scroll_offset {
+                self.scroll_offset = position;
+            }
+        }
+        self.scroll_offset = self.scroll_offset.min(max_offset);
+    }
+
+    // Make a column active, clearing the selection in all the others
+    pub fn activate_column(&mut self, target: usize) {
+        self.active_column = target;
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            if i != self.active_column {
+                column.selected_task = None;
+            }
+        }
+    }
+
+    // Board selection navigation
+    pub fn select_prev_board(&mut self) {
+        if let Some(index) = self.selected_board_index {
+            if index > 0 {
+                self.selected_board_index = Some(index - 1);
+            }
         }
     }
 
     pub fn select_next_board(&mut self) {
         if let Some(index) = self.selected_board_index {
-            if index < self.available_boards.len() - 1 {
+            // The create entry comes after the last board
+            if index < self.available_boards.len() {
                 self.selected_board_index = Some(index + 1);
             }
         }
     }
 
-    // Task navigation methods
+    /// Move the selected board one place up or down in the picker and
+    /// persist the new order. The create entry always stays last.
+    pub fn move_selected_board(&mut self, up: bool) -> Result<(), KanbanError> {
+        let Some(BoardEntry::Board(_)) = self.selected_board_entry() else {
+            return Ok(());
+        };
+        if self.config.picker_sort != PickerSort::Custom {
+            self.status_message =
+                Some("Boards can only be reordered with picker_sort = custom".to_string());
+            return Ok(());
+        }
+        let Some(index) = self.selected_board_index else {
+            return Ok(());
+        };
+        let board_count = self.available_boards.len();
+
+        let target = if up {
+            match index.checked_sub(1) {
+                Some(target) => target,
+                None => return Ok(()),
+            }
+        } else if index + 1 < board_count {
+            index + 1
+        } else {
+            return Ok(());
+        };
+
+        self.available_boards.swap(index, target);
+        self.selected_board_index = Some(target);
+
+        write_board_order(&kanban_dir()?, &self.available_boards)?;
+        Ok(())
+    }
+
+    // Task navigation methods. Tasks hidden by the filter are skipped.
     pub fn select_prev_task(&mut self) {
+        let visible = self.visible_task_indices(self.active_column);
+        let wrap = self.config.wrap_navigation;
         if let Some(column) = self.columns.get_mut(self.active_column) {
-            if column.tasks.is_empty() {
+            if visible.is_empty() {
                 column.selected_task = None;
                 return;
             }
 
             match column.selected_task {
-                Some(current) if current > 0 => {
-                    column.selected_task = Some(current - 1);
+                Some(current) => {
+                    if let Some(&prev) = visible.iter().rev().find(|&&idx| idx < current) {
+                        column.selected_task = Some(prev);
+                    } else if wrap {
+                        // At the first task, so go round to the last
+                        column.selected_task = visible.last().copied();
+                    }
                 }
-                None if !column.tasks.is_empty() => {
+                None => {
                     // If no task is selected but there are tasks, select the last one
-                    column.selected_task = Some(column.tasks.len() - 1);
+                    column.selected_task = visible.last().copied();
                 }
-                _ => {} // Already at the first task or no tasks
             }
         }
     }
 
     pub fn select_next_task(&mut self) {
+        let visible = self.visible_task_indices(self.active_column);
+        let wrap = self.config.wrap_navigation;
         if let Some(column) = self.columns.get_mut(self.active_column) {
-            if column.tasks.is_empty() {
+            if visible.is_empty() {
                 column.selected_task = None;
                 return;
             }
 
             match column.selected_task {
-                Some(current) if current < column.tasks.len() - 1 => {
-                    column.selected_task = Some(current + 1);
+                Some(current) => {
+                    if let Some(&next) = visible.iter().find(|&&idx| idx > current) {
+                        column.selected_task = Some(next);
+                    } else if wrap {
+                        // At the last task, so go round to the first
+                        column.selected_task = visible.first().copied();
+                    }
                 }
-                None if !column.tasks.is_empty() => {
+                None => {
                     // If no task is selected but there are tasks, select the first one
-                    column.selected_task = Some(0);
+                    column.selected_task = visible.first().copied();
                 }
-                _ => {} // Already at the last task or no tasks
             }
         }
     }
@@ -411,46 +1469,562 @@ impl App {
         if target_column_idx >= self.columns.len() || target_column_idx == self.active_column {
             return;
         }
+        let source = &self.columns[self.active_column];
+        let Some(task_idx) = source.selected_task.filter(|&idx| idx < source.tasks.len()) else {
+            return;
+        };
+        let end = self.columns[target_column_idx].tasks.len();
+        let moved = self.relocate_task((self.active_column, task_idx), target_column_idx, end);
 
-        // Get source column and check if a task is selected
-        if let Some(src_column) = self.columns.get_mut(self.active_column) {
-            if let Some(task_idx) = src_column.selected_task {
-                if task_idx < src_column.tasks.len() {
-                    // Remove task from source column
-                    let task = src_column.tasks.remove(task_idx);
+        // Optionally follow the task to where it went
+        if moved.is_some() && self.config.follow_moved_task {
+            self.activate_column(target_column_idx);
+            self.columns[target_column_idx].selected_task = Some(end);
+        }
+    }
 
-                    // Update selection in source column
-                    if src_column.tasks.is_empty() {
-                        src_column.selected_task = None;
-                    } else if task_idx >= src_column.tasks.len() {
-                        src_column.selected_task = Some(src_column.tasks.len() - 1);
-                    }
+    /// Move the task with the given ID to `target_index` in the column
+    /// titled `target_column` (ignoring case). An index past the end puts
+    /// the task last, and moving a task onto its own position does nothing.
+    /// The task becomes the selected one in its column. Moving into another
+    /// column that is at its WIP limit fails unless the policy allows it,
+    /// since there is no one to ask.
+    pub fn move_task_to(
+        &mut self,
+        id: usize,
+        target_column: &str,
+        target_index: usize,
+    ) -> Result<(), KanbanError> {
+        let from = self
+            .find_task_by_id(id)
+            .ok_or_else(|| KanbanError::NotFound(format!("Task #{}", id)))?;
+        let to_column = self
+            .columns
+            .iter()
+            .position(|column| column.title.eq_ignore_ascii_case(target_column.trim()))
+            .ok_or_else(|| KanbanError::NotFound(format!("Column '{}'", target_column)))?;
+        if let Some(limit) = self.full_column_limit(to_column)
+            && from.0 != to_column
+            && self.config.wip_policy != WipPolicy::Allow
+        {
+            return Err(KanbanError::WipLimit {
+                column: self.columns[to_column].title.clone(),
+                limit,
+            });
+        }
+        if let Some(task_idx) = self.relocate_task(from, to_column, target_index) {
+            self.columns[to_column].selected_task = Some(task_idx);
+        }
+        Ok(())
+    }
+
+    /// Swap the selected task with the one below (or above) it
+    pub fn shift_selected_task(&mut self, down: bool) {
+        if self.selected_task().is_some_and(|task| task.id == 0) {
+            self.assign_missing_task_ids();
+        }
+        let Some(column) = self.columns.get(self.active_column) else {
+            return;
+        };
+        let Some(task_idx) = column.selected_task.filter(|&idx| idx < column.tasks.len()) else {
+            return;
+        };
+        let target = if down {
+            task_idx + 1
+        } else if let Some(above) = task_idx.checked_sub(1) {
+            above
+        } else {
+            return;
+        };
+        let id = column.tasks[task_idx].id;
+        let title = column.title.clone();
+        let _ = self.move_task_to(id, &title, target);
+    }
+
+    /// The one place tasks change position: take the task at `from`
+    /// (column, index) and insert it at `to_index` in `to_column`, clamped
+    /// to the end. Selections stay on the tasks they were on, except that a
+    /// selected task moved to another column leaves the selection on the
+    /// task that takes its place. Returns where the task ended up, or None
+    /// when it was already there.
+    fn relocate_task(
+        &mut self,
+        (from_column, from_index): (usize, usize),
+        to_column: usize,
+        to_index: usize,
+    ) -> Option<usize> {
+        let same_column = from_column == to_column;
+        let last = self.columns[to_column].tasks.len() - usize::from(same_column);
+        let to_index = to_index.min(last);
+        if same_column && from_index == to_index {
+            return None;
+        }
 
-                    // Add task to target column
-                    if let Some(target_column) = self.columns.get_mut(target_column_idx) {
-                        target_column.tasks.push(task);
+        // One snapshot covers the remove and the insert, so a single undo
+        // puts the task back at its original index in its original column
+        self.record_undo();
+        let into_done = self.is_done_column(to_column);
+        let from_done = self.is_done_column(from_column);
+        let source = &mut self.columns[from_column];
+        let followed = same_column && source.selected_task == Some(from_index);
+        let mut task = source.tasks.remove(from_index);
+        let source_title = source.title.clone();
 
-                        // Only update target column selection if we're making it the active column
-                        if self.active_column == target_column_idx {
-                            target_column.selected_task = Some(target_column.tasks.len() - 1);
-                        }
+        source.selected_task = match source.selected_task {
+            _ if source.tasks.is_empty() => None,
+            Some(idx) if idx > from_index => Some(idx - 1),
+            Some(idx) => Some(idx.min(source.tasks.len() - 1)),
+            None => None,
+        };
 
-                        // Save changes
-                        let _ = self.save_board();
-                    }
+        // Stamp tasks as they enter a done column, and clear the stamp when
+        // they leave for a column that isn't done
+        if !same_column && !into_done {
+            task.completed_at = None;
+        } else if !same_column && !from_done {
+            task.completed_at = Some(Local::now().format("%Y-%m-%d %H:%M").to_string());
+        }
+
+        let target = &mut self.columns[to_column];
+        let description = if same_column {
+            format!(
+                "Moved task '{}' to position {} in '{}'",
+                task.title,
+                to_index + 1,
+                target.title
+            )
+        } else {
+            format!(
+                "Moved task '{}' from '{}' to '{}'",
+                task.title, source_title, target.title
+            )
+        };
+        let op = JournalOp::Move {
+            id: task.id,
+            column: target.title.clone(),
+            index: to_index,
+        };
+        target.tasks.insert(to_index, task);
+        target.selected_task = match target.selected_task {
+            _ if followed => Some(to_index),
+            Some(idx) if idx >= to_index => Some(idx + 1),
+            selected => selected,
+        };
+        self.changed_task = Some((target.tasks[to_index].id, Instant::now()));
+
+        // Save changes
+        self.journal(op);
+        let _ = self.save_board();
+        self.log_change(&description);
+        Some(to_index)
+    }
+
+    /// Whether the column at `column_idx` is one of the configured done columns
+    pub fn is_done_column(&self, column_idx: usize) -> bool {
+        self.columns.get(column_idx).is_some_and(|column| {
+            self.config
+                .done_columns
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(&column.title))
+        })
+    }
+
+    /// One line per task in a done column, oldest completion first. Tasks
+    /// without a completion time (e.g. added straight to Done) come first.
+    pub fn done_summary_lines(&self) -> Vec<String> {
+        let mut done: Vec<(&str, &Task)> = Vec::new();
+        for (i, column) in self.columns.iter().enumerate() {
+            if self.is_done_column(i) {
+                for task in &column.tasks {
+                    done.push((&column.title, task));
                 }
             }
         }
+        done.sort_by(|(_, a), (_, b)| a.completed_at.cmp(&b.completed_at));
+
+        done.into_iter()
+            .map(|(column, task)| match &task.completed_at {
+                Some(when) => format!("{}  {} ({})", when, task.title, column),
+                None => format!("{:16}  {} ({})", "-", task.title, column),
+            })
+            .collect()
+    }
+
+    /// The WIP limit of a column, if it is limited and already full
+    pub fn full_column_limit(&self, column_idx: usize) -> Option<usize> {
+        let column = self.columns.get(column_idx)?;
+        column
+            .wip_limit
+            .filter(|&limit| column.tasks.len() >= limit)
+    }
+
+    /// Move the selected task, checking the target's WIP limit first. A full
+    /// column is handled according to the configured `WipPolicy`.
+    pub fn request_move_task_to_column(&mut self, target_column_idx: usize) {
+        self.input_mode = InputMode::Normal;
+
+        let Some(limit) = self.full_column_limit(target_column_idx) else {
+            self.move_task_to_column(target_column_idx);
+            return;
+        };
+
+        match self.config.wip_policy {
+            WipPolicy::Allow => self.move_task_to_column(target_column_idx),
+            WipPolicy::Confirm => {
+                self.input_mode = InputMode::ConfirmWipOverride(target_column_idx)
+            }
+            WipPolicy::Block => {
+                self.status_message = Some(format!(
+                    "{} is at its WIP limit of {}",
+                    self.columns[target_column_idx].title, limit
+                ))
+            }
+        }
+    }
+
+    /// Move the selected task to the other column holding the fewest tasks,
+    /// the leftmost one on a tie. Does nothing on a single-column board.
+    pub fn move_task_to_least_loaded_column(&mut self) {
+        if self.selected_task().is_none() {
+            return;
+        }
+        let target = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|&(idx, _)| idx != self.active_column)
+            .min_by_key(|&(idx, column)| (column.tasks.len(), idx))
+            .map(|(idx, _)| idx);
+        if let Some(target) = target {
+            self.request_move_task_to_column(target);
+        }
+    }
+
+    /// Act on the selected task as configured by `enter_action`
+    pub fn activate_selected_task(&mut self) {
+        if self.selected_task().is_none() {
+            return;
+        }
+        match self.config.enter_action {
+            EnterAction::Detail => self.input_mode = InputMode::TaskDetail,
+            EnterAction::Rename => self.prepare_rename_task(),
+            EnterAction::ToggleDone => self.toggle_task_done(),
+            EnterAction::Move => self.input_mode = InputMode::ColumnSelectionMode,
+        }
+    }
+
+    /// Move the selected task into the first done column, or from a done
+    /// column back to the first column that isn't one
+    pub fn toggle_task_done(&mut self) {
+        if self.selected_task().is_none() {
+            return;
+        }
+        let leaving_done = self.is_done_column(self.active_column);
+        let target = (0..self.columns.len()).find(|&idx| self.is_done_column(idx) != leaving_done);
+        match target {
+            Some(target) => self.request_move_task_to_column(target),
+            None if leaving_done => {
+                self.status_message = Some("No column to move the task back to".to_string())
+            }
+            None => self.status_message = Some("This board has no done column".to_string()),
+        }
+    }
+
+    /// Everything the detail popup shows about the selected task
+    pub fn task_detail_lines(&self) -> Vec<String> {
+        let Some(task) = self.selected_task() else {
+            return Vec::new();
+        };
+        let column = &self.columns[self.active_column].title;
+        let mut lines: Vec<String> = task.title.lines().map(str::to_string).collect();
+        lines.push(String::new());
+        lines.push(format!("Column: {}", column));
+        if task.id != 0 {
+            lines.push(format!("ID: {}", task.id));
+        }
+        if let Some(priority) = &task.priority {
+            let score = task
+                .computed_priority(self.config.priority_formula)
+                .map_or_else(|| "-".to_string(), |score| format!("{:.2}", score));
+            lines.push(format!(
+                "Priority: {} (impact {}, urgency {}, effort {})",
+                score, priority.impact, priority.urgency, priority.effort
+            ));
+        }
+        if !task.tags.is_empty() {
+            lines.push(format!("Tags: {}", task.tags.join(", ")));
+        }
+        let dates = [
+            ("Due", &task.due),
+            ("Created", &task.created),
+            ("Completed", &task.completed_at),
+        ];
+        for (label, date) in dates {
+            if let Some(date) = date {
+                lines.push(format!("{}: {}", label, date));
+            }
+        }
+        if task.focus_minutes > 0 {
+            lines.push(format!("Focus time: {} min", task.focus_minutes));
+        }
+        if let Some(description) = task.description.as_deref()
+            && !description.trim().is_empty()
+        {
+            lines.push(String::new());
+            lines.push("Description:".to_string());
+            lines.extend(description.lines().map(str::to_string));
+        }
+        lines
+    }
+
+    /// Column names starting with `prefix`, ignoring case
+    pub fn column_completions(&self, prefix: &str) -> Vec<String> {
+        let prefix = prefix.to_lowercase();
+        self.columns
+            .iter()
+            .map(|col| col.title.clone())
+            .filter(|title| title.to_lowercase().starts_with(&prefix))
+            .collect()
+    }
+
+    /// Complete the column name in `input_text`. Repeated calls cycle through
+    /// all columns matching the originally typed prefix.
+    pub fn complete_column_name(&mut self) {
+        let (prefix, index) = match self.completion.take() {
+            Some((prefix, index)) => (prefix, index + 1),
+            None => (self.input_text.clone(), 0),
+        };
+
+        let candidates = self.column_completions(&prefix);
+        if candidates.is_empty() {
+            return;
+        }
+
+        let index = index % candidates.len();
+        self.input_text = candidates[index].clone();
+        self.completion = Some((prefix, index));
+    }
+
+    /// Columns the selected task could be moved to whose titles contain the
+    /// letters of `query` in order, ignoring case. `submitted` is true once
+    /// Enter is pressed, when a title equal to the query wins outright even
+    /// if longer titles also match.
+    pub fn match_destination_column(&self, query: &str, submitted: bool) -> ColumnMatch {
+        let candidates: Vec<usize> = (0..self.columns.len())
+            .filter(|&idx| idx != self.active_column)
+            .filter(|&idx| is_fuzzy_match(&self.columns[idx].title, query))
+            .collect();
+
+        let exact = candidates
+            .iter()
+            .copied()
+            .find(|&idx| self.columns[idx].title.eq_ignore_ascii_case(query.trim()));
+        match (exact, candidates.as_slice()) {
+            (Some(idx), _) if submitted => ColumnMatch::Unique(idx),
+            (_, []) => ColumnMatch::NoMatch,
+            (_, [only]) => ColumnMatch::Unique(*only),
+            _ => ColumnMatch::Ambiguous(candidates),
+        }
+    }
+
+    /// Act on the move-by-name prompt: move the selected task as soon as one
+    /// column matches what has been typed. On Enter an ambiguous query keeps
+    /// the prompt open for more letters, and one matching nothing closes it.
+    pub fn resolve_move_by_name(&mut self, submitted: bool) {
+        let query = self.input_text.clone();
+        match self.match_destination_column(&query, submitted) {
+            ColumnMatch::Unique(index) => {
+                self.input_text.clear();
+                self.completion = None;
+                self.input_mode = InputMode::Normal;
+                self.request_move_task_to_column(index);
+            }
+            ColumnMatch::Ambiguous(candidates) if submitted => {
+                self.status_message = Some(format!(
+                    "'{}' matches {} columns; type more to pick one",
+                    query,
+                    candidates.len()
+                ));
+            }
+            ColumnMatch::NoMatch if submitted => {
+                self.status_message = Some(format!("No column matching '{}'", query));
+                self.input_text.clear();
+                self.completion = None;
+                self.input_mode = InputMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Sort one column's tasks by computed priority, keeping the same task
+    /// selected. Tasks without a score count as the least important.
+    fn sort_tasks_by_priority(
+        column: &mut Column,
+        direction: SortDirection,
+        formula: PriorityFormula,
+    ) {
+        let mut indexed: Vec<(usize, Task)> = column.tasks.drain(..).enumerate().collect();
+        indexed.sort_by(|(_, a), (_, b)| {
+            let a_score = a.computed_priority(formula).unwrap_or(f32::MIN);
+            let b_score = b.computed_priority(formula).unwrap_or(f32::MIN);
+            match direction {
+                SortDirection::Descending => b_score.total_cmp(&a_score),
+                SortDirection::Ascending => a_score.total_cmp(&b_score),
+            }
+        });
+
+        // Follow the previously selected task to its new position
+        let selected = column.selected_task;
+        column.selected_task = None;
+        for (new_idx, (old_idx, task)) in indexed.into_iter().enumerate() {
+            if selected == Some(old_idx) {
+                column.selected_task = Some(new_idx);
+            }
+            column.tasks.push(task);
+        }
+    }
+
+    /// Sort the active column's tasks by computed priority
+    pub fn sort_column_by_priority(&mut self) {
+        self.record_undo();
+        if let Some(column) = self.columns.get_mut(self.active_column) {
+            Self::sort_tasks_by_priority(
+                column,
+                self.view_state.sort_direction,
+                self.config.priority_formula,
+            );
+
+            // Save changes to file
+            let _ = self.save_board();
+        }
+    }
+
+    /// Sort every column's tasks by computed priority at once
+    pub fn sort_all_columns_by_priority(&mut self) {
+        self.record_undo();
+        let direction = self.view_state.sort_direction;
+        let formula = self.config.priority_formula;
+        for column in self.columns.iter_mut() {
+            Self::sort_tasks_by_priority(column, direction, formula);
+        }
+
+        // Save changes to file
+        let _ = self.save_board();
+    }
+
+    /// Switch the priority view on or off. It replaces the tag view, as
+    /// both take the place of the columns.
+    pub fn toggle_priority_view(&mut self) {
+        self.priority_view = !self.priority_view;
+        if self.priority_view {
+            self.tag_view = false;
+        }
+    }
+
+    /// The rows of the priority view: tasks the filter shows, by priority
+    pub fn priority_view_tasks(&self) -> Vec<PrioritizedTask> {
+        tasks_by_priority(&self.columns, self.config.priority_formula)
+            .into_iter()
+            .filter(|entry| self.is_task_visible(entry.column_idx, entry.task_idx))
+            .collect()
+    }
+
+    /// Move the selection `delta` rows down the priority view. The real
+    /// column and task are selected, so edits apply where the task lives.
+    pub fn step_priority_view(&mut self, delta: isize) {
+        let rows = self.priority_view_tasks();
+        if rows.is_empty() {
+            return;
+        }
+        let current = self.columns.get(self.active_column).and_then(|column| {
+            let selected = column.selected_task?;
+            rows.iter()
+                .position(|row| row.column_idx == self.active_column && row.task_idx == selected)
+        });
+        let target = current.map_or(0, |position| {
+            step_index(position, delta, rows.len(), self.config.wrap_navigation)
+        });
+        let row = &rows[target];
+        self.select_real_task((row.column_idx, row.task_idx));
+    }
+
+    /// The groups of the tag view, holding only the tasks the board view
+    /// would draw: those in shown columns that pass folds and the filter
+    pub fn tag_view_groups(&self) -> Vec<(String, Vec<(usize, usize)>)> {
+        let shown = self.shown_columns();
+        let mut groups = group_tasks_by_tag(&self.columns);
+        for (_, tasks) in groups.iter_mut() {
+            tasks.retain(|&(column_idx, task_idx)| {
+                shown.contains(&column_idx) && self.is_task_visible(column_idx, task_idx)
+            });
+        }
+        groups.retain(|(_, tasks)| !tasks.is_empty());
+        groups
+    }
+
+    // The group and row of the tag view showing the selected task
+    fn tag_view_position(
+        &self,
+        groups: &[(String, Vec<(usize, usize)>)],
+    ) -> Option<(usize, usize)> {
+        let selected = (
+            self.active_column,
+            self.columns.get(self.active_column)?.selected_task?,
+        );
+        groups.iter().enumerate().find_map(|(group, (_, tasks))| {
+            Some((group, tasks.iter().position(|&task| task == selected)?))
+        })
+    }
+
+    /// Move the selection `delta` tasks down its group in the tag view, so
+    /// j/k stay within the group the selected task is shown in. Without a
+    /// selection in view this selects the first group's first task.
+    pub fn step_tag_view(&mut self, delta: isize) {
+        let groups = self.tag_view_groups();
+        let (group, row) = match self.tag_view_position(&groups) {
+            Some((group, row)) => {
+                let len = groups[group].1.len();
+                (
+                    group,
+                    step_index(row, delta, len, self.config.wrap_navigation),
+                )
+            }
+            None if groups.is_empty() => return,
+            None => (0, 0),
+        };
+        self.select_real_task(groups[group].1[row]);
+    }
+
+    /// Move the selection `delta` groups across the tag view, keeping its
+    /// row where the next group is long enough
+    pub fn step_tag_view_group(&mut self, delta: isize) {
+        let groups = self.tag_view_groups();
+        let (group, row) = match self.tag_view_position(&groups) {
+            Some((group, row)) => (
+                step_index(group, delta, groups.len(), self.config.wrap_navigation),
+                row,
+            ),
+            None if groups.is_empty() => return,
+            None => (0, 0),
+        };
+        let tasks = &groups[group].1;
+        self.select_real_task(tasks[row.min(tasks.len() - 1)]);
     }
+
+    // Select a task shown in one of the views that mix columns
+    fn select_real_task(&mut self, (column_idx, task_idx): (usize, usize)) {
+        self.activate_column(column_idx);
+        self.columns[column_idx].selected_task = Some(task_idx);
+    }
+
     // Get all possible jump labels
     pub fn get_jump_labels(&self) -> Vec<char> {
-        // Use letters from a to z, excluding ambiguous ones
-        // This gives us 24 distinct labels (excluding 'l' and 'o' which can be confused)
-        let mut labels: Vec<char> = "abcdefghijkmnpqrstuvwxyz".chars().collect();
+        // Use the configured alphabet (by default a-z without 'l' and 'o')
+        let mut labels: Vec<char> = self.config.jump_labels.chars().collect();
 
-        // If we need more labels, add capital letters
+        // If we need more labels, add capital letters not already in use
         if self.total_task_count() > labels.len() {
-            let caps: Vec<char> = "ABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().collect();
+            let caps: Vec<char> = ('A'..='Z').filter(|c| !labels.contains(c)).collect();
             labels.extend(caps);
         }
 
@@ -462,9 +2036,9 @@ impl App {
         let labels = self.get_jump_labels();
         let mut global_task_idx = 0;
 
-        // Count tasks until we reach the specified task
-        for (c_idx, col) in self.columns.iter().enumerate() {
-            for (t_idx, _) in col.tasks.iter().enumerate() {
+        // Count visible tasks until we reach the specified task
+        for c_idx in 0..self.columns.len() {
+            for t_idx in self.visible_task_indices(c_idx) {
                 if c_idx == col_idx && t_idx == task_idx {
                     // Found our task, return its label if available
                     return if global_task_idx < labels.len() {
@@ -487,9 +2061,9 @@ impl App {
 
         let mut global_task_idx = 0;
 
-        // Iterate through all tasks to find the one with matching label index
-        for (col_idx, col) in self.columns.iter().enumerate() {
-            for (task_idx, _) in col.tasks.iter().enumerate() {
+        // Iterate through visible tasks to find the one with matching label index
+        for col_idx in 0..self.columns.len() {
+            for task_idx in self.visible_task_indices(col_idx) {
                 if global_task_idx == label_idx {
                     return Some((col_idx, task_idx));
                 }
@@ -538,6 +2112,13 @@ impl App {
         if self.columns.is_empty() {
             return;
         }
+        // Keeping the same name is not an edit
+        if self.columns[self.active_column].title == new_name {
+            self.input_mode = InputMode::Normal;
+            self.input_text.clear();
+            return;
+        }
+        self.record_undo();
 
         // Ensure the column name is unique
         let mut unique_name = new_name.to_string();
@@ -551,12 +2132,20 @@ impl App {
         }
 
         // Rename the active column
+        let mut description = None;
         if let Some(column) = self.columns.get_mut(self.active_column) {
+            description = Some(format!(
+                "Renamed column '{}' to '{}'",
+                column.title, unique_name
+            ));
             column.title = unique_name;
         }
 
         // Save changes to file
         let _ = self.save_board();
+        if let Some(description) = description {
+            self.log_change(&description);
+        }
 
         // Exit input mode
         self.input_mode = InputMode::Normal;
@@ -565,15 +2154,34 @@ impl App {
 
     /// Rename the current task
     pub fn rename_current_task(&mut self, new_name: &str) {
+        let new_name = &sanitize_title(new_name);
+        // Keeping the same title is not an edit
+        if self
+            .selected_task()
+            .is_none_or(|task| task.title == *new_name)
+        {
+            self.input_mode = InputMode::Normal;
+            self.input_text.clear();
+            return;
+        }
+        self.record_undo();
         // Only proceed if we have an active column and a selected task
         if let Some(column) = self.columns.get_mut(self.active_column) {
             if let Some(task_idx) = column.selected_task {
                 if task_idx < column.tasks.len() {
                     if let Some(task) = column.tasks.get_mut(task_idx) {
+                        let description =
+                            format!("Renamed task '{}' to '{}'", task.title, new_name);
                         task.title = new_name.to_string();
+                        let op = JournalOp::Rename {
+                            id: task.id,
+                            title: new_name.to_string(),
+                        };
 
                         // Save changes to file
+                        self.journal(op);
                         let _ = self.save_board();
+                        self.log_change(&description);
                     }
                 }
             }
@@ -584,21 +2192,244 @@ impl App {
         self.input_text.clear();
     }
 
-    /// Prepare for renaming a column
-    pub fn prepare_rename_column(&mut self) {
-        // Only proceed if there are columns
-        if self.columns.is_empty() {
-            return;
-        }
-
-        // Set input text to current column name for editing
-        if let Some(column) = self.columns.get(self.active_column) {
-            self.input_text = column.title.clone();
-            self.input_mode = InputMode::RenamingColumn;
-        }
+    /// Forget every undo step, for when another board is opened: undoing
+    /// would otherwise put the previous board's columns into this one
+    pub fn reset_history(&mut self) {
+        self.history = History::new(self.config.undo_depth);
     }
 
-    /// Prepare for renaming a task
+    /// Remember the current board state so the next change can be undone.
+    /// Every edit starts here, so this also marks the board as dirty.
+    pub fn record_undo(&mut self) {
+        self.dirty = true;
+        self.unsaved_changes.edits += 1;
+        self.history.push(Snapshot {
+            columns: self.columns.clone(),
+            active_column: self.active_column,
+            restored: None,
+        });
+    }
+
+    /// Restore the board to the state before the last change.
+    /// Returns false when there is nothing left to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(snapshot) => {
+                self.columns = snapshot.columns;
+                self.active_column = snapshot.active_column;
+                self.changed_task = None;
+                self.dirty = true;
+                if let Some(entry) = snapshot.restored {
+                    self.return_to_trash(entry);
+                }
+
+                // Save changes to file
+                let _ = self.save_board();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Describe where the current board lives, for the info popup
+    pub fn board_info_lines(&self, kanban_dir: Option<&str>) -> Vec<String> {
+        vec![
+            format!("Board: {}", self.title),
+            format!(
+                "File: {}",
+                self.file_path
+                    .as_deref()
+                    .unwrap_or("(none - changes are not saved)")
+            ),
+            format!("KANBAN_DIR: {}", kanban_dir.unwrap_or("(not set)")),
+            format!("Format version: {}", self.format_version),
+        ]
+    }
+
+    /// Check whether the task at the given position should still be highlighted
+    pub fn is_task_recently_changed(&self, column_idx: usize, task_idx: usize) -> bool {
+        let task = self
+            .columns
+            .get(column_idx)
+            .and_then(|column| column.tasks.get(task_idx));
+        match (self.changed_task, task) {
+            (Some((id, changed_at)), Some(task)) if task.id == id => {
+                is_recent_change(changed_at, Instant::now())
+            }
+            _ => false,
+        }
+    }
+
+    /// Explicitly save the board, reporting the outcome in the status line
+    pub fn save_with_feedback(&mut self) {
+        match self.save_board_with_backup() {
+            Ok(()) => {
+                let path = self.file_path.clone().unwrap_or_default();
+                self.status_message = Some(format!("Saved to {}", path));
+                self.saved_at = Some(Instant::now());
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Error saving board: {}", e));
+            }
+        }
+    }
+
+    /// Whether the title bar should still flash after an explicit save
+    pub fn is_recently_saved(&self) -> bool {
+        self.saved_at
+            .is_some_and(|saved_at| is_recent_change(saved_at, Instant::now()))
+    }
+
+    /// Prepare for renaming a column
+    pub fn prepare_rename_column(&mut self) {
+        // Only proceed if there are columns
+        if self.columns.is_empty() {
+            return;
+        }
+
+        // Set input text to current column name for editing
+        if let Some(column) = self.columns.get(self.active_column) {
+            self.input_text = column.title.clone();
+            self.input_mode = InputMode::RenamingColumn;
+        }
+    }
+
+    /// Open the numeric prompt for one priority field of the selected task
+    pub fn prepare_edit_priority(&mut self, field: PriorityField) {
+        if let Some(task) = self.selected_task_mut() {
+            let mut priority = task.priority.clone().unwrap_or_else(Task::default_priority);
+            let value = *field.value_mut(&mut priority);
+            self.input_text = value.to_string();
+            self.input_mode = InputMode::EditingPriority(field);
+        }
+    }
+
+    /// Set one priority field of the selected task. Values above
+    /// MAX_PRIORITY_VALUE are rejected and leave the task unchanged.
+    pub fn set_priority_field(&mut self, field: PriorityField, value: u8) -> bool {
+        let Some(task) = self.selected_task() else {
+            return false;
+        };
+        if value > MAX_PRIORITY_VALUE {
+            return false;
+        }
+        // Setting the value a field already has is not an edit
+        if task
+            .priority
+            .as_ref()
+            .is_some_and(|priority| field.value(priority) == value)
+        {
+            return true;
+        }
+        self.record_undo();
+
+        if let Some(task) = self.selected_task_mut() {
+            let priority = task.priority.get_or_insert_with(Task::default_priority);
+            *field.value_mut(priority) = value;
+        }
+        let _ = self.save_board();
+        true
+    }
+
+    /// Open the prompt for the active column's WIP limit, filled in with
+    /// the current one
+    pub fn prepare_edit_wip_limit(&mut self) {
+        if let Some(column) = self.columns.get(self.active_column) {
+            self.input_text = column
+                .wip_limit
+                .map(|limit| limit.to_string())
+                .unwrap_or_default();
+            self.input_mode = InputMode::EditingWipLimit;
+        }
+    }
+
+    /// Set or clear (None) the active column's WIP limit. A limit of 0 is
+    /// rejected, as it would leave no room for any task.
+    pub fn set_wip_limit(&mut self, limit: Option<usize>) -> bool {
+        let Some(column) = self.columns.get(self.active_column) else {
+            return false;
+        };
+        if limit == Some(0) {
+            return false;
+        }
+        if column.wip_limit == limit {
+            return true;
+        }
+        self.record_undo();
+        let column = &mut self.columns[self.active_column];
+        column.wip_limit = limit;
+        let description = match limit {
+            Some(limit) => format!("Set the WIP limit of '{}' to {}", column.title, limit),
+            None => format!("Cleared the WIP limit of '{}'", column.title),
+        };
+        let _ = self.save_board();
+        self.log_change(&description);
+        true
+    }
+
+    /// An ID no task on the board uses yet
+    pub fn next_task_id(&self) -> usize {
+        self.columns
+            .iter()
+            .flat_map(|column| column.tasks.iter())
+            .map(|task| task.id)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    /// Give every task without an ID, or with one already taken, a fresh ID
+    pub fn assign_missing_task_ids(&mut self) {
+        let mut next_id = self.next_task_id();
+        let mut seen = HashSet::new();
+        for task in self
+            .columns
+            .iter_mut()
+            .flat_map(|column| column.tasks.iter_mut())
+        {
+            if task.id == 0 || !seen.insert(task.id) {
+                task.id = next_id;
+                seen.insert(next_id);
+                next_id += 1;
+            }
+        }
+    }
+
+    /// Find a task by its ID, as (column index, task index)
+    pub fn find_task_by_id(&self, id: usize) -> Option<(usize, usize)> {
+        self.columns
+            .iter()
+            .enumerate()
+            .find_map(|(column_idx, column)| {
+                let task_idx = column.tasks.iter().position(|task| task.id == id)?;
+                Some((column_idx, task_idx))
+            })
+    }
+
+    /// Select the task with the ID typed in the prompt, wherever it is
+    pub fn jump_to_task_id(&mut self) {
+        let typed = self.input_text.trim().to_string();
+        self.input_mode = InputMode::Normal;
+        self.input_text.clear();
+        match typed.parse().ok().and_then(|id| self.find_task_by_id(id)) {
+            Some((column_idx, task_idx)) => self.jump_to_task(column_idx, task_idx),
+            None => self.status_message = Some(format!("No task with ID {}", typed)),
+        }
+    }
+
+    /// The task selected in the active column, if any
+    pub fn selected_task(&self) -> Option<&Task> {
+        let column = self.columns.get(self.active_column)?;
+        column.tasks.get(column.selected_task?)
+    }
+
+    fn selected_task_mut(&mut self) -> Option<&mut Task> {
+        let column = self.columns.get_mut(self.active_column)?;
+        let task_idx = column.selected_task?;
+        column.tasks.get_mut(task_idx)
+    }
+
+    /// Prepare for renaming a task, starting from its current title
     pub fn prepare_rename_task(&mut self) {
         // Only proceed if we have an active column and a selected task
         if let Some(column) = self.columns.get(self.active_column) {
@@ -613,3 +2444,2027 @@ impl App {
         }
     }
 }
+
+// Decide whether a change made at `changed_at` is still fresh at `now`
+pub fn is_recent_change(changed_at: Instant, now: Instant) -> bool {
+    now.saturating_duration_since(changed_at) < CHANGE_HIGHLIGHT_DURATION
+}
+
+// List the display names of all board files in a directory.
+// Backups, archives and .txt files without the board header are skipped.
+// Subdirectories are scanned too, with the folder path used as a category prefix;
+// hidden folders, symlinks and folders that can't be read are left out.
+fn scan_board_dir(dir_path: &std::path::Path) -> Result<Vec<String>, std::io::Error> {
+    let mut boards = Vec::new();
+    scan_board_dir_into(dir_path, "", &mut boards)?;
+    Ok(boards)
+}
+
+fn scan_board_dir_into(
+    dir_path: &std::path::Path,
+    category: &str,
+    boards: &mut Vec<String>,
+) -> Result<(), std::io::Error> {
+    for entry in std::fs::read_dir(dir_path)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        // A symlink could lead out of the directory or back into it
+        if entry.file_type()?.is_symlink() {
+            continue;
+        }
+
+        // Recurse into category folders
+        if path.is_dir() {
+            if let Some(dir_name) = path.file_name().and_then(|name| name.to_str())
+                && !dir_name.starts_with('.')
+            {
+                let nested = if category.is_empty() {
+                    dir_name.to_string()
+                } else {
+                    format!("{}{}{}", category, CATEGORY_SEPARATOR, dir_name)
+                };
+                // One unreadable folder shouldn't hide every other board
+                let _ = scan_board_dir_into(&path, &nested, boards);
+            }
+            continue;
+        }
+
+        // Check if it's a file with .txt extension
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("txt") {
+            continue;
+        }
+
+        // Extract board name from filename
+        if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+            // Skip backups and archives that happen to end in .txt
+            if name.ends_with(".bak") || name.ends_with(".archive") {
+                continue;
+            }
+
+            // Only list files that actually look like boards
+            if !crud::is_board_file(&path) {
+                continue;
+            }
+
+            // Convert from snake_case to a readable format
+            let display_name = name.replace("_", " ");
+            if category.is_empty() {
+                boards.push(display_name);
+            } else {
+                boards.push(format!(
+                    "{}{}{}",
+                    category, CATEGORY_SEPARATOR, display_name
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Reject names that would be confused with the picker's create entry or
+// that leave nothing to name the file after
+pub fn validate_board_name(name: &str) -> Result<(), KanbanError> {
+    let name = name.trim();
+    let file_name = name
+        .rsplit(CATEGORY_SEPARATOR.trim())
+        .next()
+        .unwrap_or_default();
+    if name.is_empty() || file_name.trim().is_empty() {
+        Err(KanbanError::InvalidName("the name is empty".to_string()))
+    } else if name == CREATE_NEW_BOARD_LABEL {
+        Err(KanbanError::InvalidName(format!(
+            "'{}' is reserved",
+            CREATE_NEW_BOARD_LABEL
+        )))
+    } else if name.starts_with('[') {
+        Err(KanbanError::InvalidName(
+            "names can't start with '['".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether to greet the user with the first-run help: there are no boards
+/// to pick from yet and the help hasn't been dismissed before
+pub fn should_show_onboarding(available_boards: &[String], dismissed: bool) -> bool {
+    available_boards.is_empty() && !dismissed
+}
+
+// Read the custom board order sidecar, one board name per line
+pub fn read_board_order(dir_path: &std::path::Path) -> Vec<String> {
+    read_board_list(&dir_path.join(BOARD_ORDER_FILE_NAME))
+}
+
+// Read a list of board names, one per line; a missing file is an empty list
+fn read_board_list(path: &std::path::Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .map(|contents| {
+            contents
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Write the custom board order sidecar
+pub fn write_board_order(
+    dir_path: &std::path::Path,
+    boards: &[String],
+) -> Result<(), std::io::Error> {
+    write_board_list(&dir_path.join(BOARD_ORDER_FILE_NAME), boards)
+}
+
+// Write a list of board names, one per line
+fn write_board_list(path: &std::path::Path, boards: &[String]) -> Result<(), std::io::Error> {
+    let mut contents = boards.join("\n");
+    contents.push('\n');
+    std::fs::write(path, contents)
+}
+
+// Read the recently opened boards sidecar, newest first
+pub fn read_recent_boards(dir_path: &std::path::Path) -> Vec<String> {
+    read_board_list(&dir_path.join(RECENT_BOARDS_FILE_NAME))
+}
+
+// Move `board_name` to the front of the recent list, keeping it short
+pub fn push_recent_board(recent: &mut Vec<String>, board_name: &str) {
+    recent.retain(|name| name != board_name);
+    recent.insert(0, board_name.to_string());
+    recent.truncate(MAX_RECENT_BOARDS);
+}
+
+// The board to toggle to from `current`: the most recent other board
+pub fn previous_board<'a>(recent: &'a [String], current: &str) -> Option<&'a str> {
+    recent
+        .iter()
+        .find(|name| name.as_str() != current)
+        .map(String::as_str)
+}
+
+// Order the picker's boards. They start out alphabetical; `Custom` then
+// applies the saved board order, and `Recent` puts the boards in the recent
+// list first, newest first, then the rest by when their file last changed.
+pub fn sort_boards(
+    boards: &mut Vec<String>,
+    dir_path: &std::path::Path,
+    recent: &[String],
+    sort: PickerSort,
+) {
+    boards.sort();
+    match sort {
+        PickerSort::Alpha => {}
+        PickerSort::Custom => apply_board_order(boards, &read_board_order(dir_path)),
+        PickerSort::Recent => boards.sort_by_cached_key(|name| {
+            let opened = recent.iter().position(|recent| recent == name);
+            let modified = board_file_path(dir_path, name)
+                .ok()
+                .and_then(|path| std::fs::metadata(path).ok())
+                .and_then(|meta| meta.modified().ok());
+            (opened.unwrap_or(usize::MAX), std::cmp::Reverse(modified))
+        }),
+    }
+}
+
+// Put boards named in `order` first (in that order); the rest keep their
+// current relative order. Names in `order` that no longer exist are ignored.
+pub fn apply_board_order(boards: &mut Vec<String>, order: &[String]) {
+    let mut seen = HashSet::new();
+    let mut ordered: Vec<String> = order
+        .iter()
+        .filter(|name| boards.contains(name) && seen.insert(name.as_str()))
+        .cloned()
+        .collect();
+    let rest: Vec<String> = boards
+        .iter()
+        .filter(|name| !ordered.contains(name))
+        .cloned()
+        .collect();
+    ordered.extend(rest);
+    *boards = ordered;
+}
+
+// Resolve a board display name (possibly "category / name") to its file path.
+// Each part must be a plain file or folder name, so the path can't leave
+// `dir_path`.
+pub fn board_file_path(
+    dir_path: &std::path::Path,
+    board_name: &str,
+) -> Result<std::path::PathBuf, KanbanError> {
+    let mut parts: Vec<&str> = board_name
+        .split(CATEGORY_SEPARATOR)
+        .map(str::trim)
+        .collect();
+    for part in &parts {
+        let mut components = std::path::Path::new(part).components();
+        let plain = matches!(components.next(), Some(std::path::Component::Normal(_)))
+            && components.next().is_none()
+            && !part.contains(['/', '\\']);
+        if !plain {
+            return Err(KanbanError::InvalidName(format!(
+                "'{}' is not a plain name",
+                part
+            )));
+        }
+    }
+    let name = parts.pop().unwrap_or_default();
+
+    let mut file_path = dir_path.to_path_buf();
+    for category in parts {
+        file_path.push(category);
+    }
+    file_path.push(format!("{}.txt", name.replace(" ", "_").to_lowercase()));
+    Ok(file_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::config::Confirmations;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn test_scan_board_dir_skips_non_boards() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_scan_board_dir");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        fs::write(
+            dir.join("sprint_board.txt"),
+            "# TUI Kanban Board: Sprint Board\nDate: 2025-03-24\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("sprint_board.bak.txt"),
+            "# TUI Kanban Board: Sprint Board\nDate: 2025-03-23\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("sprint_board.archive.txt"),
+            "# TUI Kanban Board: Sprint Board\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes.txt"), "just some notes\n").unwrap();
+
+        let boards = scan_board_dir(&dir).unwrap();
+        assert_eq!(boards, vec!["sprint board".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scan_board_dir_includes_categories() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_scan_categories");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("work").join("team")).unwrap();
+        fs::create_dir_all(dir.join("personal")).unwrap();
+
+        let header = "# TUI Kanban Board: Board\n";
+        fs::write(dir.join("inbox.txt"), header).unwrap();
+        fs::write(dir.join("work").join("sprint_42.txt"), header).unwrap();
+        fs::write(dir.join("work").join("team").join("roadmap.txt"), header).unwrap();
+        fs::write(dir.join("personal").join("notes.txt"), "not a board\n").unwrap();
+
+        let mut boards = scan_board_dir(&dir).unwrap();
+        boards.sort();
+        assert_eq!(
+            boards,
+            vec![
+                "inbox".to_string(),
+                "work / sprint 42".to_string(),
+                "work / team / roadmap".to_string(),
+            ]
+        );
+
+        // Display names resolve back to the nested files
+        for board in &boards {
+            assert!(board_file_path(&dir, board).unwrap().exists());
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_scan_board_dir_skips_hidden_and_unreadable_folders() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_scan_hidden");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::create_dir_all(dir.join("locked")).unwrap();
+
+        let header = "# TUI Kanban Board: Board\n";
+        fs::write(dir.join("inbox.txt"), header).unwrap();
+        fs::write(dir.join(".git").join("stash.txt"), header).unwrap();
+        fs::write(dir.join("locked").join("secret.txt"), header).unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(dir.join("locked"), fs::Permissions::from_mode(0o000)).unwrap();
+        }
+
+        // The locked folder may still be readable when running as root,
+        // but it must never make the scan fail
+        let boards = scan_board_dir(&dir).unwrap();
+        assert!(boards.contains(&"inbox".to_string()));
+        assert!(!boards.iter().any(|board| board.starts_with(".git")));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(dir.join("locked"), fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_board_dir_skips_symlinks() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_scan_symlinks");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("work")).unwrap();
+
+        let header = "# TUI Kanban Board: Board\n";
+        fs::write(dir.join("work").join("sprint.txt"), header).unwrap();
+        // A link back to the top would otherwise be followed forever
+        std::os::unix::fs::symlink(&dir, dir.join("work").join("loop")).unwrap();
+        std::os::unix::fs::symlink(dir.join("work").join("sprint.txt"), dir.join("alias.txt"))
+            .unwrap();
+
+        let boards = scan_board_dir(&dir).unwrap();
+        assert_eq!(boards, vec!["work / sprint".to_string()]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_enabled_confirmations_route_through_confirm_modes() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.input_mode = InputMode::Normal;
+        app.config.confirmations = Confirmations::default();
+        app.columns[0].tasks = vec![task_with_impact("Keep me", 5)];
+        app.columns[0].selected_task = Some(0);
+
+        app.request_delete_task();
+        assert!(app.input_mode == InputMode::ConfirmDeleteTask);
+        app.input_mode = InputMode::Normal;
+        app.request_clear_column();
+        assert!(app.input_mode == InputMode::ConfirmClearColumn);
+        app.input_mode = InputMode::Normal;
+        app.request_delete_column();
+        assert!(app.input_mode == InputMode::ConfirmDeleteColumn);
+        app.input_mode = InputMode::Normal;
+        app.dirty = true;
+        assert!(!app.request_quit());
+        assert!(matches!(app.input_mode, InputMode::ConfirmQuit(_)));
+
+        // Nothing happened without an answer
+        assert_eq!(app.columns.len(), 2);
+        assert_eq!(app.columns[0].tasks.len(), 1);
+
+        // With everything saved there is nothing to confirm on quit
+        app.input_mode = InputMode::Normal;
+        app.dirty = false;
+        assert!(app.request_quit());
+    }
+
+    #[test]
+    fn test_cancelled_quit_returns_to_the_asking_mode() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.dirty = true;
+
+        // Asked from the board picker, backing out stays in the picker
+        app.input_mode = InputMode::BoardSelection;
+        assert!(!app.request_quit());
+        app.cancel_quit();
+        assert!(app.input_mode == InputMode::BoardSelection);
+
+        app.input_mode = InputMode::Normal;
+        assert!(!app.request_quit());
+        app.cancel_quit();
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_disabled_confirmations_act_immediately() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.input_mode = InputMode::Normal;
+        app.config.confirmations = Confirmations {
+            delete_task: false,
+            delete_column: false,
+            clear_column: false,
+            quit_unsaved: false,
+        };
+        app.columns[0].tasks = vec![task_with_impact("First", 5), task_with_impact("Second", 5)];
+        app.columns[0].selected_task = Some(0);
+
+        app.request_delete_task();
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(task_titles(&app.columns[0]), vec!["Second"]);
+
+        app.request_clear_column();
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(app.columns[0].tasks.is_empty());
+
+        app.request_delete_column();
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(app.columns.len(), 1);
+        assert_eq!(app.columns[0].title, "Done");
+
+        app.dirty = true;
+        assert!(app.request_quit());
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_manual_scroll_stays_while_active_column_is_visible() {
+        let mut app = app_with_columns(&["A", "B", "C", "D", "E"]);
+        app.active_column = 1;
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 0);
+
+        // Peek one column right; B is still on screen
+        app.scroll_columns(1);
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 1);
+        assert_eq!(app.active_column, 1);
+
+        // Moving to another visible column doesn't snap back
+        app.select_next_column();
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 1);
+
+        // Scrolling stops at the last full screen of columns
+        app.scroll_columns(5);
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 2);
+        app.scroll_columns(-5);
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 0);
+    }
+
+    #[test]
+    fn test_auto_scroll_follows_active_column_after_manual_peek() {
+        let mut app = app_with_columns(&["A", "B", "C", "D", "E"]);
+        app.active_column = 0;
+
+        // Peeking can hide the active column...
+        app.scroll_columns(2);
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 2);
+
+        // ...until it changes, when the view brings it back into sight
+        app.select_next_column();
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 1);
+        assert_eq!(app.manual_scroll, None);
+
+        app.active_column = 4;
+        app.sync_scroll(3);
+        assert_eq!(app.scroll_offset, 2);
+    }
+
+    #[test]
+    fn test_reserved_board_names_are_rejected() {
+        let mut app = App::new("Kanban Board");
+
+        for name in [CREATE_NEW_BOARD_LABEL, "[Archive]", "", "   ", "work / "] {
+            let result = app.create_new_board(name);
+            assert!(
+                matches!(result, Err(KanbanError::InvalidName(_))),
+                "'{}' was accepted",
+                name
+            );
+        }
+        // Nothing was changed by the rejected attempts
+        assert_eq!(app.title, "Kanban Board");
+        assert_eq!(
+            KanbanError::InvalidName(format!("'{}' is reserved", CREATE_NEW_BOARD_LABEL))
+                .to_string(),
+            "Invalid board name: '[Create New Board]' is reserved"
+        );
+
+        assert!(validate_board_name("work / sprint 42").is_ok());
+        assert!(validate_board_name("Release [beta]").is_ok());
+    }
+
+    #[test]
+    fn test_previous_board_toggles_between_recent_boards() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_recent_boards");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut app = App::new("work");
+        app.recent_boards.clear();
+        app.remember_board(&dir, "archive");
+        app.remember_board(&dir, "work");
+        app.remember_board(&dir, "home");
+        app.title = "home".to_string();
+        assert_eq!(previous_board(&app.recent_boards, &app.title), Some("work"));
+
+        // Opening the previous board makes the other one the previous
+        app.remember_board(&dir, "work");
+        app.title = "work".to_string();
+        assert_eq!(previous_board(&app.recent_boards, &app.title), Some("home"));
+
+        // The list survives a restart, newest first and without duplicates
+        assert_eq!(read_recent_boards(&dir), vec!["work", "home", "archive"]);
+
+        // A single board has nothing to toggle to
+        assert_eq!(previous_board(&["work".to_string()], "work"), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_should_show_onboarding() {
+        let boards = vec!["work".to_string()];
+        assert!(should_show_onboarding(&[], false));
+        assert!(!should_show_onboarding(&[], true));
+        assert!(!should_show_onboarding(&boards, false));
+        assert!(!should_show_onboarding(&boards, true));
+    }
+
+    #[test]
+    fn test_custom_board_order_round_trip() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_board_order");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let order = vec!["work".to_string(), "home".to_string()];
+        write_board_order(&dir, &order).unwrap();
+        assert_eq!(read_board_order(&dir), order);
+
+        // Ordered boards come first, new ones keep alphabetical order after,
+        // and stale entries are ignored
+        let mut boards = vec![
+            "archive".to_string(),
+            "home".to_string(),
+            "misc".to_string(),
+            "work".to_string(),
+        ];
+        apply_board_order(&mut boards, &read_board_order(&dir));
+        assert_eq!(boards, vec!["work", "home", "archive", "misc"]);
+
+        apply_board_order(&mut boards, &["gone".to_string()]);
+        assert_eq!(boards, vec!["work", "home", "archive", "misc"]);
+
+        // A name repeated further down the saved order is only listed once
+        let order = ["misc", "work", "misc"].map(String::from);
+        apply_board_order(&mut boards, &order);
+        assert_eq!(boards, vec!["misc", "work", "home", "archive"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_recent_picker_sort_uses_modification_time() {
+        let mut dir = env::temp_dir();
+        dir.push("kantui_picker_sort");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let now = SystemTime::now();
+        for (file_name, age) in [("alpha.txt", 3600), ("beta.txt", 60)] {
+            let path = dir.join(file_name);
+            fs::write(&path, "# TUI Kanban Board: Board\n").unwrap();
+            fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(now - Duration::from_secs(age))
+                .unwrap();
+        }
+        let scanned = scan_board_dir(&dir).unwrap();
+
+        let mut boards = scanned.clone();
+        sort_boards(&mut boards, &dir, &[], PickerSort::Recent);
+        assert_eq!(boards, vec!["beta", "alpha"]);
+
+        // Boards opened recently come before any that were only modified
+        sort_boards(
+            &mut boards,
+            &dir,
+            &["alpha".to_string()],
+            PickerSort::Recent,
+        );
+        assert_eq!(boards, vec!["alpha", "beta"]);
+
+        let mut boards = scanned;
+        sort_boards(&mut boards, &dir, &[], PickerSort::Alpha);
+        assert_eq!(boards, vec!["alpha", "beta"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_board_file_path_for_category() {
+        let dir = std::path::Path::new("/boards");
+        assert_eq!(
+            board_file_path(dir, "Work / Sprint 42").unwrap(),
+            dir.join("Work").join("sprint_42.txt")
+        );
+        assert_eq!(
+            board_file_path(dir, "My Board").unwrap(),
+            dir.join("my_board.txt")
+        );
+    }
+
+    #[test]
+    fn test_board_file_path_stays_inside_dir() {
+        let dir = std::path::Path::new("/boards");
+        for name in [
+            ".. / escape",
+            "work / .. / .. / etc / passwd",
+            "/etc / passwd",
+            "work /  / plan",
+            "work/../../plan",
+            "..",
+            "",
+        ] {
+            assert!(
+                matches!(board_file_path(dir, name), Err(KanbanError::InvalidName(_))),
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_recent_change() {
+        let changed_at = Instant::now();
+
+        assert!(is_recent_change(changed_at, changed_at));
+        assert!(is_recent_change(
+            changed_at,
+            changed_at + CHANGE_HIGHLIGHT_DURATION / 2
+        ));
+        assert!(!is_recent_change(
+            changed_at,
+            changed_at + CHANGE_HIGHLIGHT_DURATION
+        ));
+        // A "now" before the change (clock weirdness) still counts as recent
+        assert!(is_recent_change(
+            changed_at + Duration::from_secs(1),
+            changed_at
+        ));
+    }
+
+    fn column_titles(app: &App) -> Vec<&str> {
+        app.columns.iter().map(|col| col.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_insert_column_at_start() {
+        let mut app = App::new("Insert Board");
+        app.add_column("Done");
+        app.active_column = 1;
+
+        app.insert_column(0, "Backlog");
+
+        assert_eq!(column_titles(&app), vec!["Backlog", "To Do", "Done"]);
+        assert_eq!(app.active_column, 0);
+        assert_eq!(app.columns[1].selected_task, None);
+    }
+
+    #[test]
+    fn test_insert_column_between_existing() {
+        let mut app = App::new("Insert Board");
+        app.add_column("Done");
+
+        app.insert_column(1, "To Do");
+
+        assert_eq!(column_titles(&app), vec!["To Do", "To Do (1)", "Done"]);
+        assert_eq!(app.active_column, 1);
+    }
+
+    fn task_with_impact(title: &str, impact: u8) -> Task {
+        Task {
+            id: 0,
+            title: title.to_string(),
+            description: None,
+            priority: Some(crud::Priority {
+                impact,
+                urgency: 5,
+                effort: 3,
+            }),
+            completed_at: None,
+            tags: Vec::new(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        }
+    }
+
+    fn task_titles(column: &Column) -> Vec<&str> {
+        column
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_tasks_by_priority_spans_columns() {
+        let mut unscored = task_with_impact("someday", 0);
+        unscored.priority = None;
+        let columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![task_with_impact("low", 1), unscored],
+                selected_task: None,
+                wip_limit: None,
+            },
+            Column {
+                title: "Doing".to_string(),
+                tasks: vec![task_with_impact("high", 9), task_with_impact("also low", 1)],
+                selected_task: None,
+                wip_limit: None,
+            },
+        ];
+
+        let rows = tasks_by_priority(&columns, PriorityFormula::default());
+        let summary: Vec<(usize, usize, &str)> = rows
+            .iter()
+            .map(|row| (row.column_idx, row.task_idx, row.column.as_str()))
+            .collect();
+        // Ties keep board order and the unscored task goes last
+        assert_eq!(
+            summary,
+            vec![
+                (1, 0, "Doing"),
+                (0, 0, "To Do"),
+                (1, 1, "Doing"),
+                (0, 1, "To Do")
+            ]
+        );
+        assert!(rows[0].score > rows[1].score);
+        assert_eq!(rows[3].score, None);
+    }
+
+    #[test]
+    fn test_group_tasks_by_primary_tag() {
+        let tagged = |title: &str, tags: &[&str]| Task {
+            id: 0,
+            title: title.to_string(),
+            description: None,
+            priority: None,
+            completed_at: None,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        };
+        let column = |title: &str, tasks: Vec<Task>| Column {
+            title: title.to_string(),
+            tasks,
+            selected_task: None,
+            wip_limit: None,
+        };
+        let columns = vec![
+            column(
+                "To Do",
+                vec![
+                    tagged("Fix login", &["bug", "auth"]),
+                    tagged("Plan", &[]),
+                    tagged("Write docs", &["Docs"]),
+                ],
+            ),
+            column(
+                "Doing",
+                vec![tagged("Crash", &["bug"]), tagged("Deploy", &["auth"])],
+            ),
+        ];
+
+        let groups = group_tasks_by_tag(&columns);
+
+        // Only the first tag counts; untagged tasks come last
+        assert_eq!(
+            groups,
+            vec![
+                ("auth".to_string(), vec![(1, 1)]),
+                ("bug".to_string(), vec![(0, 0), (1, 0)]),
+                ("Docs".to_string(), vec![(0, 2)]),
+                (UNTAGGED_GROUP.to_string(), vec![(0, 1)]),
+            ]
+        );
+        assert!(group_tasks_by_tag(&[column("Empty", Vec::new())]).is_empty());
+    }
+
+    #[test]
+    fn test_tag_view_shows_what_the_board_shows() {
+        let mut app = app_with_numbered_tasks();
+        app.columns[0].tasks[0].tags = vec!["bug".to_string()];
+        app.columns[1].tasks[0].tags = vec!["bug".to_string()];
+        app.columns[1].tasks[1].tags = vec!["docs".to_string()];
+
+        let tags = |app: &App| -> Vec<String> {
+            app.tag_view_groups()
+                .into_iter()
+                .map(|(tag, _)| tag)
+                .collect()
+        };
+        assert_eq!(tags(&app), vec!["bug", "docs", "Untagged"]);
+
+        // Filtered out tasks leave their groups, and empty groups go
+        app.filter = Some(crate::kanban::filter::TaskFilter::parse("tag:bug").unwrap());
+        assert_eq!(
+            app.tag_view_groups(),
+            vec![("bug".to_string(), vec![(0, 0), (1, 0)])]
+        );
+        app.filter = None;
+
+        // So do tasks folded away in their column
+        app.view_state
+            .column_folds
+            .insert("Doing".to_string(), f32::MAX);
+        assert_eq!(
+            app.tag_view_groups(),
+            vec![
+                ("bug".to_string(), vec![(0, 0)]),
+                ("Untagged".to_string(), vec![(0, 1), (0, 2)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tag_view_navigation_stays_in_group() {
+        let mut app = app_with_numbered_tasks();
+        // "bug" holds A and E, which aren't neighbors in either column
+        app.columns[0].tasks[0].tags = vec!["bug".to_string()];
+        app.columns[1].tasks[1].tags = vec!["bug".to_string()];
+        app.activate_column(0);
+        app.columns[0].selected_task = Some(0);
+
+        app.step_tag_view(1);
+        assert_eq!(
+            (app.active_column, app.columns[1].selected_task),
+            (1, Some(1))
+        );
+        app.step_tag_view(1);
+        assert_eq!(
+            (app.active_column, app.columns[1].selected_task),
+            (1, Some(1))
+        );
+        app.step_tag_view(-1);
+        assert_eq!(
+            (app.active_column, app.columns[0].selected_task),
+            (0, Some(0))
+        );
+
+        // Across to the untagged group, keeping the row
+        app.step_tag_view(1);
+        app.step_tag_view_group(1);
+        assert_eq!(
+            (app.active_column, app.columns[0].selected_task),
+            (0, Some(2))
+        );
+        app.step_tag_view(1);
+        assert_eq!(
+            (app.active_column, app.columns[1].selected_task),
+            (1, Some(0))
+        );
+        app.step_tag_view_group(-1);
+        assert_eq!(
+            (app.active_column, app.columns[1].selected_task),
+            (1, Some(1))
+        );
+    }
+
+    #[test]
+    fn test_priority_view_navigation_selects_real_task() {
+        let mut app = App::new("Priority Board");
+        app.columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![task_with_impact("low", 1)],
+                selected_task: None,
+                wip_limit: None,
+            },
+            Column {
+                title: "Doing".to_string(),
+                tasks: vec![task_with_impact("high", 9)],
+                selected_task: None,
+                wip_limit: None,
+            },
+        ];
+        app.toggle_priority_view();
+
+        app.step_priority_view(1);
+        assert_eq!(app.active_column, 1);
+        assert_eq!(app.columns[1].selected_task, Some(0));
+
+        app.step_priority_view(1);
+        assert_eq!(app.active_column, 0);
+        assert_eq!(app.columns[0].selected_task, Some(0));
+
+        // Editing in the view changes the task in its column
+        assert!(app.set_priority_field(PriorityField::Impact, 10));
+        assert_eq!(
+            app.columns[0].tasks[0].priority.as_ref().unwrap().impact,
+            10
+        );
+    }
+
+    #[test]
+    fn test_sort_all_columns_by_priority() {
+        let mut app = App::new("Sort Board");
+        app.columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![
+                    task_with_impact("low", 1),
+                    task_with_impact("high", 9),
+                    task_with_impact("mid", 5),
+                ],
+                selected_task: Some(0),
+                wip_limit: None,
+            },
+            Column {
+                title: "Done".to_string(),
+                tasks: vec![
+                    Task {
+                        id: 0,
+                        title: "unscored".to_string(),
+                        description: None,
+                        priority: None,
+                        completed_at: None,
+                        tags: Vec::new(),
+                        due: None,
+                        created: None,
+                        focus_minutes: 0,
+                    },
+                    task_with_impact("b", 2),
+                    task_with_impact("a", 8),
+                ],
+                selected_task: Some(2),
+                wip_limit: None,
+            },
+        ];
+
+        app.sort_all_columns_by_priority();
+
+        assert_eq!(task_titles(&app.columns[0]), vec!["high", "mid", "low"]);
+        assert_eq!(task_titles(&app.columns[1]), vec!["a", "b", "unscored"]);
+        // Each column keeps its own selected task
+        assert_eq!(app.columns[0].selected_task, Some(2));
+        assert_eq!(app.columns[1].selected_task, Some(0));
+    }
+
+    #[test]
+    fn test_undo_move_restores_original_position() {
+        let mut app = app_with_columns(&["To Do", "Doing"]);
+        app.columns[0].tasks = vec![
+            task_with_impact("First", 1),
+            task_with_impact("Second", 1),
+            task_with_impact("Third", 1),
+        ];
+        app.columns[1].tasks = vec![task_with_impact("Busy", 1)];
+        app.columns[0].selected_task = Some(1);
+
+        app.move_task_to_column(1);
+        assert_eq!(task_titles(&app.columns[0]), vec!["First", "Third"]);
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy", "Second"]);
+
+        // A single undo reverses the whole move
+        assert!(app.undo());
+        assert_eq!(
+            task_titles(&app.columns[0]),
+            vec!["First", "Second", "Third"]
+        );
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy"]);
+        assert_eq!(app.columns[0].selected_task, Some(1));
+        assert_eq!(app.active_column, 0);
+        assert!(!app.undo());
+
+        // Moving with nothing selected leaves no undo step behind
+        app.columns[0].selected_task = None;
+        app.move_task_to_column(1);
+        assert!(!app.undo());
+    }
+
+    #[test]
+    fn test_undo_respects_history_depth() {
+        let mut app = App::new("Undo Board");
+        app.history = History::new(2);
+
+        app.add_column("One");
+        app.add_column("Two");
+        app.add_column("Three");
+
+        // Only the two most recent changes can be undone
+        assert!(app.undo());
+        assert_eq!(column_titles(&app), vec!["To Do", "One", "Two"]);
+        assert!(app.undo());
+        assert_eq!(column_titles(&app), vec!["To Do", "One"]);
+        assert!(!app.undo());
+        assert_eq!(column_titles(&app), vec!["To Do", "One"]);
+    }
+
+    #[test]
+    fn test_opening_another_board_clears_undo() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_undo_other_board.txt");
+        let other_path = temp_path.to_str().unwrap().to_string();
+        let mut other = App::new("Other Board");
+        other.file_path = Some(other_path.clone());
+        other.save_board().unwrap();
+
+        let mut app = App::new("Undo Board");
+        app.file_path = None;
+        app.add_column("One");
+        app.file_path = Some(other_path.clone());
+        app.load_board().unwrap();
+
+        // The first board's columns must not be undone into this one
+        assert!(!app.undo());
+        assert_eq!(column_titles(&app), vec!["To Do"]);
+
+        let _ = fs::remove_file(crate::kanban::storage::backup_path(&other_path));
+        fs::remove_file(&other_path).unwrap();
+    }
+
+    #[test]
+    fn test_edits_that_change_nothing_are_not_recorded() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.file_path = None;
+        app.columns[0].tasks = vec![task_with_impact("Same", 5)];
+        app.columns[0].selected_task = Some(0);
+
+        app.rename_current_task("Same");
+        app.rename_current_column("To Do");
+        assert!(app.set_priority_field(PriorityField::Impact, 5));
+        assert!(!app.dirty);
+        assert!(!app.undo());
+
+        assert!(app.set_priority_field(PriorityField::Impact, 6));
+        assert!(app.dirty);
+        assert!(app.undo());
+    }
+
+    fn app_with_columns(titles: &[&str]) -> App {
+        let mut app = App::new("Completion Board");
+        app.columns = titles
+            .iter()
+            .map(|title| Column {
+                title: title.to_string(),
+                tasks: Vec::new(),
+                selected_task: None,
+                wip_limit: None,
+            })
+            .collect();
+        app
+    }
+
+    #[test]
+    fn test_unsaved_change_summary_accumulates() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.file_path = None;
+        app.input_mode = InputMode::Normal;
+        assert_eq!(app.unsaved_changes.describe(), None);
+
+        app.add_task("First");
+        app.add_task("Second");
+        app.move_task_to_column(1);
+        app.sort_column_by_priority();
+        assert_eq!(
+            app.unsaved_changes.describe().as_deref(),
+            Some("2 tasks added, 1 task moved, 1 other change since last save")
+        );
+
+        // Without a file nothing was saved, so quitting shows the summary
+        assert!(!app.request_quit());
+        assert_eq!(
+            app.unsaved_summary(),
+            vec!["2 tasks added, 1 task moved, 1 other change since last save"]
+        );
+
+        // A successful save starts the tally over
+        let mut temp_path = std::env::temp_dir();
+        temp_path.push("kantui_change_summary.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        app.file_path = Some(file_path.clone());
+        app.save_board().unwrap();
+        assert_eq!(app.unsaved_changes, ChangeSummary::default());
+        assert!(app.unsaved_summary().is_empty());
+
+        let _ = std::fs::remove_file(crate::kanban::storage::backup_path(&file_path));
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_match_destination_column() {
+        let mut app = app_with_columns(&["To Do", "Doing", "Done", "Do"]);
+        app.active_column = 0;
+
+        // Unique: only "Done" has an 'e' after the 'n'
+        assert_eq!(
+            app.match_destination_column("dne", false),
+            ColumnMatch::Unique(2)
+        );
+        assert_eq!(
+            app.match_destination_column("DOI", false),
+            ColumnMatch::Unique(1)
+        );
+        // Ambiguous: all three remaining columns contain "do"
+        assert_eq!(
+            app.match_destination_column("do", false),
+            ColumnMatch::Ambiguous(vec![1, 2, 3])
+        );
+        // An exact name only wins once Enter is pressed
+        assert_eq!(
+            app.match_destination_column("do", true),
+            ColumnMatch::Unique(3)
+        );
+        // No match, and the active column is never a candidate
+        assert_eq!(
+            app.match_destination_column("blocked", true),
+            ColumnMatch::NoMatch
+        );
+        assert_eq!(
+            app.match_destination_column("to do", true),
+            ColumnMatch::NoMatch
+        );
+    }
+
+    #[test]
+    fn test_resolve_move_by_name() {
+        let mut app = app_with_columns(&["To Do", "Doing", "Done"]);
+        app.columns[0].tasks = vec![task_with_impact("Write docs", 5)];
+        app.columns[0].selected_task = Some(0);
+        app.active_column = 0;
+        app.input_mode = InputMode::MovingTaskByName;
+
+        // Typing an ambiguous prefix waits for more input
+        app.input_text = "do".to_string();
+        app.resolve_move_by_name(false);
+        assert!(app.input_mode == InputMode::MovingTaskByName);
+        app.resolve_move_by_name(true);
+        assert!(app.input_mode == InputMode::MovingTaskByName);
+        assert!(app.status_message.is_some());
+
+        // One more letter picks out a single column and moves at once
+        app.input_text.push('e');
+        app.resolve_move_by_name(false);
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(task_titles(&app.columns[2]), vec!["Write docs"]);
+        assert!(app.input_text.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_column_copies_tasks_with_new_ids() {
+        let mut app = App::new("Test Board");
+        let done = Column {
+            title: "Done".to_string(),
+            tasks: Vec::new(),
+            selected_task: None,
+            wip_limit: None,
+        };
+        app.columns.push(done);
+        app.active_column = 0;
+
+        app.duplicate_column();
+        let titles: Vec<&str> = app.columns.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["To Do", "To Do (copy)", "Done"]);
+        assert_eq!(app.active_column, 1);
+        assert_eq!(task_titles(&app.columns[1]), task_titles(&app.columns[0]));
+        let original_ids: Vec<usize> = app.columns[0].tasks.iter().map(|t| t.id).collect();
+        for task in &app.columns[1].tasks {
+            assert!(!original_ids.contains(&task.id));
+        }
+        assert_ne!(app.columns[1].tasks[0].id, app.columns[1].tasks[1].id);
+
+        // A second copy of the original gets its own name
+        app.active_column = 0;
+        app.duplicate_column();
+        assert_eq!(app.columns[1].title, "To Do (copy) (1)");
+        assert_eq!(app.columns[2].title, "To Do (copy)");
+    }
+
+    #[test]
+    fn test_focus_first_nonempty_column() {
+        let mut app = app_with_columns(&["Backlog", "To Do", "Doing", "Done"]);
+        app.columns[2].tasks = vec![task_with_impact("Ship it", 5)];
+        app.columns[3].tasks = vec![task_with_impact("Plan", 5)];
+        app.active_column = 0;
+
+        app.focus_first_nonempty_column();
+        assert_eq!(app.active_column, 2);
+        assert_eq!(app.columns[2].selected_task, Some(0));
+
+        // With nothing anywhere the active column stays put
+        let mut empty = app_with_columns(&["To Do", "Done"]);
+        empty.active_column = 1;
+        empty.focus_first_nonempty_column();
+        assert_eq!(empty.active_column, 1);
+        assert!(empty.status_message.is_some());
+    }
+
+    #[test]
+    fn test_hidden_empty_columns_are_skipped() {
+        let mut app = app_with_columns(&["Ideas", "To Do", "Blocked", "Doing", "Done"]);
+        app.columns[1].tasks = vec![task_with_impact("Plan", 5)];
+        app.columns[3].tasks = vec![task_with_impact("Build", 5)];
+        app.active_column = 1;
+        assert_eq!(app.shown_columns(), vec![0, 1, 2, 3, 4]);
+
+        app.toggle_hide_empty_columns();
+        assert_eq!(app.shown_columns(), vec![1, 3]);
+
+        // Navigation steps over the hidden columns
+        app.select_next_column();
+        assert_eq!(app.active_column, 3);
+        app.select_prev_column();
+        assert_eq!(app.active_column, 1);
+
+        // The active column stays shown even when empty, and a column
+        // reappears once it has a task
+        app.active_column = 0;
+        assert_eq!(app.shown_columns(), vec![0, 1, 3]);
+        app.columns[4].tasks = vec![task_with_impact("Ship", 5)];
+        assert_eq!(app.shown_columns(), vec![0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_column_completions() {
+        let app = app_with_columns(&["To Do", "In Progress", "In Review", "Done"]);
+
+        assert_eq!(app.column_completions("do"), vec!["Done".to_string()]);
+        assert_eq!(
+            app.column_completions("in"),
+            vec!["In Progress".to_string(), "In Review".to_string()]
+        );
+        assert!(app.column_completions("blocked").is_empty());
+    }
+
+    #[test]
+    fn test_complete_column_name_cycles() {
+        let mut app = app_with_columns(&["To Do", "In Progress", "In Review", "Done"]);
+
+        app.input_text = "in".to_string();
+        app.complete_column_name();
+        assert_eq!(app.input_text, "In Progress");
+        app.complete_column_name();
+        assert_eq!(app.input_text, "In Review");
+        app.complete_column_name();
+        assert_eq!(app.input_text, "In Progress");
+
+        // No match leaves the input alone
+        app.completion = None;
+        app.input_text = "xyz".to_string();
+        app.complete_column_name();
+        assert_eq!(app.input_text, "xyz");
+        assert!(app.completion.is_none());
+    }
+
+    fn app_with_selected_task() -> App {
+        let mut app = App::new("Priority Board");
+        app.file_path = None;
+        app.columns = vec![Column {
+            title: "To Do".to_string(),
+            tasks: vec![task_with_impact("Tune me", 5)],
+            selected_task: Some(0),
+            wip_limit: None,
+        }];
+        app.active_column = 0;
+        app
+    }
+
+    fn selected_priority(app: &App) -> crud::Priority {
+        app.columns[0].tasks[0].priority.clone().unwrap()
+    }
+
+    #[test]
+    fn test_set_priority_field_impact() {
+        let mut app = app_with_selected_task();
+
+        assert!(app.set_priority_field(PriorityField::Impact, 9));
+        assert_eq!(selected_priority(&app).impact, 9);
+        assert!(!app.set_priority_field(PriorityField::Impact, 11));
+        assert_eq!(selected_priority(&app).impact, 9);
+    }
+
+    #[test]
+    fn test_set_priority_field_urgency() {
+        let mut app = app_with_selected_task();
+
+        assert!(app.set_priority_field(PriorityField::Urgency, 0));
+        assert_eq!(selected_priority(&app).urgency, 0);
+        assert!(!app.set_priority_field(PriorityField::Urgency, 42));
+        assert_eq!(selected_priority(&app).urgency, 0);
+    }
+
+    #[test]
+    fn test_set_priority_field_effort() {
+        let mut app = app_with_selected_task();
+
+        assert!(app.set_priority_field(PriorityField::Effort, 10));
+        assert_eq!(selected_priority(&app).effort, 10);
+        assert!(!app.set_priority_field(PriorityField::Effort, 255));
+        assert_eq!(selected_priority(&app).effort, 10);
+
+        // Nothing selected means nothing to update
+        app.columns[0].selected_task = None;
+        assert!(!app.set_priority_field(PriorityField::Effort, 1));
+    }
+
+    #[test]
+    fn test_set_wip_limit() {
+        let mut app = app_with_selected_task();
+
+        app.prepare_edit_wip_limit();
+        assert!(app.input_mode == InputMode::EditingWipLimit);
+        assert_eq!(app.input_text, "");
+
+        assert!(app.set_wip_limit(Some(3)));
+        assert_eq!(app.columns[0].wip_limit, Some(3));
+        app.prepare_edit_wip_limit();
+        assert_eq!(app.input_text, "3");
+
+        assert!(!app.set_wip_limit(Some(0)));
+        assert_eq!(app.columns[0].wip_limit, Some(3));
+
+        assert!(app.set_wip_limit(None));
+        assert_eq!(app.columns[0].wip_limit, None);
+        assert!(app.undo());
+        assert_eq!(app.columns[0].wip_limit, Some(3));
+    }
+
+    #[test]
+    fn test_urgency_and_effort_edits_change_card_color() {
+        use crate::kanban::ui::task_formatter::task_priority_color;
+        let mut app = app_with_selected_task();
+        let color =
+            |app: &App| task_priority_color(&app.columns[0].tasks[0], app.config.priority_formula);
+        let before = color(&app);
+
+        assert!(app.set_priority_field(PriorityField::Urgency, 10));
+        assert!(app.set_priority_field(PriorityField::Effort, 1));
+        let urgent = color(&app);
+        assert_ne!(urgent, before);
+
+        assert!(app.set_priority_field(PriorityField::Urgency, 0));
+        assert!(app.set_priority_field(PriorityField::Effort, 10));
+        assert_ne!(color(&app), urgent);
+    }
+
+    #[test]
+    fn test_move_task_to_least_loaded_column() {
+        let mut app = app_with_columns(&["To Do", "Doing", "Review"]);
+        for (column, count) in app.columns.iter_mut().zip([3, 1, 2]) {
+            column.tasks = (0..count)
+                .map(|i| task_with_impact(&format!("{} {}", column.title, i), 5))
+                .collect();
+        }
+        app.active_column = 0;
+        app.columns[0].selected_task = Some(0);
+
+        app.move_task_to_least_loaded_column();
+        assert_eq!(task_titles(&app.columns[1]), vec!["Doing 0", "To Do 0"]);
+        assert_eq!(app.columns[0].tasks.len(), 2);
+
+        // Doing and Review now both hold two; the leftmost wins
+        app.columns[0].selected_task = Some(0);
+        app.move_task_to_least_loaded_column();
+        assert_eq!(app.columns[1].tasks.len(), 3);
+        assert_eq!(app.columns[2].tasks.len(), 2);
+
+        // With a single column there is nowhere to go
+        let mut app = app_with_columns(&["To Do"]);
+        app.columns[0].tasks.push(task_with_impact("Only", 5));
+        app.columns[0].selected_task = Some(0);
+        app.move_task_to_least_loaded_column();
+        assert_eq!(task_titles(&app.columns[0]), vec!["Only"]);
+    }
+
+    fn app_with_full_column(policy: WipPolicy) -> App {
+        let mut app = App::new("WIP Board");
+        app.file_path = None;
+        app.config.wip_policy = policy;
+        app.columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![task_with_impact("Waiting", 5)],
+                selected_task: Some(0),
+                wip_limit: None,
+            },
+            Column {
+                title: "In Progress".to_string(),
+                tasks: vec![task_with_impact("Busy", 5)],
+                selected_task: None,
+                wip_limit: Some(1),
+            },
+        ];
+        app.active_column = 0;
+        app
+    }
+
+    #[test]
+    fn test_wip_policy_allow_moves() {
+        let mut app = app_with_full_column(WipPolicy::Allow);
+
+        app.request_move_task_to_column(1);
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy", "Waiting"]);
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_wip_policy_block_refuses() {
+        let mut app = app_with_full_column(WipPolicy::Block);
+
+        app.request_move_task_to_column(1);
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy"]);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("In Progress is at its WIP limit of 1")
+        );
+    }
+
+    #[test]
+    fn test_wip_policy_confirm_asks_first() {
+        let mut app = app_with_full_column(WipPolicy::Confirm);
+
+        app.request_move_task_to_column(1);
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy"]);
+        assert!(app.input_mode == InputMode::ConfirmWipOverride(1));
+
+        // Columns under their limit never ask
+        app.columns[1].wip_limit = Some(5);
+        app.request_move_task_to_column(1);
+        assert_eq!(task_titles(&app.columns[1]), vec!["Busy", "Waiting"]);
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_custom_jump_label_alphabet() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.config.jump_labels = "asdf".to_string();
+        app.columns[0].tasks = vec![task_with_impact("One", 5), task_with_impact("Two", 5)];
+        app.columns[1].tasks = vec![task_with_impact("Three", 5)];
+
+        assert_eq!(app.get_jump_label_for_task(0, 0), Some('a'));
+        assert_eq!(app.get_jump_label_for_task(0, 1), Some('s'));
+        assert_eq!(app.get_jump_label_for_task(1, 0), Some('d'));
+        assert_eq!(app.get_task_by_jump_label('d'), Some((1, 0)));
+        assert_eq!(app.get_task_by_jump_label('b'), None);
+    }
+
+    #[test]
+    fn test_merge_duplicate_columns() {
+        let mut app = app_with_columns(&["To Do", "Doing", "To Do"]);
+        app.file_path = None;
+        app.columns[0].tasks = vec![task_with_impact("A", 5), task_with_impact("B", 5)];
+        app.columns[2].tasks = vec![task_with_impact("C", 5)];
+        app.active_column = 2;
+
+        assert_eq!(app.find_duplicate_columns(), Some((0, 2)));
+        assert!(app.merge_columns(0, 2));
+
+        assert_eq!(column_titles(&app), vec!["To Do", "Doing"]);
+        assert_eq!(task_titles(&app.columns[0]), vec!["A", "B", "C"]);
+        assert_eq!(app.active_column, 0);
+        assert_eq!(app.find_duplicate_columns(), None);
+        assert!(!app.merge_columns(1, 1));
+    }
+
+    #[test]
+    fn test_moving_into_done_stamps_completed_at() {
+        let mut app = app_with_columns(&["To Do", "Done", "Archive"]);
+        app.file_path = None;
+        app.config.done_columns = vec!["done".to_string(), "archive".to_string()];
+        app.columns[0].tasks = vec![task_with_impact("Ship it", 5)];
+        app.columns[0].selected_task = Some(0);
+        app.active_column = 0;
+
+        app.move_task_to_column(1);
+        let stamp = app.columns[1].tasks[0].completed_at.clone();
+        assert!(stamp.is_some());
+        assert_eq!(app.done_summary_lines().len(), 1);
+
+        // Moving between done columns keeps the original time
+        app.active_column = 1;
+        app.columns[1].selected_task = Some(0);
+        app.move_task_to_column(2);
+        assert_eq!(app.columns[2].tasks[0].completed_at, stamp);
+    }
+
+    #[test]
+    fn test_move_keeps_source_column_active_by_default() {
+        let mut app = app_with_columns(&["To Do", "Doing"]);
+        app.file_path = None;
+        app.columns[0].tasks = vec![task_with_impact("A", 5), task_with_impact("B", 5)];
+        app.columns[1].tasks = vec![task_with_impact("C", 5)];
+        app.columns[0].selected_task = Some(0);
+        app.active_column = 0;
+
+        app.move_task_to_column(1);
+
+        assert_eq!(app.active_column, 0);
+        assert_eq!(app.selected_task_title(), Some("B"));
+        assert_eq!(app.columns[1].selected_task, None);
+    }
+
+    fn app_with_numbered_tasks() -> App {
+        let mut app = app_with_columns(&["To Do", "Doing"]);
+        app.file_path = None;
+        for (column, titles) in [(0, ["A", "B", "C"]), (1, ["D", "E", "F"])] {
+            app.columns[column].tasks = titles
+                .iter()
+                .enumerate()
+                .map(|(idx, title)| Task {
+                    id: column * 3 + idx + 1,
+                    ..task_with_impact(title, 5)
+                })
+                .collect();
+            app.columns[column].selected_task = Some(0);
+        }
+        app
+    }
+
+    #[test]
+    fn test_change_highlight_follows_the_task() {
+        let mut app = app_with_numbered_tasks();
+        app.move_task_to(1, "doing", 1).unwrap();
+        assert!(app.is_task_recently_changed(1, 1));
+
+        // Another task taking its place doesn't inherit the highlight
+        app.columns[1].tasks.swap(0, 1);
+        assert!(!app.is_task_recently_changed(1, 1));
+        assert!(app.is_task_recently_changed(1, 0));
+
+        // Moving it again makes it the only highlighted task
+        app.move_task_to(1, "to do", 0).unwrap();
+        assert!(app.is_task_recently_changed(0, 0));
+        assert!(!app.is_task_recently_changed(1, 0));
+    }
+
+    #[test]
+    fn test_move_task_to_across_columns() {
+        let mut app = app_with_numbered_tasks();
+
+        app.move_task_to(1, "doing", 1).unwrap();
+        assert_eq!(task_titles(&app.columns[0]), vec!["B", "C"]);
+        assert_eq!(task_titles(&app.columns[1]), vec!["D", "A", "E", "F"]);
+        assert_eq!(app.columns[0].selected_task, Some(0));
+        assert_eq!(app.columns[1].selected_task, Some(1));
+        assert_eq!(app.active_column, 0);
+
+        assert!(app.undo());
+        assert_eq!(task_titles(&app.columns[0]), vec!["A", "B", "C"]);
+
+        assert!(app.move_task_to(42, "Doing", 0).is_err());
+        assert!(app.move_task_to(1, "Nowhere", 0).is_err());
+    }
+
+    #[test]
+    fn test_move_task_to_respects_wip_limit() {
+        let mut app = app_with_numbered_tasks();
+        app.columns[1].wip_limit = Some(3);
+
+        for policy in [WipPolicy::Block, WipPolicy::Confirm] {
+            app.config.wip_policy = policy;
+            assert!(matches!(
+                app.move_task_to(1, "Doing", 0),
+                Err(KanbanError::WipLimit { limit: 3, .. })
+            ));
+            assert_eq!(task_titles(&app.columns[1]), vec!["D", "E", "F"]);
+        }
+
+        // Reordering inside the full column is still fine
+        app.move_task_to(6, "Doing", 0).unwrap();
+        assert_eq!(task_titles(&app.columns[1]), vec!["F", "D", "E"]);
+
+        app.config.wip_policy = WipPolicy::Allow;
+        app.move_task_to(1, "Doing", 0).unwrap();
+        assert_eq!(task_titles(&app.columns[1]), vec!["A", "F", "D", "E"]);
+    }
+
+    #[test]
+    fn test_reorders_are_journaled_with_their_position() {
+        let mut path = env::temp_dir();
+        path.push("kantui_journal_reorder.txt");
+        let file_path = path.to_str().unwrap().to_string();
+        let journal = crate::kanban::journal::journal_path(&file_path);
+        let _ = fs::remove_file(&journal);
+        // A directory where the board file should be makes every save fail,
+        // so the journal keeps what it was given
+        fs::create_dir_all(&path).unwrap();
+
+        let mut app = app_with_numbered_tasks();
+        app.file_path = Some(file_path.clone());
+        app.config.journal = true;
+        app.move_task_to(3, "To Do", 0).unwrap();
+        app.move_task_to(1, "Doing", 1).unwrap();
+
+        assert_eq!(
+            crate::kanban::journal::read_journal(&journal),
+            vec![
+                JournalOp::Move {
+                    id: 3,
+                    column: "To Do".to_string(),
+                    index: 0,
+                },
+                JournalOp::Move {
+                    id: 1,
+                    column: "Doing".to_string(),
+                    index: 1,
+                },
+            ]
+        );
+        assert_eq!(app.unsaved_changes.moved, 2);
+
+        fs::remove_file(&journal).unwrap();
+        fs::remove_dir(&path).unwrap();
+    }
+
+    #[test]
+    fn test_move_task_to_clamps_index() {
+        let mut app = app_with_numbered_tasks();
+
+        app.move_task_to(2, "To Do", 99).unwrap();
+        assert_eq!(task_titles(&app.columns[0]), vec!["A", "C", "B"]);
+        assert_eq!(app.columns[0].selected_task, Some(2));
+
+        app.move_task_to(4, "To Do", 99).unwrap();
+        assert_eq!(task_titles(&app.columns[0]), vec!["A", "C", "B", "D"]);
+        assert_eq!(task_titles(&app.columns[1]), vec!["E", "F"]);
+    }
+
+    #[test]
+    fn test_move_task_to_same_position_is_noop() {
+        let mut app = app_with_numbered_tasks();
+        app.columns[0].selected_task = Some(2);
+
+        app.move_task_to(2, "To Do", 1).unwrap();
+        assert_eq!(task_titles(&app.columns[0]), vec!["A", "B", "C"]);
+        assert_eq!(app.columns[0].selected_task, Some(2));
+        assert!(!app.dirty);
+        assert!(!app.undo());
+
+        // Shifting the first task up has nowhere to go either
+        app.columns[0].selected_task = Some(0);
+        app.shift_selected_task(false);
+        assert!(!app.dirty);
+        app.shift_selected_task(true);
+        assert_eq!(task_titles(&app.columns[0]), vec!["B", "A", "C"]);
+        assert_eq!(app.columns[0].selected_task, Some(1));
+    }
+
+    #[test]
+    fn test_follow_moved_task_selects_it_in_destination() {
+        let mut app = app_with_columns(&["To Do", "Doing"]);
+        app.file_path = None;
+        app.config.follow_moved_task = true;
+        app.columns[0].tasks = vec![task_with_impact("A", 5), task_with_impact("B", 5)];
+        app.columns[1].tasks = vec![task_with_impact("C", 5)];
+        app.columns[0].selected_task = Some(0);
+        app.active_column = 0;
+
+        app.move_task_to_column(1);
+
+        assert_eq!(app.active_column, 1);
+        assert_eq!(app.columns[1].selected_task, Some(1));
+        assert_eq!(app.selected_task_title(), Some("A"));
+        assert_eq!(app.columns[0].selected_task, None);
+    }
+
+    #[test]
+    fn test_moving_out_of_done_clears_completed_at() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.file_path = None;
+        let mut task = task_with_impact("Reopened", 5);
+        task.completed_at = Some("2025-03-24 17:30".to_string());
+        app.columns[1].tasks = vec![task];
+        app.columns[1].selected_task = Some(0);
+        app.active_column = 1;
+
+        app.move_task_to_column(0);
+        assert_eq!(app.columns[0].tasks[0].completed_at, None);
+        assert!(app.done_summary_lines().is_empty());
+    }
+
+    #[test]
+    fn test_board_operations_skip_create_entry() {
+        let mut app = App::new("Picker Board");
+        app.available_boards = vec!["alpha".to_string(), "beta".to_string()];
+
+        assert_eq!(
+            app.board_entries(),
+            vec![
+                BoardEntry::Board("alpha"),
+                BoardEntry::Board("beta"),
+                BoardEntry::CreateNew
+            ]
+        );
+
+        // Navigation stops at the create entry
+        app.selected_board_index = Some(1);
+        app.select_next_board();
+        app.select_next_board();
+        assert_eq!(app.selected_board_index, Some(2));
+        assert_eq!(app.selected_board_entry(), Some(BoardEntry::CreateNew));
+
+        // Reordering the create entry does nothing
+        app.move_selected_board(true).unwrap();
+        assert_eq!(app.available_boards, vec!["alpha", "beta"]);
+        assert_eq!(app.selected_board_index, Some(2));
+
+        // Selecting it opens the new-board prompt rather than loading a file
+        app.load_selected_board().unwrap();
+        assert!(app.input_mode == InputMode::AddingBoard);
+    }
+
+    #[test]
+    fn test_seed_columns_follow_config() {
+        let mut app = App::new("Seed Board");
+        let titles: Vec<String> = app.seed_columns().into_iter().map(|c| c.title).collect();
+        assert_eq!(titles, vec!["To Do"]);
+
+        app.config.seed_columns = vec!["Backlog".to_string(), "Doing".to_string()];
+        let titles: Vec<String> = app.seed_columns().into_iter().map(|c| c.title).collect();
+        assert_eq!(titles, vec!["Backlog", "Doing"]);
+    }
+
+    #[test]
+    fn test_board_info_lines() {
+        let mut path = env::temp_dir();
+        path.push("kantui_info_board.txt");
+        let file_path = path.to_str().unwrap().to_string();
+        // A board from before the Format header
+        fs::write(
+            &path,
+            "# TUI Kanban Board: Info Board\nDate: 2024-01-01\n\n== To Do ==\n",
+        )
+        .unwrap();
+        let mut app = App::new("Info Board");
+        app.file_path = Some(file_path.clone());
+        app.load_board().unwrap();
+
+        let lines = app.board_info_lines(Some("/boards"));
+        assert!(lines.contains(&format!("File: {}", file_path)));
+        assert!(lines.contains(&"KANBAN_DIR: /boards".to_string()));
+        assert!(lines.contains(&"Format version: 1".to_string()));
+
+        // Saving writes the current format
+        app.save_board().unwrap();
+        let lines = app.board_info_lines(Some("/boards"));
+        assert!(lines.contains(&format!("Format version: {}", crud::FORMAT_VERSION)));
+        fs::remove_file(&path).unwrap();
+
+        app.file_path = None;
+        let lines = app.board_info_lines(None);
+        assert!(lines.contains(&"File: (none - changes are not saved)".to_string()));
+        assert!(lines.contains(&"KANBAN_DIR: (not set)".to_string()));
+    }
+
+    #[test]
+    fn test_save_with_feedback_sets_status() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_save_feedback.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+
+        let mut app = App::new("Feedback Board");
+        app.file_path = Some(file_path.clone());
+        app.save_with_feedback();
+
+        assert_eq!(app.status_message, Some(format!("Saved to {}", file_path)));
+        assert!(app.is_recently_saved());
+
+        fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_save_with_feedback_reports_error() {
+        let mut app = App::new("Feedback Board");
+        app.file_path = None;
+        app.save_with_feedback();
+
+        assert!(
+            app.status_message
+                .as_deref()
+                .is_some_and(|msg| msg.starts_with("Error saving board"))
+        );
+        assert!(!app.is_recently_saved());
+    }
+
+    #[test]
+    fn test_sticky_add_stays_in_add_mode() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.config.sticky_add = true;
+        app.input_mode = InputMode::AddingTask;
+        app.input_text = "First".to_string();
+
+        app.add_task("First");
+
+        assert!(app.input_mode == InputMode::AddingTask);
+        assert!(app.input_text.is_empty());
+        assert_eq!(app.columns[0].tasks.len(), 1);
+
+        app.config.sticky_add = false;
+        app.add_task("Second");
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_paste_multiple_lines_adds_one_task_per_line() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.input_mode = InputMode::AddingTask;
+        app.input_text = "Buy ".to_string();
+
+        app.paste_text("milk\r\n\n  eggs  \nbread\n");
+
+        assert_eq!(
+            task_titles(&app.columns[0]),
+            vec!["Buy milk", "eggs", "bread"]
+        );
+        assert_eq!(app.columns[0].selected_task, Some(2));
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(app.input_text.is_empty());
+
+        // The whole paste is undone in one step
+        app.undo();
+        assert!(app.columns[0].tasks.is_empty());
+    }
+
+    #[test]
+    fn test_paste_single_line_appends_to_input() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.input_mode = InputMode::AddingTask;
+        app.paste_text("Write docs");
+        assert_eq!(app.input_text, "Write docs");
+        assert!(app.columns[0].tasks.is_empty());
+
+        app.input_mode = InputMode::RenamingColumn;
+        app.input_text.clear();
+        app.paste_text("Two\nlines");
+        assert_eq!(app.input_text, "Two lines");
+    }
+
+    #[test]
+    fn test_sort_direction_reverses_order() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.columns[0].tasks = vec![
+            task_with_impact("Mid", 5),
+            task_with_impact("High", 9),
+            task_with_impact("Low", 1),
+        ];
+
+        app.sort_column_by_priority();
+        assert_eq!(task_titles(&app.columns[0]), vec!["High", "Mid", "Low"]);
+
+        app.toggle_sort_direction();
+        assert_eq!(app.view_state.sort_direction, SortDirection::Ascending);
+        app.sort_column_by_priority();
+        assert_eq!(task_titles(&app.columns[0]), vec!["Low", "Mid", "High"]);
+    }
+
+    #[test]
+    fn test_rename_prefills_current_title() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.columns[0].tasks = vec![task_with_impact("Write docs", 5)];
+        app.columns[0].selected_task = Some(0);
+
+        app.prepare_rename_task();
+        assert!(app.input_mode == InputMode::RenamingTask);
+        assert_eq!(app.input_text, "Write docs");
+
+        app.input_mode = InputMode::Normal;
+        app.active_column = 1;
+        app.prepare_rename_column();
+        assert!(app.input_mode == InputMode::RenamingColumn);
+        assert_eq!(app.input_text, "Done");
+    }
+
+    #[test]
+    fn test_sanitize_title_strips_invisible_characters() {
+        assert_eq!(sanitize_title("Fix\tlogin"), "Fix login");
+        assert_eq!(sanitize_title("Fix\u{200B}login"), "Fixlogin");
+        assert_eq!(sanitize_title("Fix\u{1b}[31m login\u{7}"), "Fix[31m login");
+        assert_eq!(sanitize_title("Café 🚀"), "Café 🚀");
+        assert_eq!(
+            sanitize_title(" Release list \r\nTag the build\n"),
+            "Release list\nTag the build"
+        );
+    }
+
+    #[test]
+    fn test_new_and_renamed_titles_are_sanitized() {
+        let mut app = app_with_columns(&["To Do"]);
+
+        app.add_task("Write\tdocs\u{200B}");
+        assert_eq!(app.columns[0].tasks[0].title, "Write docs");
+
+        app.rename_current_task("Ship\u{0}\trelease");
+        assert_eq!(app.columns[0].tasks[0].title, "Ship release");
+    }
+
+    #[test]
+    fn test_split_task_inserts_after_original() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.columns[0].tasks = vec![task_with_impact("Big job", 7), task_with_impact("Other", 2)];
+        app.columns[0].tasks[0].id = 1;
+        app.columns[0].tasks[0].tags = vec!["infra".to_string()];
+        app.columns[0].tasks[1].id = 2;
+        app.columns[0].selected_task = Some(0);
+
+        app.prepare_split_task();
+        assert!(app.input_mode == InputMode::SplittingTask);
+        app.split_task("Second half");
+
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(
+            task_titles(&app.columns[0]),
+            vec!["Big job", "Second half", "Other"]
+        );
+        assert_eq!(app.columns[0].selected_task, Some(1));
+        let (original, split) = (&app.columns[0].tasks[0], &app.columns[0].tasks[1]);
+        assert_eq!(split.priority, original.priority);
+        assert_eq!(split.tags, vec!["infra"]);
+        assert_eq!(split.id, 3);
+
+        // A blank title leaves the column alone
+        app.split_task("  ");
+        assert_eq!(app.columns[0].tasks.len(), 3);
+    }
+
+    #[test]
+    fn test_jump_to_task_id() {
+        let mut app = app_with_columns(&["To Do", "Done"]);
+        app.columns[1].tasks = vec![task_with_impact("First", 5), task_with_impact("Second", 5)];
+        app.columns[1].tasks[0].id = 7;
+        app.columns[1].tasks[1].id = 12;
+
+        app.input_text = "12".to_string();
+        app.jump_to_task_id();
+        assert_eq!(app.active_column, 1);
+        assert_eq!(app.columns[1].selected_task, Some(1));
+
+        app.input_text = "99".to_string();
+        app.jump_to_task_id();
+        assert_eq!(app.active_column, 1);
+        assert_eq!(app.columns[1].selected_task, Some(1));
+        assert_eq!(app.status_message.as_deref(), Some("No task with ID 99"));
+    }
+
+    #[test]
+    fn test_assign_missing_task_ids() {
+        let mut app = app_with_columns(&["To Do"]);
+        app.columns[0].tasks = vec![
+            task_with_impact("Kept", 5),
+            task_with_impact("Duplicate", 5),
+            task_with_impact("Missing", 5),
+        ];
+        app.columns[0].tasks[0].id = 4;
+        app.columns[0].tasks[1].id = 4;
+
+        app.assign_missing_task_ids();
+
+        let ids: Vec<usize> = app.columns[0].tasks.iter().map(|task| task.id).collect();
+        assert_eq!(ids, vec![4, 5, 6]);
+        assert_eq!(app.next_task_id(), 7);
+    }
+
+    #[test]
+    fn test_wrap_navigation() {
+        let mut app = app_with_columns(&["To Do", "Doing", "Done"]);
+        app.columns[0].tasks = vec![task_with_impact("One", 5), task_with_impact("Two", 5)];
+        app.columns[0].selected_task = Some(1);
+        app.active_column = 0;
+
+        // Off by default: the ends are hard stops
+        app.select_prev_column();
+        assert_eq!(app.active_column, 0);
+        app.select_next_task();
+        assert_eq!(app.columns[0].selected_task, Some(1));
+
+        app.config.wrap_navigation = true;
+        app.select_next_task();
+        assert_eq!(app.columns[0].selected_task, Some(0));
+        app.select_prev_task();
+        assert_eq!(app.columns[0].selected_task, Some(1));
+
+        app.select_prev_column();
+        assert_eq!(app.active_column, 2);
+        app.select_next_column();
+        assert_eq!(app.active_column, 0);
+
+        // A single column or task has nowhere to wrap to
+        app.columns.truncate(1);
+        app.columns[0].tasks.truncate(1);
+        app.columns[0].selected_task = Some(0);
+        app.select_next_column();
+        assert_eq!(app.active_column, 0);
+        app.select_next_task();
+        assert_eq!(app.columns[0].selected_task, Some(0));
+    }
+}
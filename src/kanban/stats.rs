@@ -0,0 +1,256 @@
+use crate::kanban::models::{App, InputMode};
+use chrono::{Local, NaiveDate};
+
+/// Totals shown in the board statistics popup. Computed once when the
+/// popup opens, so they describe the board as it was at that moment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardStats {
+    /// Title and number of tasks of each column, in board order
+    pub column_counts: Vec<(String, usize)>,
+    /// Tasks on the whole board
+    pub total_tasks: usize,
+    /// Tasks sitting in one of the configured done columns
+    pub done_tasks: usize,
+    /// Mean computed priority of the tasks that have one
+    pub average_priority: Option<f32>,
+    /// Unfinished tasks whose due date is before today
+    pub overdue: usize,
+    /// Title and age in days of the unfinished task created longest ago
+    pub oldest_task: Option<(String, i64)>,
+}
+
+impl BoardStats {
+    /// Gather the statistics for a board. `today` decides what is overdue
+    /// and how old tasks are.
+    pub fn compute(app: &App, today: NaiveDate) -> BoardStats {
+        let mut stats = BoardStats {
+            column_counts: Vec::new(),
+            total_tasks: 0,
+            done_tasks: 0,
+            average_priority: None,
+            overdue: 0,
+            oldest_task: None,
+        };
+        let mut priority_sum = 0.0;
+        let mut priority_count = 0;
+
+        for (column_idx, column) in app.columns.iter().enumerate() {
+            stats
+                .column_counts
+                .push((column.title.clone(), column.tasks.len()));
+            stats.total_tasks += column.tasks.len();
+            let is_done = app.is_done_column(column_idx);
+            if is_done {
+                stats.done_tasks += column.tasks.len();
+            }
+
+            for task in &column.tasks {
+                if let Some(score) = task.computed_priority(app.config.priority_formula) {
+                    priority_sum += score;
+                    priority_count += 1;
+                }
+                if is_done {
+                    continue;
+                }
+                if task
+                    .due
+                    .as_deref()
+                    .and_then(parse_date)
+                    .is_some_and(|due| due < today)
+                {
+                    stats.overdue += 1;
+                }
+                let Some(created) = task.created.as_deref().and_then(parse_date) else {
+                    continue;
+                };
+                let age = (today - created).num_days();
+                if stats
+                    .oldest_task
+                    .as_ref()
+                    .is_none_or(|(_, oldest)| age > *oldest)
+                {
+                    stats.oldest_task = Some((task.title.clone(), age));
+                }
+            }
+        }
+
+        if priority_count > 0 {
+            stats.average_priority = Some(priority_sum / priority_count as f32);
+        }
+        stats
+    }
+
+    /// Share of all tasks that are done, from 0 to 100
+    pub fn completion_percent(&self) -> usize {
+        (self.done_tasks * 100)
+            .checked_div(self.total_tasks)
+            .unwrap_or(0)
+    }
+
+    /// The statistics as lines of text for the popup
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .column_counts
+            .iter()
+            .map(|(title, count)| format!("{}: {} task(s)", title, count))
+            .collect();
+        lines.push(String::new());
+        lines.push(format!(
+            "Completed: {} of {} ({}%)",
+            self.done_tasks,
+            self.total_tasks,
+            self.completion_percent()
+        ));
+        lines.push(match self.average_priority {
+            Some(average) => format!("Average priority: {:.2}", average),
+            None => "Average priority: -".to_string(),
+        });
+        lines.push(format!("Overdue: {}", self.overdue));
+        lines.push(match &self.oldest_task {
+            Some((title, days)) => format!("Oldest open task: '{}' ({} days)", title, days),
+            None => "Oldest open task: -".to_string(),
+        });
+        lines
+    }
+}
+
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+}
+
+impl App {
+    /// Compute the board statistics and show them in a popup
+    pub fn open_board_stats(&mut self) {
+        self.board_stats = Some(BoardStats::compute(self, Local::now().date_naive()));
+        self.input_mode = InputMode::BoardStats;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::{Column, Task};
+    use crate::ops::crud::{Priority, PriorityFormula};
+
+    fn task(title: &str, effort: u8, created: Option<&str>, due: Option<&str>) -> Task {
+        Task {
+            id: 0,
+            title: title.to_string(),
+            description: None,
+            priority: Some(Priority {
+                impact: 10,
+                urgency: 10,
+                effort,
+            }),
+            completed_at: None,
+            tags: Vec::new(),
+            due: due.map(str::to_string),
+            created: created.map(str::to_string),
+            focus_minutes: 0,
+        }
+    }
+
+    fn column(title: &str, tasks: Vec<Task>) -> Column {
+        Column {
+            title: title.to_string(),
+            tasks,
+            selected_task: None,
+            wip_limit: None,
+        }
+    }
+
+    // Three scored tasks with efforts 10, 5 and 2, plus one unscored task
+    fn fixture() -> (App, NaiveDate) {
+        let mut app = App::new("Stats Board");
+        let mut unscored = task("Someday", 1, None, Some("2025-03-01"));
+        unscored.priority = None;
+        app.columns = vec![
+            column(
+                "To Do",
+                vec![
+                    task("Write docs", 10, Some("2025-03-10"), Some("2025-03-20")),
+                    unscored,
+                ],
+            ),
+            column(
+                "Doing",
+                vec![task("Fix crash", 5, Some("2025-03-01"), Some("2025-03-24"))],
+            ),
+            column(
+                "Done",
+                vec![task("Set up", 2, Some("2025-01-01"), Some("2025-02-01"))],
+            ),
+        ];
+        (app, NaiveDate::from_ymd_opt(2025, 3, 24).unwrap())
+    }
+
+    #[test]
+    fn test_stats_count_tasks_per_column() {
+        let (app, today) = fixture();
+        let stats = BoardStats::compute(&app, today);
+
+        assert_eq!(
+            stats.column_counts,
+            vec![
+                ("To Do".to_string(), 2),
+                ("Doing".to_string(), 1),
+                ("Done".to_string(), 1),
+            ]
+        );
+        assert_eq!(stats.total_tasks, 4);
+    }
+
+    #[test]
+    fn test_stats_completion() {
+        let (app, today) = fixture();
+        let stats = BoardStats::compute(&app, today);
+
+        assert_eq!(stats.done_tasks, 1);
+        assert_eq!(stats.completion_percent(), 25);
+
+        // An empty board doesn't divide by zero
+        let mut empty = App::new("Empty");
+        empty.columns.clear();
+        assert_eq!(BoardStats::compute(&empty, today).completion_percent(), 0);
+    }
+
+    #[test]
+    fn test_stats_average_priority_skips_unscored_tasks() {
+        let (app, today) = fixture();
+        let stats = BoardStats::compute(&app, today);
+
+        let expected = [10, 5, 2]
+            .iter()
+            .map(|&effort| {
+                task("", effort, None, None)
+                    .computed_priority(PriorityFormula::default())
+                    .unwrap()
+            })
+            .sum::<f32>()
+            / 3.0;
+        assert!((stats.average_priority.unwrap() - expected).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_stats_overdue_ignores_done_and_due_today() {
+        let (app, today) = fixture();
+        let stats = BoardStats::compute(&app, today);
+
+        // "Write docs" and "Someday"; "Fix crash" is due today and
+        // "Set up" is already done
+        assert_eq!(stats.overdue, 2);
+    }
+
+    #[test]
+    fn test_stats_oldest_open_task() {
+        let (app, today) = fixture();
+        let stats = BoardStats::compute(&app, today);
+
+        assert_eq!(stats.oldest_task, Some(("Fix crash".to_string(), 23)));
+        assert!(
+            stats
+                .lines()
+                .contains(&"Oldest open task: 'Fix crash' (23 days)".to_string())
+        );
+    }
+}
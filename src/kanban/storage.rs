@@ -1,30 +1,81 @@
-use crate::kanban::models::{App, Column, Task};
+use crate::kanban::models::{App, ChangeSummary, Column, InputMode, Task, board_file_path};
 use crate::ops::crud;
+use crate::ops::error::KanbanError;
 use chrono::Local;
 use std::fs;
-use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Shown when an edit can't be saved because the board has no file
+pub const UNSAVED_NO_FILE_MESSAGE: &str =
+    "Changes are not being saved: no board file (is KANBAN_DIR set?)";
+
+/// Shown when an edit is made to a read-only board
+pub const READ_ONLY_MESSAGE: &str = "Read-only view: changes are not saved";
+
+/// The board directory named by KANBAN_DIR, created if it doesn't exist
+pub fn kanban_dir() -> Result<PathBuf, KanbanError> {
+    let dir = std::env::var("KANBAN_DIR").map_err(|_| KanbanError::DirMissing)?;
+    resolve_board_dir(Path::new(&dir))
+}
+
+/// Check that `dir` can hold boards, creating it if it doesn't exist. A
+/// path to a file is reported as such, rather than as the unhelpful error
+/// reading or creating the directory would give.
+pub fn resolve_board_dir(dir: &Path) -> Result<PathBuf, KanbanError> {
+    if !dir.exists() {
+        fs::create_dir_all(dir)?;
+    } else if !dir.is_dir() {
+        return Err(KanbanError::NotADirectory(dir.display().to_string()));
+    }
+    Ok(dir.to_path_buf())
+}
+
+/// A board that is no longer open but has changes that couldn't be saved
+/// when it was left. `save_all` retries it, and opening it again picks the
+/// changes back up.
+#[derive(Clone, Debug)]
+pub struct UnsavedBoard {
+    pub title: String,
+    pub path: String,
+    pub board: crud::Board,
+}
+
+// Write a board file. The `.bak` backup is left as it is.
+fn write_board_file(path: &str, board: &crud::Board) -> Result<(), KanbanError> {
+    crud::update_board(path, board)
+}
+
+// Copy a board file to its `.bak` backup, if there is a file yet
+fn refresh_backup(path: &str) -> Result<(), KanbanError> {
+    if Path::new(path).exists() {
+        fs::copy(path, backup_path(path))?;
+    }
+    Ok(())
+}
+
+/// Summary of a `save_all`, e.g. "Saved 2 boards"
+pub fn save_all_summary(saved: usize, failed: &[String]) -> String {
+    let noun = |count: usize| if count == 1 { "board" } else { "boards" };
+    let mut summary = format!("Saved {} {}", saved, noun(saved));
+    if !failed.is_empty() {
+        summary.push_str(&format!(
+            "; {} {} still unsaved ({})",
+            failed.len(),
+            noun(failed.len()),
+            failed.join(", ")
+        ));
+    }
+    summary
+}
 
 /// Helper functions to convert between frontend and backend models
 impl App {
     /// Initialize the app with KANBAN_DIR environment check
-    pub fn initialize_storage(&mut self) -> Result<(), io::Error> {
-        // Check for KANBAN_DIR environment variable
-        let kanban_dir = match std::env::var("KANBAN_DIR") {
-            Ok(dir) => dir,
-            Err(_) => {
-                return Err(io::Error::new(
-                    io::ErrorKind::NotFound,
-                    "KANBAN_DIR environment variable not set",
-                ));
-            }
-        };
-
-        // Create directory if it doesn't exist
-        let dir_path = Path::new(&kanban_dir);
-        if !dir_path.exists() {
-            fs::create_dir_all(dir_path)?;
-        }
+    pub fn initialize_storage(&mut self) -> Result<(), KanbanError> {
+        // Check for KANBAN_DIR, creating the directory if it doesn't exist
+        let kanban_dir = kanban_dir()?;
+        let dir_path = kanban_dir.as_path();
 
         // Create filename from board title
         let file_name = format!("{}.txt", self.title.replace(" ", "_").to_lowercase());
@@ -44,24 +95,158 @@ impl App {
     }
 
     /// Load board from file
-    pub fn load_board(&mut self) -> Result<(), io::Error> {
+    pub fn load_board(&mut self) -> Result<(), KanbanError> {
         if let Some(path) = &self.file_path {
             let backend_board = crud::read_board(path)?;
-            self.update_from_backend_board(backend_board);
+            self.file_modified = file_mtime(path);
+            // Changes that couldn't be saved when the board was left win
+            // over what is on disk, and still need saving
+            let unsaved = self
+                .unsaved_boards
+                .iter()
+                .position(|unsaved| &unsaved.path == path)
+                .map(|idx| self.unsaved_boards.remove(idx));
+            self.dirty = unsaved.is_some();
+            self.unsaved_changes = ChangeSummary::default();
+            self.update_from_backend_board(unsaved.map_or(backend_board, |unsaved| unsaved.board));
+            self.reset_history();
+            self.task_text_cache.clear();
+            self.load_view_state();
+            self.read_only = false;
+            // Offer to merge same-named columns, however the board was opened
+            if let Some((first, second)) = self.find_duplicate_columns() {
+                self.input_mode = InputMode::ConfirmMergeColumns(first, second);
+            }
             Ok(())
         } else {
-            Err(io::Error::new(io::ErrorKind::NotFound, "No file path set"))
+            Err(KanbanError::NoFilePath)
         }
     }
 
-    /// Save board to file
-    pub fn save_board(&self) -> Result<(), io::Error> {
+    /// Save board to file. Autosaves come through here after every edit, so
+    /// the `.bak` backup is left alone; see `save_board_with_backup`.
+    pub fn save_board(&mut self) -> Result<(), KanbanError> {
+        if self.read_only {
+            self.status_message = Some(READ_ONLY_MESSAGE.to_string());
+            return Err(KanbanError::ReadOnly);
+        }
         if let Some(path) = &self.file_path {
-            let backend_board = self.to_backend_board();
-            crud::update_board(path, &backend_board)?;
+            write_board_file(path, &self.to_backend_board())?;
+            self.format_version = crud::FORMAT_VERSION;
+            self.file_modified = file_mtime(path);
+            self.dirty = false;
+            self.unsaved_changes = ChangeSummary::default();
+            // The board file now holds every journaled edit
+            self.clear_journal();
             Ok(())
         } else {
-            Err(io::Error::new(io::ErrorKind::NotFound, "No file path set"))
+            // Most callers ignore the result, so say so where the user can
+            // see it rather than dropping the change without a trace
+            self.status_message = Some(UNSAVED_NO_FILE_MESSAGE.to_string());
+            Err(KanbanError::NoFilePath)
+        }
+    }
+
+    /// Save board to file for an explicit save, first keeping the version
+    /// on disk as the `.bak` backup
+    pub fn save_board_with_backup(&mut self) -> Result<(), KanbanError> {
+        if let Some(path) = &self.file_path
+            && !self.read_only
+        {
+            refresh_backup(path)?;
+        }
+        self.save_board()
+    }
+
+    /// Before switching boards, hold on to the open board if it has changes
+    /// that still can't be saved, so they aren't lost with the switch
+    pub fn keep_unsaved_board(&mut self) {
+        if !self.dirty || self.read_only {
+            return;
+        }
+        let Some(path) = self.file_path.clone() else {
+            return;
+        };
+        if self.save_board().is_err() {
+            self.unsaved_boards.retain(|unsaved| unsaved.path != path);
+            self.unsaved_boards.push(UnsavedBoard {
+                title: self.title.clone(),
+                path,
+                board: self.to_backend_board(),
+            });
+        }
+    }
+
+    /// Write every board with unsaved changes: the open one and any kept by
+    /// `keep_unsaved_board`. Boards that still fail stay unsaved.
+    pub fn save_all(&mut self) {
+        let mut saved = 0;
+        let mut failed = Vec::new();
+        if self.dirty && self.file_path.is_some() && !self.read_only {
+            match self.save_board_with_backup() {
+                Ok(()) => saved += 1,
+                Err(_) => failed.push(self.title.clone()),
+            }
+        }
+        for unsaved in std::mem::take(&mut self.unsaved_boards) {
+            let result = refresh_backup(&unsaved.path)
+                .and_then(|()| write_board_file(&unsaved.path, &unsaved.board));
+            match result {
+                Ok(()) => saved += 1,
+                Err(_) => {
+                    failed.push(unsaved.title.clone());
+                    self.unsaved_boards.push(unsaved);
+                }
+            }
+        }
+        self.status_message = Some(save_all_summary(saved, &failed));
+    }
+
+    /// Replace the board with the version saved before the last explicit
+    /// save. The backup outlives restarts and reloads, which leave it alone.
+    pub fn revert_to_backup(&mut self) -> Result<(), KanbanError> {
+        if let Some(path) = &self.file_path {
+            let backend_board = crud::read_board(&backup_path(path))?;
+            self.record_undo();
+            self.update_from_backend_board(backend_board);
+            // Saving rotates the reverted-from version into the backup
+            self.save_board_with_backup()
+        } else {
+            Err(KanbanError::NoFilePath)
+        }
+    }
+
+    /// Reload the board if another program changed its file. With unsaved
+    /// changes in memory, ask before replacing them.
+    pub fn check_external_change(&mut self) {
+        if !self.config.live_reload || self.input_mode != InputMode::Normal {
+            return;
+        }
+        let Some(path) = &self.file_path else {
+            return;
+        };
+
+        let on_disk = file_mtime(path);
+        if !file_changed_externally(self.file_modified, on_disk) {
+            return;
+        }
+        // Only react once per external change
+        self.file_modified = on_disk;
+
+        if self.dirty {
+            self.input_mode = InputMode::ConfirmReload;
+        } else {
+            self.reload_from_disk();
+        }
+    }
+
+    /// Replace the in-memory board with the file's current contents
+    pub fn reload_from_disk(&mut self) {
+        match self.load_board() {
+            Ok(()) => {
+                self.status_message = Some("Reloaded board after an external change".to_string())
+            }
+            Err(e) => self.status_message = Some(format!("Error reloading board: {}", e)),
         }
     }
 
@@ -69,37 +254,130 @@ impl App {
         let mut board = crud::Board::new(
             &self.title,
             &Local::now().format("%Y-%m-%d").to_string(),
-            "TUI Kanban Board",
+            &self.description,
         );
+        board.priority_formula = self.config.priority_formula;
 
         // Add all columns and their tasks
         for column in &self.columns {
             board.add_column(&column.title);
+            if let Some(backend_column) = board.columns.last_mut() {
+                backend_column.wip_limit = column.wip_limit;
+            }
 
             for task in &column.tasks {
-                // Use the priority value if available, otherwise use default values
-                let priority_impact = task.priority.unwrap_or(5);
-
-                let backend_task = crud::Task {
-                    id: task_to_id(task),
-                    title: task.title.clone(),
-                    priority: Some(crud::Priority {
-                        impact: priority_impact, // Set impact directly from the task priority
-                        urgency: 5,              // Default urgency
-                        effort: 3,               // Default effort
-                    }),
-                    tags: Vec::new(),
-                    created: Some(Local::now().format("%Y-%m-%d").to_string()),
-                };
-
-                let _ = board.add_task(&column.title, backend_task);
+                let _ = board.add_task(&column.title, to_backend_task(task));
             }
         }
 
         board
     }
 
-    /// Update frontend App from backend Board
+    /// Show a board that has no file behind it, such as one piped in on
+    /// stdin. Edits stay in memory and are never saved.
+    pub fn open_read_only(&mut self, board: crud::Board) {
+        self.title = board.name.clone();
+        self.file_path = None;
+        self.read_only = true;
+        self.update_from_backend_board(board);
+        self.reset_history();
+        self.task_text_cache.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Move the selected task to a column of another board file, which is
+    /// loaded and saved on its own; the current board stays in view. The
+    /// column is matched case-insensitively. Undo only restores the task
+    /// here, it doesn't take it back out of the other board.
+    pub fn send_task_to_board(
+        &mut self,
+        target_path: &Path,
+        column_name: &str,
+    ) -> Result<(), KanbanError> {
+        let task = self
+            .selected_task()
+            .cloned()
+            .ok_or_else(|| KanbanError::NotFound("Selected task".to_string()))?;
+        let target = target_path.to_string_lossy().to_string();
+        if self.file_path.as_deref() == Some(target.as_str()) {
+            return Err(KanbanError::Collision(format!(
+                "Task '{}' on board '{}'",
+                task.title, self.title
+            )));
+        }
+
+        let mut board = crud::read_board(&target)?;
+        let column_title = board
+            .columns
+            .iter()
+            .map(|column| column.name.clone())
+            .find(|name| name.eq_ignore_ascii_case(column_name))
+            .ok_or_else(|| {
+                KanbanError::NotFound(format!("Column '{}' on that board", column_name))
+            })?;
+
+        let mut backend_task = to_backend_task(&task);
+        let into_done = self
+            .config
+            .done_columns
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(&column_title));
+        if !into_done {
+            backend_task.completed = None;
+        } else if backend_task.completed.is_none() {
+            backend_task.completed = Some(Local::now().format("%Y-%m-%d %H:%M").to_string());
+        }
+        board.add_task(&column_title, backend_task)?;
+        crud::update_board(&target, &board)?;
+
+        // Only drop the task here once the other board has it
+        let board_name = board.name.clone();
+        self.record_undo();
+        let column = &mut self.columns[self.active_column];
+        if let Some(task_idx) = column.selected_task {
+            column.tasks.remove(task_idx);
+            column.selected_task = match column.tasks.len() {
+                0 => None,
+                len => Some(task_idx.min(len - 1)),
+            };
+        }
+        let _ = self.save_board();
+        self.log_change(&format!(
+            "Sent task '{}' to '{}' on board '{}'",
+            task.title, column_title, board_name
+        ));
+        Ok(())
+    }
+
+    /// Send the selected task to the target typed as `board: column`
+    pub fn send_task_by_name(&mut self) {
+        let Some((board_name, column_name)) = self.input_text.rsplit_once(':') else {
+            self.status_message = Some("Type the target as 'board: column'".to_string());
+            return;
+        };
+        let (board_name, column_name) = (
+            board_name.trim().to_string(),
+            column_name.trim().to_string(),
+        );
+        let result = kanban_dir().and_then(|dir| {
+            let target_path = board_file_path(&dir, &board_name)?;
+            self.send_task_to_board(&target_path, &column_name)
+        });
+
+        match result {
+            Ok(()) => {
+                self.status_message = Some(format!(
+                    "Sent task to '{}' on board '{}'",
+                    column_name, board_name
+                ));
+                self.input_mode = InputMode::Normal;
+                self.input_text.clear();
+            }
+            // Keep the prompt open so the target can be corrected
+            Err(e) => self.status_message = Some(format!("Error sending task: {}", e)),
+        }
+    }
+
     /// Update frontend App from backend Board
     fn update_from_backend_board(&mut self, board: crud::Board) {
         // Store original active column name to restore selection
@@ -107,6 +385,11 @@ impl App {
             .columns
             .get(self.active_column)
             .map(|col| col.title.clone());
+        // and the selected task's ID, so a reload doesn't jump back to the top
+        let selected_id = self.selected_task().map(|task| task.id);
+
+        self.description = board.description.clone();
+        self.format_version = board.format_version;
 
         // Clear existing columns
         self.columns.clear();
@@ -117,29 +400,20 @@ impl App {
                 title: backend_column.name.clone(),
                 tasks: Vec::new(),
                 selected_task: None,
+                wip_limit: backend_column.wip_limit,
             };
 
             // Add tasks to this column
             for backend_task in &backend_column.tasks {
-                // Extract priority from the backend task
-                let priority = if let Some(ref prio) = backend_task.priority {
-                    Some(prio.impact) // Use impact as the frontend priority value
-                } else {
-                    None
-                };
-
-                let task = Task {
-                    title: backend_task.title.clone(),
-                    description: None,
-                    priority,
-                };
-
-                column.tasks.push(task);
+                column.tasks.push(from_backend_task(backend_task));
             }
 
             self.columns.push(column);
         }
 
+        // Older files may lack IDs or repeat them
+        self.assign_missing_task_ids();
+
         // Restore active column if possible
         if let Some(name) = active_column_name {
             for (i, column) in self.columns.iter().enumerate() {
@@ -160,7 +434,11 @@ impl App {
         // Set selection only for the active column
         for (i, column) in self.columns.iter_mut().enumerate() {
             if i == self.active_column && !column.tasks.is_empty() {
-                column.selected_task = Some(0);
+                let previous = column
+                    .tasks
+                    .iter()
+                    .position(|task| Some(task.id) == selected_id);
+                column.selected_task = Some(previous.unwrap_or(0));
             } else {
                 column.selected_task = None;
             }
@@ -168,12 +446,399 @@ impl App {
     }
 }
 
-// Helper function to generate a unique ID for a task based on its content
-fn task_to_id(task: &Task) -> usize {
-    // Simple hash of the task title
-    let mut id: usize = 0;
-    for b in task.title.bytes() {
-        id = id.wrapping_mul(31).wrapping_add(b as usize);
+// Convert a task to the storage layer's form, stamping a creation date
+pub fn to_backend_task(task: &Task) -> crud::Task {
+    crud::Task {
+        id: task.id,
+        title: task.title.clone(),
+        priority: task.priority.clone(),
+        tags: task.tags.clone(),
+        created: task
+            .created
+            .clone()
+            .or_else(|| Some(Local::now().format("%Y-%m-%d").to_string())),
+        completed: task.completed_at.clone(),
+        due: task.due.clone(),
+        focus_minutes: task.focus_minutes,
+    }
+}
+
+// Convert a task read by the storage layer back to the app's form
+pub fn from_backend_task(backend_task: &crud::Task) -> Task {
+    Task {
+        id: backend_task.id,
+        title: backend_task.title.clone(),
+        description: None,
+        priority: backend_task.priority.clone(),
+        completed_at: backend_task.completed.clone(),
+        tags: backend_task.tags.clone(),
+        due: backend_task.due.clone(),
+        created: backend_task.created.clone(),
+        focus_minutes: backend_task.focus_minutes,
+    }
+}
+
+// Last modification time of a file, if it can be read
+fn file_mtime(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+// Decide whether the file was modified since we last loaded or saved it.
+// Nothing is reloaded before the first load, or when the file is gone.
+pub fn file_changed_externally(known: Option<SystemTime>, on_disk: Option<SystemTime>) -> bool {
+    match (known, on_disk) {
+        (Some(known), Some(on_disk)) => known != on_disk,
+        _ => false,
+    }
+}
+
+// Path of the backup copy kept next to a board file
+pub fn backup_path(path: &str) -> String {
+    format!("{}.bak", path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_file_changed_externally() {
+        let saved = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_000);
+        let edited = saved + std::time::Duration::from_secs(5);
+
+        assert!(!file_changed_externally(Some(saved), Some(saved)));
+        assert!(file_changed_externally(Some(saved), Some(edited)));
+        // Never loaded, or the file disappeared
+        assert!(!file_changed_externally(None, Some(edited)));
+        assert!(!file_changed_externally(Some(saved), None));
+    }
+
+    #[test]
+    fn test_board_dir_that_is_a_file_is_reported() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_not_a_dir.txt");
+        fs::write(&temp_path, "not a directory").unwrap();
+
+        match resolve_board_dir(&temp_path) {
+            Err(KanbanError::NotADirectory(path)) => {
+                assert_eq!(path, temp_path.display().to_string())
+            }
+            other => panic!("expected NotADirectory, got {:?}", other),
+        }
+        fs::remove_file(&temp_path).unwrap();
+
+        // A missing directory is created instead
+        let mut dir_path = env::temp_dir();
+        dir_path.push("kantui_new_board_dir");
+        let _ = fs::remove_dir_all(&dir_path);
+        assert_eq!(resolve_board_dir(&dir_path).unwrap(), dir_path);
+        assert!(dir_path.is_dir());
+        fs::remove_dir(&dir_path).unwrap();
+    }
+
+    #[test]
+    fn test_save_all_writes_every_dirty_board() {
+        let path_for = |name: &str| {
+            let mut temp_path = env::temp_dir();
+            temp_path.push(name);
+            temp_path.to_str().unwrap().to_string()
+        };
+        let open_path = path_for("kantui_save_all_open.txt");
+        let left_path = path_for("kantui_save_all_left.txt");
+        let _ = fs::remove_file(&left_path);
+
+        // A board left with changes that couldn't be saved at the time
+        let mut left = App::new("Left Board");
+        left.columns[0].tasks[0].title = "Edited while away".to_string();
+        let mut app = App::new("Open Board");
+        app.unsaved_boards.push(UnsavedBoard {
+            title: "Left Board".to_string(),
+            path: left_path.clone(),
+            board: left.to_backend_board(),
+        });
+        app.file_path = Some(open_path.clone());
+        app.record_undo();
+        assert!(app.dirty);
+
+        app.save_all();
+        assert_eq!(app.status_message.as_deref(), Some("Saved 2 boards"));
+        assert!(!app.dirty);
+        assert!(app.unsaved_boards.is_empty());
+        assert_eq!(crud::read_board(&open_path).unwrap().name, "Open Board");
+        let on_disk = crud::read_board(&left_path).unwrap();
+        assert_eq!(on_disk.columns[0].tasks[0].title, "Edited while away");
+
+        // Nothing left to do the second time round
+        app.save_all();
+        assert_eq!(app.status_message.as_deref(), Some("Saved 0 boards"));
+
+        for path in [&open_path, &left_path] {
+            let _ = fs::remove_file(backup_path(path));
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_save_all_summary() {
+        assert_eq!(save_all_summary(1, &[]), "Saved 1 board");
+        assert_eq!(
+            save_all_summary(0, &["Work".to_string()]),
+            "Saved 0 boards; 1 board still unsaved (Work)"
+        );
+    }
+
+    #[test]
+    fn test_revert_to_backup_restores_previous_save() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_revert_board.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        let _ = fs::remove_file(backup_path(&file_path));
+
+        let mut app = App::new("Revert Board");
+        app.file_path = Some(file_path.clone());
+        app.save_board().unwrap();
+
+        // An explicit save moves the first version into the backup
+        app.columns[0].title = "Renamed".to_string();
+        app.columns[0].tasks.clear();
+        app.save_board_with_backup().unwrap();
+        assert!(Path::new(&backup_path(&file_path)).exists());
+
+        app.revert_to_backup().unwrap();
+        assert_eq!(app.columns[0].title, "To Do");
+        assert_eq!(app.columns[0].tasks.len(), 2);
+
+        // The reverted state is what's on disk now
+        let on_disk = crud::read_board(&file_path).unwrap();
+        assert_eq!(on_disk.columns[0].name, "To Do");
+
+        fs::remove_file(&file_path).unwrap();
+        fs::remove_file(backup_path(&file_path)).unwrap();
+    }
+
+    #[test]
+    fn test_autosaves_and_reopening_keep_the_backup() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_autosave_backup.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        let _ = fs::remove_file(backup_path(&file_path));
+
+        let mut app = App::new("Backup Board");
+        app.file_path = Some(file_path.clone());
+        app.save_board().unwrap();
+        // Only an explicit save makes a backup
+        assert!(!Path::new(&backup_path(&file_path)).exists());
+        app.columns[0].title = "Planned".to_string();
+        app.save_board_with_backup().unwrap();
+
+        // Edit after edit is autosaved without touching the backup
+        for title in ["One", "Two", "Three"] {
+            app.columns[0].title = title.to_string();
+            app.save_board().unwrap();
+        }
+
+        // Nor does opening the board again, as after a restart
+        let mut reopened = App::new("Backup Board");
+        reopened.file_path = Some(file_path.clone());
+        reopened.load_board().unwrap();
+        assert_eq!(reopened.columns[0].title, "Three");
+        reopened.load_board().unwrap();
+
+        reopened.revert_to_backup().unwrap();
+        assert_eq!(reopened.columns[0].title, "To Do");
+
+        fs::remove_file(&file_path).unwrap();
+        fs::remove_file(backup_path(&file_path)).unwrap();
+    }
+
+    #[test]
+    fn test_save_keeps_board_description() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_description_board.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+
+        let mut board = crud::Board::new("Described", "2025-03-24", "Spring launch plan");
+        board.add_column("To Do");
+        crud::update_board(&file_path, &board).unwrap();
+
+        let mut app = App::new("Described");
+        app.file_path = Some(file_path.clone());
+        app.load_board().unwrap();
+        assert_eq!(app.description, "Spring launch plan");
+
+        app.add_task("Book venue");
+        let on_disk = crud::read_board(&file_path).unwrap();
+        assert_eq!(on_disk.description, "Spring launch plan");
+
+        let _ = fs::remove_file(backup_path(&file_path));
+        fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_edit_without_file_path_is_reported() {
+        let mut app = App::new("Unsaved Board");
+        app.file_path = None;
+
+        app.add_task("Lost?");
+
+        assert_eq!(app.status_message.as_deref(), Some(UNSAVED_NO_FILE_MESSAGE));
+        assert!(app.dirty);
+    }
+
+    #[test]
+    fn test_moved_task_keeps_all_fields_through_save_and_load() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_move_round_trip.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+
+        let task = Task {
+            id: 3,
+            title: "Ship release".to_string(),
+            description: None, // Not part of the board file format
+            priority: Some(crud::Priority {
+                impact: 9,
+                urgency: 7,
+                effort: 2,
+            }),
+            completed_at: None,
+            tags: vec!["release".to_string(), "ops".to_string()],
+            due: Some("2025-04-01".to_string()),
+            created: Some("2025-01-02".to_string()),
+            focus_minutes: 50,
+        };
+        let mut app = App::new("Round Trip Board");
+        app.file_path = Some(file_path.clone());
+        app.columns = vec![
+            Column {
+                title: "To Do".to_string(),
+                tasks: vec![task.clone()],
+                selected_task: Some(0),
+                wip_limit: None,
+            },
+            Column {
+                title: "Doing".to_string(),
+                tasks: Vec::new(),
+                selected_task: None,
+                wip_limit: None,
+            },
+        ];
+        app.active_column = 0;
+
+        app.move_task_to_column(1);
+
+        let mut reloaded = App::new("Round Trip Board");
+        reloaded.file_path = Some(file_path.clone());
+        reloaded.load_board().unwrap();
+        assert!(reloaded.columns[0].tasks.is_empty());
+        assert_eq!(reloaded.columns[1].tasks, vec![task]);
+
+        fs::remove_file(&file_path).unwrap();
+        let _ = fs::remove_file(backup_path(&file_path));
+    }
+
+    #[test]
+    fn test_send_task_to_other_board() {
+        let dir = env::temp_dir();
+        let here = dir
+            .join("kantui_send_here.txt")
+            .to_string_lossy()
+            .to_string();
+        let there = dir.join("kantui_send_there.txt");
+
+        let mut other = crud::Board::new("There", "2025-03-24", "TUI Kanban Board");
+        other.add_column("Inbox");
+        crud::update_board(there.to_str().unwrap(), &other).unwrap();
+
+        let mut app = App::new("Here");
+        app.file_path = Some(here.clone());
+        app.columns.truncate(1);
+        app.active_column = 0;
+        let task_count = app.columns[0].tasks.len();
+        app.columns[0].selected_task = Some(0);
+        let title = app.columns[0].tasks[0].title.clone();
+
+        // Missing columns and boards leave both boards alone
+        assert!(matches!(
+            app.send_task_to_board(&there, "Nowhere"),
+            Err(KanbanError::NotFound(_))
+        ));
+        assert!(matches!(
+            app.send_task_to_board(&dir.join("kantui_no_such_board.txt"), "Inbox"),
+            Err(KanbanError::NotFound(_))
+        ));
+        assert_eq!(app.columns[0].tasks.len(), task_count);
+
+        app.send_task_to_board(&there, "inbox").unwrap();
+
+        assert_eq!(app.columns[0].tasks.len(), task_count - 1);
+        let saved_here = crud::read_board(&here).unwrap();
+        assert!(saved_here.columns[0].tasks.iter().all(|t| t.title != title));
+        let saved_there = crud::read_board(there.to_str().unwrap()).unwrap();
+        assert_eq!(saved_there.columns[0].tasks.len(), 1);
+        assert_eq!(saved_there.columns[0].tasks[0].title, title);
+
+        fs::remove_file(&here).unwrap();
+        fs::remove_file(&there).unwrap();
+    }
+
+    #[test]
+    fn test_reload_keeps_selected_task() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_reload_selection.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+
+        let mut app = App::new("Reload Board");
+        app.file_path = Some(file_path.clone());
+        app.columns.truncate(1);
+        app.active_column = 0;
+        for title in ["One", "Two", "Three"] {
+            app.add_task(title);
+        }
+        app.columns[0].selected_task = Some(app.columns[0].tasks.len() - 2);
+        let selected = app.selected_task().map(|task| task.title.clone());
+
+        app.load_board().unwrap();
+
+        assert_eq!(app.selected_task().map(|task| task.title.clone()), selected);
+
+        fs::remove_file(&file_path).unwrap();
+        let _ = fs::remove_file(backup_path(&file_path));
+    }
+
+    #[test]
+    fn test_reload_offers_to_merge_duplicate_columns() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_reload_duplicates.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+        fs::write(
+            &temp_path,
+            "# TUI Kanban Board: Dupes\n\n== To Do ==\n== Doing ==\n== To Do ==\n",
+        )
+        .unwrap();
+
+        let mut app = App::new("Dupes");
+        app.file_path = Some(file_path.clone());
+        app.input_mode = InputMode::Normal;
+        app.reload_from_disk();
+        assert!(app.input_mode == InputMode::ConfirmMergeColumns(0, 2));
+
+        fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_read_only_board_is_never_saved() {
+        let text = "# TUI Kanban Board: Piped\n\n== To Do ==\n* [ID:1] Look around\n";
+        let board = crud::Board::from_reader(text.as_bytes()).unwrap();
+
+        let mut app = App::new("Kanban Board");
+        app.open_read_only(board);
+        assert_eq!(app.title, "Piped");
+        assert_eq!(app.columns[0].tasks[0].title, "Look around");
+        assert!(app.input_mode == InputMode::Normal);
+
+        app.add_task("Try to edit");
+        assert!(app.save_board().is_err());
+        assert_eq!(app.status_message.as_deref(), Some(READ_ONLY_MESSAGE));
     }
-    id
 }
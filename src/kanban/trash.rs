@@ -0,0 +1,464 @@
+use crate::kanban::models::{App, Column, InputMode, Task};
+use crate::kanban::storage::{from_backend_task, to_backend_task};
+use crate::ops::crud;
+use crate::ops::error::KanbanError;
+use chrono::{Local, NaiveDateTime};
+use std::path::{Path, PathBuf};
+
+// Format of the deletion time stored with each trash entry
+const TRASH_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The trash that sits next to a board file, e.g. `work.txt` -> `work.trash`
+pub fn trash_path(board_path: &str) -> PathBuf {
+    Path::new(board_path).with_extension("trash")
+}
+
+/// What a trash entry holds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrashKind {
+    /// Tasks deleted from a column that was kept
+    Tasks,
+    /// A whole deleted column with its tasks
+    Column,
+}
+
+/// One deletion kept in the trash.
+///
+/// The trash file uses the board file format, with one column section per
+/// entry headed `== <deleted> | tasks | <column> ==` or
+/// `== <deleted> | column | <column> ==`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrashEntry {
+    pub kind: TrashKind,
+    /// When it was deleted, as `YYYY-MM-DD HH:MM:SS`
+    pub deleted: String,
+    /// The column the tasks came from, or the deleted column's title
+    pub column: String,
+    /// WIP limit of a deleted column
+    pub wip_limit: Option<usize>,
+    pub tasks: Vec<Task>,
+}
+
+impl TrashEntry {
+    /// One line describing the entry for the trash viewer
+    pub fn label(&self) -> String {
+        let what = match (self.kind, self.tasks.as_slice()) {
+            (TrashKind::Tasks, [task]) => format!("task '{}' from '{}'", task.title, self.column),
+            (TrashKind::Tasks, tasks) => format!("{} tasks from '{}'", tasks.len(), self.column),
+            (TrashKind::Column, tasks) => {
+                format!("column '{}' ({} tasks)", self.column, tasks.len())
+            }
+        };
+        format!("{}  {}", self.deleted, what)
+    }
+
+    // Whether the entry is older than the retention window; 0 days keeps
+    // everything
+    fn is_expired(&self, now: NaiveDateTime, retention_days: u32) -> bool {
+        if retention_days == 0 {
+            return false;
+        }
+        NaiveDateTime::parse_from_str(&self.deleted, TRASH_TIME_FORMAT)
+            .is_ok_and(|deleted| (now - deleted).num_days() >= retention_days as i64)
+    }
+
+    fn to_backend_column(&self) -> crud::Column {
+        let kind = match self.kind {
+            TrashKind::Tasks => "tasks",
+            TrashKind::Column => "column",
+        };
+        crud::Column {
+            name: format!("{} | {} | {}", self.deleted, kind, self.column),
+            tasks: self.tasks.iter().map(to_backend_task).collect(),
+            wip_limit: self.wip_limit,
+        }
+    }
+
+    fn from_backend_column(column: &crud::Column) -> Option<TrashEntry> {
+        let mut parts = column.name.splitn(3, " | ");
+        let deleted = parts.next()?.to_string();
+        let kind = match parts.next()? {
+            "tasks" => TrashKind::Tasks,
+            "column" => TrashKind::Column,
+            _ => return None,
+        };
+        Some(TrashEntry {
+            kind,
+            deleted,
+            column: parts.next()?.to_string(),
+            wip_limit: column.wip_limit,
+            tasks: column.tasks.iter().map(from_backend_task).collect(),
+        })
+    }
+}
+
+/// Read the entries of a trash file, newest first. A missing file is an
+/// empty trash; sections that aren't trash entries are skipped.
+pub fn read_trash(path: &Path) -> Result<Vec<TrashEntry>, KanbanError> {
+    match crud::read_board(&path.to_string_lossy()) {
+        Ok(board) => Ok(board
+            .columns
+            .iter()
+            .filter_map(TrashEntry::from_backend_column)
+            .collect()),
+        Err(KanbanError::NotFound(_)) => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Write the entries to a trash file, removing the file once it's empty
+pub fn write_trash(path: &Path, entries: &[TrashEntry]) -> Result<(), KanbanError> {
+    if entries.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+    let mut board = crud::Board::new("Trash", &Local::now().format("%Y-%m-%d").to_string(), "");
+    board.columns = entries.iter().map(TrashEntry::to_backend_column).collect();
+    crud::update_board(&path.to_string_lossy(), &board)
+}
+
+/// Add an entry to the front of a trash file, dropping entries that have
+/// outlived the retention window
+pub fn append_to_trash(
+    path: &Path,
+    entry: TrashEntry,
+    now: NaiveDateTime,
+    retention_days: u32,
+) -> Result<(), KanbanError> {
+    let mut entries = read_trash(path)?;
+    entries.insert(0, entry);
+    entries.retain(|entry| !entry.is_expired(now, retention_days));
+    write_trash(path, &entries)
+}
+
+impl App {
+    /// Keep deleted tasks or a deleted column in the board's trash. Best
+    /// effort, like the changelog: a failed write never blocks the delete.
+    pub fn move_to_trash(
+        &mut self,
+        kind: TrashKind,
+        column: &str,
+        wip_limit: Option<usize>,
+        tasks: Vec<Task>,
+    ) {
+        let Some(path) = self.file_path.as_deref().map(trash_path) else {
+            return;
+        };
+        if self.read_only || (kind == TrashKind::Tasks && tasks.is_empty()) {
+            return;
+        }
+        let now = Local::now().naive_local();
+        let entry = TrashEntry {
+            kind,
+            deleted: now.format(TRASH_TIME_FORMAT).to_string(),
+            column: column.to_string(),
+            wip_limit,
+            tasks,
+        };
+        let _ = append_to_trash(&path, entry, now, self.config.trash_retention_days);
+    }
+
+    /// Open the trash viewer with the board's trash, newest first
+    pub fn open_trash(&mut self) {
+        let Some(path) = self.file_path.as_deref().map(trash_path) else {
+            self.status_message = Some("This board has no trash".to_string());
+            return;
+        };
+        let now = Local::now().naive_local();
+        match read_trash(&path) {
+            Ok(mut entries) => {
+                entries.retain(|entry| !entry.is_expired(now, self.config.trash_retention_days));
+                self.trash = entries;
+                self.selected_trash_index = 0;
+                self.input_mode = InputMode::TrashViewer;
+            }
+            Err(e) => self.status_message = Some(format!("Error reading trash: {}", e)),
+        }
+    }
+
+    pub fn select_prev_trash_entry(&mut self) {
+        self.selected_trash_index = self.selected_trash_index.saturating_sub(1);
+    }
+
+    pub fn select_next_trash_entry(&mut self) {
+        if self.selected_trash_index + 1 < self.trash.len() {
+            self.selected_trash_index += 1;
+        }
+    }
+
+    /// Put back an entry that was restored from the trash, when the restore
+    /// is undone. Best effort, like `move_to_trash`.
+    pub fn return_to_trash(&mut self, entry: TrashEntry) {
+        let Some(path) = self.file_path.as_deref().map(trash_path) else {
+            return;
+        };
+        let now = Local::now().naive_local();
+        let _ = append_to_trash(&path, entry, now, self.config.trash_retention_days);
+    }
+
+    /// Restore the entry selected in the trash viewer
+    pub fn restore_selected_trash_entry(&mut self) {
+        match self.restore_from_trash(self.selected_trash_index) {
+            Ok(description) => self.status_message = Some(description),
+            Err(e) => self.status_message = Some(format!("Error restoring: {}", e)),
+        }
+        if self.trash.is_empty() {
+            self.input_mode = InputMode::Normal;
+        } else {
+            self.selected_trash_index = self.selected_trash_index.min(self.trash.len() - 1);
+        }
+    }
+
+    /// Put a trash entry back on the board and take it out of the trash.
+    /// A column goes back at the end of the board. Tasks go back to the
+    /// column they came from, or to the active column if that one is gone.
+    /// Tasks whose IDs were reused in the meantime get fresh ones.
+    pub fn restore_from_trash(&mut self, index: usize) -> Result<String, KanbanError> {
+        let entry = self
+            .trash
+            .get(index)
+            .cloned()
+            .ok_or_else(|| KanbanError::NotFound("Trash entry".to_string()))?;
+        let target = match entry.kind {
+            TrashKind::Column => None,
+            TrashKind::Tasks => Some(
+                self.columns
+                    .iter()
+                    .position(|column| column.title == entry.column)
+                    .or((self.active_column < self.columns.len()).then_some(self.active_column))
+                    .ok_or_else(|| KanbanError::NotFound("Column to restore into".to_string()))?,
+            ),
+        };
+
+        // Undoing the restore puts the entry back in the trash
+        self.record_undo();
+        if let Some(snapshot) = self.history.last_mut() {
+            snapshot.restored = Some(entry.clone());
+        }
+        let mut tasks = entry.tasks;
+        let mut next_id = self.next_task_id();
+        for task in &mut tasks {
+            if self.find_task_by_id(task.id).is_some() {
+                task.id = next_id;
+                next_id += 1;
+            }
+        }
+        let description = match target {
+            Some(column_idx) => {
+                let column = &mut self.columns[column_idx];
+                let description = format!(
+                    "Restored {} task(s) from the trash to '{}'",
+                    tasks.len(),
+                    column.title
+                );
+                column.tasks.extend(tasks);
+                description
+            }
+            None => {
+                let description = format!("Restored column '{}' from the trash", entry.column);
+                self.columns.push(Column {
+                    title: entry.column,
+                    tasks,
+                    selected_task: None,
+                    wip_limit: entry.wip_limit,
+                });
+                description
+            }
+        };
+
+        self.trash.remove(index);
+        if let Some(path) = self.file_path.as_deref().map(trash_path) {
+            write_trash(&path, &self.trash)?;
+        }
+        let _ = self.save_board();
+        self.log_change(&description);
+        Ok(description)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::storage::backup_path;
+    use chrono::NaiveDate;
+    use std::env;
+    use std::fs;
+
+    fn task(id: usize, title: &str) -> Task {
+        Task {
+            id,
+            title: title.to_string(),
+            description: None,
+            priority: Some(Task::default_priority()),
+            completed_at: None,
+            tags: vec!["ops".to_string()],
+            due: Some("2025-04-01".to_string()),
+            created: Some("2025-03-01".to_string()),
+            focus_minutes: 25,
+        }
+    }
+
+    // 09:30 on the given day counted from March 1st 2025
+    fn at(day: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap()
+            + chrono::Duration::days(day - 1)
+    }
+
+    fn entry(
+        kind: TrashKind,
+        column: &str,
+        deleted: NaiveDateTime,
+        tasks: Vec<Task>,
+    ) -> TrashEntry {
+        TrashEntry {
+            kind,
+            deleted: deleted.format(TRASH_TIME_FORMAT).to_string(),
+            column: column.to_string(),
+            wip_limit: None,
+            tasks,
+        }
+    }
+
+    fn app_with_file(name: &str) -> (App, PathBuf) {
+        let mut board_path = env::temp_dir();
+        board_path.push(name);
+        let path = trash_path(board_path.to_str().unwrap());
+        let _ = fs::remove_file(&path);
+
+        let mut app = App::new("Trash Board");
+        app.file_path = Some(board_path.to_str().unwrap().to_string());
+        app.read_only = false;
+        (app, path)
+    }
+
+    #[test]
+    fn test_trash_append_round_trip() {
+        let mut path = env::temp_dir();
+        path.push("kantui_trash_append.trash");
+        let _ = fs::remove_file(&path);
+
+        let first = entry(TrashKind::Tasks, "To Do", at(1), vec![task(3, "Fix login")]);
+        let mut second = entry(
+            TrashKind::Column,
+            "Doing",
+            at(2),
+            vec![task(4, "Ship"), task(5, "Test")],
+        );
+        second.wip_limit = Some(3);
+        append_to_trash(&path, first.clone(), at(1), 30).unwrap();
+        append_to_trash(&path, second.clone(), at(2), 30).unwrap();
+
+        // Newest first, with every task field intact
+        assert_eq!(read_trash(&path).unwrap(), vec![second, first.clone()]);
+        assert_eq!(
+            first.label(),
+            "2025-03-01 09:30:00  task 'Fix login' from 'To Do'"
+        );
+
+        // Entries past the retention window are dropped on the next append
+        let third = entry(TrashKind::Tasks, "To Do", at(32), vec![task(6, "New")]);
+        append_to_trash(&path, third.clone(), at(32), 30).unwrap();
+        assert_eq!(read_trash(&path).unwrap(), vec![third]);
+
+        // An empty trash leaves no file behind
+        write_trash(&path, &[]).unwrap();
+        assert!(!path.exists());
+        assert!(read_trash(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_deleted_task_restores_to_its_column() {
+        let (mut app, path) = app_with_file("kantui_trash_task.txt");
+        app.columns.truncate(1);
+        app.columns[0].tasks = vec![task(1, "Keep"), task(2, "Oops")];
+        app.columns[0].selected_task = Some(1);
+        app.active_column = 0;
+
+        app.delete_current_task();
+        assert_eq!(app.columns[0].tasks.len(), 1);
+
+        app.open_trash();
+        assert!(app.input_mode == InputMode::TrashViewer);
+        assert_eq!(app.trash.len(), 1);
+        app.restore_selected_trash_entry();
+
+        let titles: Vec<&str> = app.columns[0]
+            .tasks
+            .iter()
+            .map(|t| t.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Keep", "Oops"]);
+        assert_eq!(app.columns[0].tasks[1], task(2, "Oops"));
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(!path.exists());
+
+        let board_path = app.file_path.clone().unwrap();
+        fs::remove_file(&board_path).unwrap();
+        let _ = fs::remove_file(backup_path(&board_path));
+    }
+
+    #[test]
+    fn test_undoing_a_restore_puts_it_back_in_the_trash() {
+        let (mut app, path) = app_with_file("kantui_trash_undo.txt");
+        app.columns.truncate(1);
+        app.columns[0].tasks = vec![task(1, "Keep"), task(2, "Oops")];
+        app.columns[0].selected_task = Some(1);
+        app.active_column = 0;
+        app.delete_current_task();
+
+        app.open_trash();
+        app.restore_selected_trash_entry();
+        assert_eq!(app.columns[0].tasks.len(), 2);
+        assert!(!path.exists());
+
+        // The task leaves the board but isn't lost: it's in the trash again
+        assert!(app.undo());
+        assert_eq!(app.columns[0].tasks.len(), 1);
+        let trash = read_trash(&path).unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].tasks, vec![task(2, "Oops")]);
+
+        app.open_trash();
+        app.restore_selected_trash_entry();
+        assert_eq!(app.columns[0].tasks[1], task(2, "Oops"));
+
+        let board_path = app.file_path.clone().unwrap();
+        fs::remove_file(&board_path).unwrap();
+        let _ = fs::remove_file(backup_path(&board_path));
+    }
+
+    #[test]
+    fn test_restore_column_and_tasks_of_removed_column() {
+        let (mut app, path) = app_with_file("kantui_trash_column.txt");
+        app.columns.truncate(1);
+        app.columns[0].title = "Inbox".to_string();
+        app.columns[0].tasks = vec![task(1, "Reused ID")];
+        app.active_column = 0;
+        app.trash = vec![
+            entry(TrashKind::Tasks, "Gone", at(2), vec![task(1, "Orphan")]),
+            entry(TrashKind::Column, "Doing", at(1), vec![task(7, "Ship")]),
+        ];
+
+        // The tasks' column no longer exists, so they go to the active one,
+        // with a fresh ID since 1 is taken
+        app.restore_from_trash(0).unwrap();
+        assert_eq!(app.columns[0].tasks[1].title, "Orphan");
+        assert_eq!(app.columns[0].tasks[1].id, 2);
+
+        app.restore_from_trash(0).unwrap();
+        assert_eq!(app.columns.last().unwrap().title, "Doing");
+        assert_eq!(app.columns.last().unwrap().tasks[0].id, 7);
+        assert!(app.trash.is_empty());
+        assert!(app.restore_from_trash(0).is_err());
+
+        assert!(!path.exists());
+        let board_path = app.file_path.clone().unwrap();
+        fs::remove_file(&board_path).unwrap();
+        let _ = fs::remove_file(backup_path(&board_path));
+    }
+}
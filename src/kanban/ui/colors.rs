@@ -0,0 +1,103 @@
+use crate::kanban::config::ColorMode;
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+
+/// The 16 ANSI colors with the RGB values xterm uses for them
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Whether the terminal advertises 24-bit color through `COLORTERM` or `TERM`
+pub fn supports_truecolor(colorterm: Option<&str>, term: Option<&str>) -> bool {
+    let advertises = |value: &str| {
+        let value = value.to_lowercase();
+        ["truecolor", "24bit", "direct"]
+            .iter()
+            .any(|marker| value.contains(marker))
+    };
+    colorterm.is_some_and(advertises) || term.is_some_and(advertises)
+}
+
+/// Whether to draw RGB colors as they are, per the config or else the
+/// environment
+pub fn use_truecolor(mode: ColorMode) -> bool {
+    match mode {
+        ColorMode::TrueColor => true,
+        ColorMode::Ansi16 => false,
+        ColorMode::Auto => supports_truecolor(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        ),
+    }
+}
+
+/// The ANSI color closest to an RGB color
+pub fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    let distance = |(ar, ag, ab): (u8, u8, u8)| {
+        let d = |x: u8, y: u8| (i32::from(x) - i32::from(y)).pow(2);
+        d(r, ar) + d(g, ag) + d(b, ab)
+    };
+    ANSI16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|&(color, _)| color)
+        .unwrap_or(Color::Reset)
+}
+
+/// Map an RGB color to its nearest ANSI color, leaving other colors alone
+pub fn to_ansi16(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => nearest_ansi16(r, g, b),
+        other => other,
+    }
+}
+
+/// Replace every RGB color in a drawn frame with its nearest ANSI color
+pub fn downgrade_buffer(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        cell.fg = to_ansi16(cell.fg);
+        cell.bg = to_ansi16(cell.bg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_ansi16() {
+        // The app and popup backgrounds
+        assert_eq!(nearest_ansi16(22, 22, 22), Color::Black);
+        assert_eq!(nearest_ansi16(38, 38, 38), Color::Black);
+        // The default selection background
+        assert_eq!(nearest_ansi16(255, 215, 95), Color::LightYellow);
+        assert_eq!(nearest_ansi16(200, 30, 30), Color::Red);
+        assert_eq!(nearest_ansi16(120, 120, 130), Color::DarkGray);
+        assert_eq!(nearest_ansi16(80, 80, 240), Color::LightBlue);
+        assert_eq!(to_ansi16(Color::Cyan), Color::Cyan);
+    }
+
+    #[test]
+    fn test_supports_truecolor() {
+        assert!(supports_truecolor(Some("truecolor"), Some("xterm")));
+        assert!(supports_truecolor(Some("24bit"), None));
+        assert!(supports_truecolor(None, Some("xterm-direct")));
+        assert!(!supports_truecolor(None, Some("xterm-256color")));
+        assert!(!supports_truecolor(None, None));
+    }
+}
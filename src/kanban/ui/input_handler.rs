@@ -1,216 +1,198 @@
-use crate::kanban::models::{App, InputMode};
+use crate::kanban::models::{App, InputMode, MAX_PRIORITY_VALUE, PriorityField};
+use crate::kanban::ui::popups::max_info_scroll;
 use ratatui::{
     Terminal,
     backend::CrosstermBackend,
-    crossterm::event::{self, Event, KeyCode, KeyModifiers},
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    layout::Rect,
 };
 use std::io;
+use std::time::{Duration, Instant};
+
+// How often the event loop wakes up to redraw without input
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Ctrl+C quits from any mode, taking the same path as 'q' so the
+/// terminal gets restored.
+fn is_quit_key(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+}
+
+/// Handle Ctrl+C: quit if nothing is unsaved, otherwise ask first like 'q'
+/// does. Pressed again at that prompt it quits. Returns true to quit now.
+fn handle_quit_key(app: &mut App) -> bool {
+    matches!(app.input_mode, InputMode::ConfirmQuit(_)) || app.request_quit()
+}
+
+/// Handle the full-keyboard editing keys in Normal mode: Delete asks to
+/// delete the selected task and Insert starts adding one. Returns true if
+/// the key was one of them.
+fn handle_editing_key(app: &mut App, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Delete => {
+            app.request_delete_task();
+            true
+        }
+        KeyCode::Insert => {
+            app.start_adding_task();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Handle the key after the 'd' prefix. Each delete asks first when its
+/// confirmation is on, like the Delete key.
+fn handle_delete_prefix(app: &mut App, code: KeyCode) {
+    match code {
+        KeyCode::Char('t') => app.request_delete_task(),
+        KeyCode::Char('c') => app.request_delete_column(),
+        // Empty the column but keep it
+        KeyCode::Char('x') => app.request_clear_column(),
+        _ => {} // Ignore other characters
+    }
+}
+
+/// Handle Tab and Shift+Tab in Normal mode as aliases for 'l' and 'h'.
+/// Returns true if the key was one of them.
+fn handle_column_tab_key(app: &mut App, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Tab => {
+            app.select_next_column();
+            true
+        }
+        KeyCode::BackTab => {
+            app.select_prev_column();
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Handle a key while confirming that the active column should be
+/// emptied: y clears it, n or Esc cancels. Returns true if the key closed
+/// the confirmation.
+fn handle_confirm_clear_column_key(app: &mut App, code: KeyCode) -> bool {
+    match code {
+        KeyCode::Char('y') => app.clear_column(),
+        KeyCode::Char('n') | KeyCode::Esc => {}
+        _ => return false,
+    }
+    app.input_mode = InputMode::Normal;
+    true
+}
 
 /// Runs the main event loop for the application.
 pub fn run_app(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     mut app: App,
+) -> io::Result<()> {
+    let result = run_event_loop(terminal, &mut app);
+    // Remember where the user was so the board reopens there
+    app.save_selection();
+    result
+}
+
+fn run_event_loop(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    app: &mut App,
 ) -> io::Result<()> {
     let mut last_key: Option<KeyCode> = None;
 
     loop {
-        terminal.draw(|f| crate::kanban::ui::render::draw_ui(f, &app))?;
-
-        if let Event::Key(key) = event::read()? {
-            match app.input_mode {
-                InputMode::BoardSelection => {
-                    match key.code {
-                        // Change this from quitting to returning to Normal mode
-                        KeyCode::Esc => {
-                            // Only return to Normal mode if we're not in the initial app startup
-                            if app.columns.len() > 0 {
-                                app.input_mode = InputMode::Normal;
-                            } else {
-                                // If no board is loaded, Esc should still quit
-                                return Ok(());
-                            }
-                        }
-                        KeyCode::Char('b') => {
-                            // Return to normal mode
-                            app.input_mode = InputMode::Normal;
-                        }
-                        KeyCode::Char('q') => return Ok(()), // Explicit quit option
-                        KeyCode::Up | KeyCode::Char('k') => app.select_prev_board(),
-                        KeyCode::Down | KeyCode::Char('j') => app.select_next_board(),
-                        KeyCode::Enter => {
-                            // Handle board selection
-                            if let Err(e) = app.load_selected_board() {
-                                eprintln!("Error loading board: {}", e);
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-                InputMode::AddingBoard => {
-                    match key.code {
-                        KeyCode::Enter => {
-                            let board_name = if app.input_text.is_empty() {
-                                "My Kanban Board".to_string()
-                            } else {
-                                app.input_text.clone()
-                            };
-
-                            // Create the new board
-                            if let Err(e) = app.create_new_board(&board_name) {
-                                eprintln!("Error creating board: {}", e);
-                            } else {
-                                app.input_mode = InputMode::Normal;
-                            }
+        let size = terminal.size()?;
+        app.sync_scroll(crate::kanban::ui::render::max_visible_columns(
+            size.width,
+            &app.config,
+        ));
+        terminal.draw(|f| crate::kanban::ui::render::draw_ui(f, app))?;
 
-                            app.input_text.clear();
-                        }
-                        KeyCode::Esc => {
-                            // Return to board selection
-                            app.input_mode = InputMode::BoardSelection;
-                            app.input_text.clear();
-                        }
-                        KeyCode::Char(c) => app.input_text.push(c),
-                        KeyCode::Backspace => {
-                            app.input_text.pop();
-                        }
-                        _ => {}
-                    }
-                }
-                InputMode::Normal => {
-                    // Handle double-tap 'd' for deleting columns
-                    // if key.modifiers.is_empty() && key.code == KeyCode::Char('d') {
-                    //     if let Some(KeyCode::Char('d')) = last_key {
-                    //         last_key = None;
-                    //         app.input_mode = InputMode::ConfirmDeleteColumn;
-                    //         continue;
-                    //     } else {
-                    //         last_key = Some(KeyCode::Char('d'));
-                    //         continue;
-                    //     }
-                    // } else {
-                    //     last_key = None;
-                    match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Char('a') => {
-                            // 'a' prefix for add commands
-                            match event::read()? {
-                                Event::Key(key) => match key.code {
-                                    KeyCode::Char('c') => app.input_mode = InputMode::AddingColumn,
-                                    KeyCode::Char('t') => app.input_mode = InputMode::AddingTask,
-                                    _ => {} // Ignore other characters
-                                },
-                                _ => {} // Ignore other events
-                            }
-                        }
-                        KeyCode::Char('d') => {
-                            // 'd' prefix for delete commands
-                            match event::read()? {
-                                Event::Key(key) => match key.code {
-                                    KeyCode::Char('t') => {
-                                        // Make sure we're deleting a task when there is one
-                                        if let Some(column) = app.columns.get(app.active_column) {
-                                            if column.selected_task.is_some() {
-                                                app.delete_current_task();
-                                            }
-                                        }
-                                    }
-                                    KeyCode::Char('c') => {
-                                        // Only proceed to delete column confirmation if we have columns
-                                        if !app.columns.is_empty() {
-                                            app.input_mode = InputMode::ConfirmDeleteColumn;
-                                        }
-                                    }
-                                    _ => {} // Ignore other characters
-                                },
-                                _ => {} // Ignore other events
-                            }
-                        }
-                        KeyCode::Char('b') => {
-                            // Toggle board selection
-                            if app.input_mode == InputMode::BoardSelection {
-                                // If already in board selection, return to normal mode
-                                app.input_mode = InputMode::Normal;
-                            } else {
-                                // Otherwise scan boards and enter board selection mode
-                                if let Err(e) = app.scan_available_boards() {
-                                    eprintln!("Error scanning boards: {}", e);
-                                }
-                                app.input_mode = InputMode::BoardSelection;
-                            }
-                        }
-                        KeyCode::Char('g') => {
-                            // Handle 'g' prefix for jump shortcuts
-                            match event::read()? {
-                                Event::Key(key) => match key.code {
-                                    KeyCode::Char('c') => {
-                                        app.input_mode = InputMode::JumpToColumnMode
-                                    }
-                                    KeyCode::Char('t') => {
-                                        app.input_mode = InputMode::JumpToTaskMode
-                                    }
-                                    _ => {} // Ignore other characters
-                                },
-                                _ => {} // Ignore other events
-                            }
-                        }
-                        KeyCode::Char('m') => {
-                            // Only enter column selection mode if there's a task selected in the current column
-                            if let Some(column) = app.columns.get(app.active_column) {
-                                if column.selected_task.is_some() {
-                                    app.input_mode = InputMode::ColumnSelectionMode;
-                                }
-                            }
+        // Poll so time-based state (like change highlights) gets redrawn
+        if !event::poll(POLL_INTERVAL)? {
+            // Pick up edits made to the board file by other programs
+            app.check_external_change();
+            app.tick_focus_timer(Instant::now());
+            continue;
+        }
+
+        let key = match event::read()? {
+            Event::Key(key) => key,
+            Event::Paste(text) => {
+                app.paste_text(&text);
+                continue;
+            }
+            _ => continue,
+        };
+        // Status messages only last until the next key press
+        app.status_message = None;
+
+        if is_quit_key(&key) {
+            if handle_quit_key(app) {
+                return Ok(());
+            }
+            continue;
+        }
+
+        match app.input_mode {
+            // Any key closes the first-run help over the board picker
+            InputMode::BoardSelection if app.show_onboarding => app.dismiss_onboarding(),
+            InputMode::BoardSelection => {
+                match key.code {
+                    // Change this from quitting to returning to Normal mode
+                    KeyCode::Esc => {
+                        // Only return to Normal mode if we're not in the initial app startup
+                        if app.columns.len() > 0 {
+                            app.input_mode = InputMode::Normal;
+                        } else {
+                            // If no board is loaded, Esc should still quit
+                            return Ok(());
                         }
-                        KeyCode::Char('h') => app.select_prev_column(),
-                        KeyCode::Char('l') => app.select_next_column(),
-                        KeyCode::Char('j') => app.select_next_task(),
-                        KeyCode::Char('k') => app.select_prev_task(),
-                        // Keep save functionality with Ctrl+S
-                        KeyCode::Char('s') if key.modifiers == KeyModifiers::CONTROL => {
-                            // Explicitly save board to file
-                            if let Err(e) = app.save_board() {
-                                eprintln!("Error saving board: {}", e);
-                            }
+                    }
+                    KeyCode::Char('b') => {
+                        // Return to normal mode
+                        app.input_mode = InputMode::Normal;
+                    }
+                    // Explicit quit option, asking first if anything is unsaved
+                    KeyCode::Char('q') if app.request_quit() => return Ok(()),
+                    KeyCode::Up | KeyCode::Char('k') => app.select_prev_board(),
+                    KeyCode::Down | KeyCode::Char('j') => app.select_next_board(),
+                    // Reorder boards; the new order is remembered
+                    KeyCode::Char('K') | KeyCode::Char('J') => {
+                        let up = key.code == KeyCode::Char('K');
+                        if let Err(e) = app.move_selected_board(up) {
+                            app.status_message = Some(format!("Error saving board order: {}", e));
                         }
-                        KeyCode::Char('r') => {
-                            // 'r' prefix for rename commands
-                            match event::read()? {
-                                Event::Key(key) => match key.code {
-                                    KeyCode::Char('c') => {
-                                        // Only allow renaming if there's at least one column
-                                        if !app.columns.is_empty() {
-                                            app.prepare_rename_column();
-                                        }
-                                    }
-                                    KeyCode::Char('t') => {
-                                        // Only allow renaming if there's a selected task
-                                        if let Some(column) = app.columns.get(app.active_column) {
-                                            if column.selected_task.is_some() {
-                                                app.prepare_rename_task();
-                                            }
-                                        }
-                                    }
-                                    _ => {} // Ignore other characters
-                                },
-                                _ => {} // Ignore other events
-                            }
+                    }
+                    KeyCode::Enter => {
+                        // Handle board selection
+                        if let Err(e) = app.load_selected_board() {
+                            app.status_message = Some(format!("Error loading board: {}", e));
                         }
-
-                        _ => {}
                     }
-                    // }
+                    _ => {}
                 }
-                InputMode::AddingColumn => match key.code {
+            }
+            InputMode::AddingBoard => {
+                match key.code {
                     KeyCode::Enter => {
-                        let column_name = if app.input_text.is_empty() {
-                            "New Column".to_string()
+                        let board_name = if app.input_text.is_empty() {
+                            "My Kanban Board".to_string()
                         } else {
                             app.input_text.clone()
                         };
-                        app.add_column(&column_name);
+
+                        // Create the new board
+                        if let Err(e) = app.create_new_board(&board_name) {
+                            app.status_message = Some(format!("Error creating board: {}", e));
+                        } else {
+                            app.input_mode = InputMode::Normal;
+                        }
+
+                        app.input_text.clear();
                     }
                     KeyCode::Esc => {
-                        app.input_mode = InputMode::Normal;
+                        // Return to board selection
+                        app.input_mode = InputMode::BoardSelection;
                         app.input_text.clear();
                     }
                     KeyCode::Char(c) => app.input_text.push(c),
@@ -218,140 +200,869 @@ pub fn run_app(
                         app.input_text.pop();
                     }
                     _ => {}
-                },
-                InputMode::AddingTask => match key.code {
-                    KeyCode::Enter => {
-                        let task_name = if app.input_text.is_empty() {
-                            "New Task".to_string()
+                }
+            }
+            InputMode::Normal => {
+                // Handle double-tap 'd' for deleting columns
+                // if key.modifiers.is_empty() && key.code == KeyCode::Char('d') {
+                //     if let Some(KeyCode::Char('d')) = last_key {
+                //         last_key = None;
+                //         app.input_mode = InputMode::ConfirmDeleteColumn;
+                //         continue;
+                //     } else {
+                //         last_key = Some(KeyCode::Char('d'));
+                //         continue;
+                //     }
+                // } else {
+                //     last_key = None;
+                match key.code {
+                    KeyCode::Char('q') => {
+                        if app.request_quit() {
+                            return Ok(());
+                        }
+                    }
+                    KeyCode::Char('a') => {
+                        // 'a' prefix for add commands
+                        match event::read()? {
+                            Event::Key(key) => match key.code {
+                                KeyCode::Char('c') => app.input_mode = InputMode::AddingColumn,
+                                KeyCode::Char('t') => app.start_adding_task(),
+                                KeyCode::Char('d') => app.duplicate_column(),
+                                // Insert a column before/after the active one
+                                KeyCode::Char('b') => {
+                                    app.input_mode = InputMode::InsertingColumn(app.active_column)
+                                }
+                                KeyCode::Char('a') => {
+                                    let index = if app.columns.is_empty() {
+                                        0
+                                    } else {
+                                        app.active_column + 1
+                                    };
+                                    app.input_mode = InputMode::InsertingColumn(index)
+                                }
+                                _ => {} // Ignore other characters
+                            },
+                            _ => {} // Ignore other events
+                        }
+                    }
+                    KeyCode::Char('d') => {
+                        // 'd' prefix for delete commands
+                        if let Event::Key(key) = event::read()? {
+                            handle_delete_prefix(app, key.code);
+                        }
+                    }
+                    KeyCode::Char('b') => {
+                        // Toggle board selection
+                        if app.input_mode == InputMode::BoardSelection {
+                            // If already in board selection, return to normal mode
+                            app.input_mode = InputMode::Normal;
                         } else {
-                            app.input_text.clone()
-                        };
-                        app.add_task(&task_name);
+                            // Otherwise scan boards and enter board selection mode
+                            if let Err(e) = app.scan_available_boards() {
+                                app.status_message = Some(format!("Error scanning boards: {}", e));
+                            }
+                            app.input_mode = InputMode::BoardSelection;
+                        }
                     }
-                    KeyCode::Esc => {
-                        app.input_mode = InputMode::Normal;
-                        app.input_text.clear();
+                    KeyCode::Char('g') => {
+                        // Handle 'g' prefix for jump shortcuts
+                        match event::read()? {
+                            Event::Key(key) => match key.code {
+                                KeyCode::Char('c') => app.input_mode = InputMode::JumpToColumnMode,
+                                KeyCode::Char('t') => app.input_mode = InputMode::JumpToTaskMode,
+                                KeyCode::Char('n') => app.focus_first_nonempty_column(),
+                                KeyCode::Char('o') => app.open_task_url(),
+                                _ => {} // Ignore other characters
+                            },
+                            _ => {} // Ignore other events
+                        }
                     }
-                    KeyCode::Char(c) => app.input_text.push(c),
-                    KeyCode::Backspace => {
-                        app.input_text.pop();
+                    KeyCode::Char('m') => {
+                        // Only enter column selection mode if there's a task selected in the current column
+                        if let Some(column) = app.columns.get(app.active_column) {
+                            if column.selected_task.is_some() {
+                                app.input_mode = InputMode::ColumnSelectionMode;
+                            }
+                        }
                     }
-                    _ => {}
-                },
-                InputMode::MoveMode => match key.code {
-                    KeyCode::Esc => app.input_mode = InputMode::Normal,
-                    KeyCode::Char(c) if c >= '0' && c <= '9' => {
-                        let index = c.to_digit(10).unwrap() as usize;
-                        app.jump_to_column(index);
+                    KeyCode::Char('M') => {
+                        // Move the selected task by typing the column name
+                        let has_selection = app
+                            .columns
+                            .get(app.active_column)
+                            .is_some_and(|column| column.selected_task.is_some());
+                        if has_selection {
+                            app.input_text.clear();
+                            app.completion = None;
+                            app.input_mode = InputMode::MovingTaskByName;
+                        }
                     }
-                    _ => {}
-                },
-                InputMode::ConfirmDeleteColumn => match key.code {
-                    KeyCode::Char('y') => {
-                        // Make sure we don't try to delete when there are no columns
-                        if !app.columns.is_empty() {
-                            app.delete_current_column();
+                    KeyCode::Delete | KeyCode::Insert => {
+                        handle_editing_key(app, key.code);
+                    }
+                    KeyCode::Tab | KeyCode::BackTab => {
+                        handle_column_tab_key(app, key.code);
+                    }
+                    KeyCode::Char('h') if app.tag_view => app.step_tag_view_group(-1),
+                    KeyCode::Char('l') if app.tag_view => app.step_tag_view_group(1),
+                    KeyCode::Char('h') => app.select_prev_column(),
+                    KeyCode::Char('l') => app.select_next_column(),
+                    KeyCode::Char('j') if app.priority_view => app.step_priority_view(1),
+                    KeyCode::Char('k') if app.priority_view => app.step_priority_view(-1),
+                    KeyCode::Char('j') if app.tag_view => app.step_tag_view(1),
+                    KeyCode::Char('k') if app.tag_view => app.step_tag_view(-1),
+                    KeyCode::Char('j') => app.select_next_task(),
+                    KeyCode::Char('k') => app.select_prev_task(),
+                    // Ctrl+6 (sent as Ctrl+^ by most terminals) flips to the
+                    // previously open board, like vim's alternate file
+                    KeyCode::Char('6' | '^') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        if let Err(e) = app.switch_to_previous_board() {
+                            app.status_message = Some(format!("Error switching boards: {}", e));
                         }
-                        app.input_mode = InputMode::Normal;
                     }
-                    KeyCode::Char('n') | KeyCode::Esc => {
-                        // Also allow Esc to cancel deletion
-                        app.input_mode = InputMode::Normal;
+                    // Keep save functionality with Ctrl+S
+                    KeyCode::Char('s') if key.modifiers == KeyModifiers::CONTROL => {
+                        // Explicitly save board to file
+                        app.save_with_feedback();
                     }
-                    _ => {}
-                },
-                InputMode::ColumnSelectionMode => match key.code {
-                    KeyCode::Esc => app.input_mode = InputMode::Normal,
-                    KeyCode::Char(c) if c >= '1' && c <= '9' => {
-                        let index = c.to_digit(10).unwrap() as usize;
-                        // Handle the column index: key 1 maps to index 0, key 2 to index 1, etc.
-                        let target_index = index - 1;
-
-                        // Only move if the target index is valid and not the current column
-                        if target_index < app.columns.len() && target_index != app.active_column {
-                            app.move_task_to_column(target_index);
+                    KeyCode::Char('u') => {
+                        let undone = app.undo();
+                        if !undone {
+                            app.status_message = Some("Nothing to undo".to_string());
                         }
-
-                        app.input_mode = InputMode::Normal;
                     }
-                    _ => {}
-                },
-                InputMode::JumpToColumnMode => match key.code {
-                    KeyCode::Esc => app.input_mode = InputMode::Normal,
-                    KeyCode::Char(c) if c >= '1' && c <= '9' => {
-                        let index = c.to_digit(10).unwrap() as usize;
-                        // Map key 1 to index 0, key 2 to index 1, etc.
-                        let target_index = index - 1;
-
-                        // Only jump if the target index is valid
-                        if target_index < app.columns.len() {
-                            app.active_column = target_index;
-
-                            // Clear selection in all non-active columns
-                            for (i, column) in app.columns.iter_mut().enumerate() {
-                                if i != app.active_column {
-                                    column.selected_task = None;
+                    KeyCode::Char('D') => {
+                        // Review what got finished
+                        app.input_mode = InputMode::DoneSummary;
+                    }
+                    KeyCode::Char('/') => app.prepare_filter(),
+                    KeyCode::Char('T') => app.prepare_send_task(),
+                    KeyCode::Char('S') => app.prepare_split_task(),
+                    KeyCode::Char('#') => {
+                        app.input_text.clear();
+                        app.input_mode = InputMode::JumpToTaskId;
+                    }
+                    KeyCode::Char('?') => {
+                        app.info_scroll = 0;
+                        app.input_mode = InputMode::KeyHelp;
+                    }
+                    KeyCode::Char('i') => {
+                        // Show which file backs the current board
+                        app.info_scroll = 0;
+                        app.input_mode = InputMode::BoardInfo;
+                    }
+                    KeyCode::Char('I') => app.open_board_stats(),
+                    KeyCode::Char('G') => {
+                        app.tag_view = !app.tag_view;
+                        app.priority_view = false;
+                    }
+                    KeyCode::Char('P') => app.toggle_priority_view(),
+                    KeyCode::Char('B') => app.move_task_to_least_loaded_column(),
+                    KeyCode::Char('J') => app.shift_selected_task(true),
+                    KeyCode::Char('K') => app.shift_selected_task(false),
+                    KeyCode::Char('W') => app.save_all(),
+                    KeyCode::Enter => app.activate_selected_task(),
+                    KeyCode::Char('F') => app.toggle_focus_timer(),
+                    KeyCode::Char('C') => app.prepare_import_csv(),
+                    KeyCode::Char('X') => app.open_trash(),
+                    KeyCode::Char('R') => {
+                        // Revert to the version saved before the last explicit save
+                        app.input_mode = InputMode::ConfirmRevert;
+                    }
+                    KeyCode::Char('p') => {
+                        // 'p' prefix for editing the selected task's priority
+                        if let Event::Key(key) = event::read()? {
+                            match key.code {
+                                KeyCode::Char('i') => {
+                                    app.prepare_edit_priority(PriorityField::Impact)
+                                }
+                                KeyCode::Char('u') => {
+                                    app.prepare_edit_priority(PriorityField::Urgency)
                                 }
+                                KeyCode::Char('f') => {
+                                    app.prepare_edit_priority(PriorityField::Effort)
+                                }
+                                _ => {} // Ignore other characters
                             }
                         }
-
-                        app.input_mode = InputMode::Normal;
                     }
-                    _ => app.input_mode = InputMode::Normal, // Any other key cancels the mode
-                },
-                InputMode::JumpToTaskMode => match key.code {
-                    KeyCode::Esc => app.input_mode = InputMode::Normal,
-                    KeyCode::Char(c) => {
-                        // Check if the character is a valid jump label
-                        if let Some(task_info) = app.get_task_by_jump_label(c) {
-                            app.jump_to_task(task_info.0, task_info.1);
-                            // Mode will be reset to Normal in jump_to_task
-                        } else {
-                            // If not a valid label, stay in jump mode
-                            // This allows users to see the labels and try again
+                    KeyCode::Char('w') => {
+                        // 'w' prefix for the column's WIP limit
+                        if let Event::Key(key) = event::read()?
+                            && key.code == KeyCode::Char('l')
+                        {
+                            app.prepare_edit_wip_limit();
                         }
                     }
-                    _ => {} // Other keys do nothing - stay in jump mode
-                },
-                InputMode::RenamingColumn => match key.code {
-                    KeyCode::Enter => {
-                        let new_name = if app.input_text.is_empty() {
-                            "Unnamed Column".to_string()
-                        } else {
-                            app.input_text.clone()
-                        };
-                        app.rename_current_column(&new_name);
+                    KeyCode::Char('s') => {
+                        // 's' prefix for sort commands
+                        if let Event::Key(key) = event::read()? {
+                            match key.code {
+                                KeyCode::Char('c') => app.sort_column_by_priority(),
+                                KeyCode::Char('b') => app.sort_all_columns_by_priority(),
+                                KeyCode::Char('d') => app.toggle_sort_direction(),
+                                _ => {} // Ignore other characters
+                            }
+                        }
                     }
-                    KeyCode::Esc => {
-                        app.input_mode = InputMode::Normal;
-                        app.input_text.clear();
+                    KeyCode::Char('y') => {
+                        // 'y' prefix for copying to the clipboard
+                        if let Event::Key(key) = event::read()? {
+                            match key.code {
+                                KeyCode::Char('c') => app.copy_column_to_clipboard(false),
+                                KeyCode::Char('p') => app.copy_column_to_clipboard(true),
+                                _ => {} // Ignore other characters
+                            }
+                        }
                     }
-                    KeyCode::Char(c) => app.input_text.push(c),
-                    KeyCode::Backspace => {
-                        app.input_text.pop();
+                    KeyCode::Char('z') => {
+                        // 'z' prefix for fold and scroll commands
+                        if let Event::Key(key) = event::read()? {
+                            match key.code {
+                                KeyCode::Char('a') => app.toggle_column_fold(),
+                                KeyCode::Char('e') => app.toggle_hide_empty_columns(),
+                                KeyCode::Char('c') => app.toggle_compact_tasks(),
+                                // Peek at off-screen columns, keeping the active one
+                                KeyCode::Char('h') => app.scroll_columns(-1),
+                                KeyCode::Char('l') => app.scroll_columns(1),
+                                _ => {} // Ignore other characters
+                            }
+                        }
                     }
+                    KeyCode::Char('r') => {
+                        // 'r' prefix for rename commands
+                        match event::read()? {
+                            Event::Key(key) => match key.code {
+                                KeyCode::Char('c') => {
+                                    // Only allow renaming if there's at least one column
+                                    if !app.columns.is_empty() {
+                                        app.prepare_rename_column();
+                                    }
+                                }
+                                KeyCode::Char('t') => {
+                                    // Only allow renaming if there's a selected task
+                                    if let Some(column) = app.columns.get(app.active_column) {
+                                        if column.selected_task.is_some() {
+                                            app.prepare_rename_task();
+                                        }
+                                    }
+                                }
+                                _ => {} // Ignore other characters
+                            },
+                            _ => {} // Ignore other events
+                        }
+                    }
+
                     _ => {}
-                },
-                InputMode::RenamingTask => match key.code {
-                    KeyCode::Enter => {
-                        let new_name = if app.input_text.is_empty() {
-                            "Unnamed Task".to_string()
-                        } else {
-                            app.input_text.clone()
-                        };
-                        app.rename_current_task(&new_name);
+                }
+                // }
+            }
+            InputMode::AddingColumn => match key.code {
+                KeyCode::Enter => {
+                    let column_name = if app.input_text.is_empty() {
+                        "New Column".to_string()
+                    } else {
+                        app.input_text.clone()
+                    };
+                    app.add_column(&column_name);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::InsertingColumn(index) => match key.code {
+                KeyCode::Enter => {
+                    let column_name = if app.input_text.is_empty() {
+                        "New Column".to_string()
+                    } else {
+                        app.input_text.clone()
+                    };
+                    app.insert_column(index, &column_name);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::AddingTask => match key.code {
+                KeyCode::Enter => {
+                    let task_name = if app.input_text.is_empty() {
+                        "New Task".to_string()
+                    } else {
+                        app.input_text.clone()
+                    };
+                    app.add_task(&task_name);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::MoveMode => match key.code {
+                KeyCode::Esc => app.input_mode = InputMode::Normal,
+                KeyCode::Char(c) if c >= '0' && c <= '9' => {
+                    let index = c.to_digit(10).unwrap() as usize;
+                    app.jump_to_column(index);
+                }
+                _ => {}
+            },
+            InputMode::ConfirmReload => match key.code {
+                KeyCode::Char('y') => {
+                    app.input_mode = InputMode::Normal;
+                    app.reload_from_disk();
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    // Keep our version; the next save overwrites the file
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::ConfirmMergeColumns(first, second) => match key.code {
+                KeyCode::Char('y') => {
+                    app.merge_columns(first, second);
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::ConfirmReplayJournal => match key.code {
+                KeyCode::Char('y') => app.replay_journal(),
+                KeyCode::Char('n') | KeyCode::Esc => app.discard_journal(),
+                _ => {}
+            },
+            InputMode::ConfirmDeleteTask => match key.code {
+                KeyCode::Char('y') => {
+                    app.delete_current_task();
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::ConfirmDeleteColumn => match key.code {
+                KeyCode::Char('y') => {
+                    // Make sure we don't try to delete when there are no columns
+                    if !app.columns.is_empty() {
+                        app.delete_current_column();
                     }
-                    KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    // Also allow Esc to cancel deletion
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::ConfirmClearColumn => {
+                handle_confirm_clear_column_key(app, key.code);
+            }
+            InputMode::TrashViewer => match key.code {
+                KeyCode::Char('j') | KeyCode::Down => app.select_next_trash_entry(),
+                KeyCode::Char('k') | KeyCode::Up => app.select_prev_trash_entry(),
+                KeyCode::Enter | KeyCode::Char('r') => app.restore_selected_trash_entry(),
+                KeyCode::Esc | KeyCode::Char('q') => app.input_mode = InputMode::Normal,
+                _ => {}
+            },
+            InputMode::ConfirmQuit(_) => match key.code {
+                KeyCode::Char('y') => return Ok(()),
+                KeyCode::Char('n') | KeyCode::Esc => app.cancel_quit(),
+                _ => {}
+            },
+            InputMode::DoneSummary => {
+                // Any key closes the summary
+                app.input_mode = InputMode::Normal;
+            }
+            InputMode::BoardInfo | InputMode::KeyHelp => match key.code {
+                // j/k scroll a long description or key list, any other key closes
+                KeyCode::Char('j') | KeyCode::Down => {
+                    // Stop where drawing does, so no press past the end is lost
+                    let area = Rect::new(0, 0, size.width, size.height);
+                    app.info_scroll = (app.info_scroll + 1).min(max_info_scroll(app, area));
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    app.info_scroll = app.info_scroll.saturating_sub(1);
+                }
+                _ => app.input_mode = InputMode::Normal,
+            },
+            InputMode::BoardStats | InputMode::TaskDetail => {
+                // Any key closes the stats and detail popups
+                app.input_mode = InputMode::Normal;
+            }
+            InputMode::ConfirmWipOverride(target) => match key.code {
+                KeyCode::Char('y') => {
+                    app.move_task_to_column(target);
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::ConfirmRevert => match key.code {
+                KeyCode::Char('y') => {
+                    match app.revert_to_backup() {
+                        Ok(()) => {
+                            app.status_message =
+                                Some("Reverted to the previously saved version".to_string())
+                        }
+                        Err(e) => {
+                            app.status_message = Some(format!("Error reverting board: {}", e))
+                        }
+                    }
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char('n') | KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => {}
+            },
+            InputMode::MovingTaskByName => match key.code {
+                KeyCode::Tab => app.complete_column_name(),
+                KeyCode::Enter => app.resolve_move_by_name(true),
+                KeyCode::Esc => {
+                    app.input_text.clear();
+                    app.completion = None;
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Char(c) => {
+                    app.input_text.push(c);
+                    app.completion = None;
+                    app.resolve_move_by_name(false);
+                }
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                    app.completion = None;
+                }
+                _ => {}
+            },
+            InputMode::EditingPriority(field) => match key.code {
+                KeyCode::Enter => {
+                    let accepted = app
+                        .input_text
+                        .parse()
+                        .is_ok_and(|value| app.set_priority_field(field, value));
+                    if accepted {
+                        app.input_mode = InputMode::Normal;
+                    } else {
+                        app.status_message = Some(format!(
+                            "{} must be between 0 and {}",
+                            field.label(),
+                            MAX_PRIORITY_VALUE
+                        ));
+                    }
+                    app.input_text.clear();
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::EditingWipLimit => match key.code {
+                KeyCode::Enter => {
+                    // An empty prompt clears the limit
+                    let limit = match app.input_text.trim() {
+                        "" => Ok(None),
+                        text => text.parse().map(Some),
+                    };
+                    // On bad input stay in the prompt with the text kept to fix
+                    if limit.is_ok_and(|limit| app.set_wip_limit(limit)) {
                         app.input_mode = InputMode::Normal;
                         app.input_text.clear();
+                    } else {
+                        app.status_message =
+                            Some("WIP limit must be above 0, or empty for none".to_string());
                     }
-                    KeyCode::Char(c) => app.input_text.push(c),
-                    KeyCode::Backspace => {
-                        app.input_text.pop();
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::ColumnSelectionMode => match key.code {
+                KeyCode::Esc => app.input_mode = InputMode::Normal,
+                KeyCode::Char(c) if c >= '1' && c <= '9' => {
+                    let index = c.to_digit(10).unwrap() as usize;
+                    // Handle the column index: key 1 maps to index 0, key 2 to index 1, etc.
+                    let target_index = index - 1;
+
+                    // Only move if the target index is valid and not the current column
+                    if target_index < app.columns.len() && target_index != app.active_column {
+                        app.request_move_task_to_column(target_index);
+                    } else {
+                        app.input_mode = InputMode::Normal;
                     }
-                    _ => {}
-                },
-            }
+                }
+                _ => {}
+            },
+            InputMode::JumpToColumnMode => match key.code {
+                KeyCode::Esc => app.input_mode = InputMode::Normal,
+                KeyCode::Char(c) if c >= '1' && c <= '9' => {
+                    let index = c.to_digit(10).unwrap() as usize;
+                    // Map key 1 to index 0, key 2 to index 1, etc.
+                    let target_index = index - 1;
+
+                    // Only jump if the target index is valid
+                    if target_index < app.columns.len() {
+                        app.active_column = target_index;
+
+                        // Clear selection in all non-active columns
+                        for (i, column) in app.columns.iter_mut().enumerate() {
+                            if i != app.active_column {
+                                column.selected_task = None;
+                            }
+                        }
+                    }
+
+                    app.input_mode = InputMode::Normal;
+                }
+                _ => app.input_mode = InputMode::Normal, // Any other key cancels the mode
+            },
+            InputMode::JumpToTaskMode => match key.code {
+                KeyCode::Esc => app.input_mode = InputMode::Normal,
+                KeyCode::Char(c) => {
+                    // Check if the character is a valid jump label
+                    if let Some(task_info) = app.get_task_by_jump_label(c) {
+                        app.jump_to_task(task_info.0, task_info.1);
+                        // Mode will be reset to Normal in jump_to_task
+                    } else {
+                        // If not a valid label, stay in jump mode
+                        // This allows users to see the labels and try again
+                    }
+                }
+                _ => {} // Other keys do nothing - stay in jump mode
+            },
+            InputMode::RenamingColumn => match key.code {
+                KeyCode::Enter => {
+                    let new_name = if app.input_text.is_empty() {
+                        "Unnamed Column".to_string()
+                    } else {
+                        app.input_text.clone()
+                    };
+                    app.rename_current_column(&new_name);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::RenamingTask => match key.code {
+                KeyCode::Enter => {
+                    let new_name = if app.input_text.is_empty() {
+                        "Unnamed Task".to_string()
+                    } else {
+                        app.input_text.clone()
+                    };
+                    app.rename_current_task(&new_name);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::JumpToTaskId => match key.code {
+                KeyCode::Enter => app.jump_to_task_id(),
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::ImportingCsv => match key.code {
+                KeyCode::Enter => app.import_csv_from_prompt(),
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::SplittingTask => match key.code {
+                KeyCode::Enter => {
+                    let new_title = app.input_text.clone();
+                    app.split_task(&new_title);
+                }
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::SendingTaskToBoard => match key.code {
+                KeyCode::Enter => app.send_task_by_name(),
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
+            InputMode::Filtering => match key.code {
+                KeyCode::Enter => app.apply_filter(),
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                    app.input_text.clear();
+                }
+                KeyCode::Char(c) => app.input_text.push(c),
+                KeyCode::Backspace => {
+                    app.input_text.pop();
+                }
+                _ => {}
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::config::EnterAction;
+    use crate::kanban::models::{Column, Task};
+
+    fn app_with_one_task() -> App {
+        let mut app = App::new("Key Board");
+        app.file_path = None;
+        app.input_mode = InputMode::Normal;
+        app.columns = vec![Column {
+            title: "To Do".to_string(),
+            tasks: vec![Task {
+                id: 0,
+                title: "Delete me".to_string(),
+                description: None,
+                priority: None,
+                completed_at: None,
+                tags: Vec::new(),
+                due: None,
+                created: None,
+                focus_minutes: 0,
+            }],
+            selected_task: Some(0),
+            wip_limit: None,
+        }];
+        app.active_column = 0;
+        app
+    }
+
+    #[test]
+    fn test_ctrl_c_is_quit_key() {
+        assert!(is_quit_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::CONTROL
+        )));
+        assert!(!is_quit_key(&KeyEvent::new(
+            KeyCode::Char('c'),
+            KeyModifiers::NONE
+        )));
+        assert!(!is_quit_key(&KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL
+        )));
+    }
+
+    #[test]
+    fn test_delete_key_asks_for_confirmation() {
+        let mut app = app_with_one_task();
+
+        assert!(handle_editing_key(&mut app, KeyCode::Delete));
+        assert!(app.input_mode == InputMode::ConfirmDeleteTask);
+        // Nothing is deleted until the user confirms
+        assert_eq!(app.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_dt_asks_for_confirmation_when_enabled() {
+        let mut app = app_with_one_task();
+        app.config.confirmations.delete_task = true;
+
+        handle_delete_prefix(&mut app, KeyCode::Char('t'));
+        assert!(app.input_mode == InputMode::ConfirmDeleteTask);
+        assert_eq!(app.columns[0].tasks.len(), 1);
+
+        // With the confirmation off, dt deletes right away
+        app.input_mode = InputMode::Normal;
+        app.config.confirmations.delete_task = false;
+        handle_delete_prefix(&mut app, KeyCode::Char('t'));
+        assert!(app.columns[0].tasks.is_empty());
+    }
+
+    #[test]
+    fn test_delete_key_without_selection_does_nothing() {
+        let mut app = app_with_one_task();
+        app.columns[0].selected_task = None;
+
+        assert!(handle_editing_key(&mut app, KeyCode::Delete));
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_insert_key_starts_adding_task() {
+        let mut app = app_with_one_task();
+
+        assert!(handle_editing_key(&mut app, KeyCode::Insert));
+        assert!(app.input_mode == InputMode::AddingTask);
+        assert!(!handle_editing_key(&mut app, KeyCode::Char('x')));
+    }
+
+    #[test]
+    fn test_adding_task_needs_a_column() {
+        let mut app = app_with_one_task();
+        app.columns.clear();
+
+        // 'at' and Insert both go through start_adding_task
+        app.start_adding_task();
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(app.status_message.as_deref().unwrap().contains("add one"));
+
+        app.status_message = None;
+        assert!(handle_editing_key(&mut app, KeyCode::Insert));
+        assert!(app.input_mode == InputMode::Normal);
+        assert!(app.status_message.is_some());
+    }
+
+    #[test]
+    fn test_tab_moves_between_columns() {
+        let mut app = app_with_one_task();
+        let mut done = app.columns[0].clone();
+        done.title = "Done".to_string();
+        app.columns.push(done);
+
+        assert!(handle_column_tab_key(&mut app, KeyCode::Tab));
+        assert_eq!(app.active_column, 1);
+        // Leaving a column clears its selection, as 'l' does
+        assert_eq!(app.columns[0].selected_task, None);
+
+        assert!(handle_column_tab_key(&mut app, KeyCode::BackTab));
+        assert_eq!(app.active_column, 0);
+        assert!(!handle_column_tab_key(&mut app, KeyCode::Char('l')));
+    }
+
+    #[test]
+    fn test_confirming_clear_column_removes_tasks() {
+        let mut app = app_with_one_task();
+        let task = app.columns[0].tasks[0].clone();
+        app.columns[0].tasks.push(task);
+
+        app.request_clear_column();
+        assert!(app.input_mode == InputMode::ConfirmClearColumn);
+        // Other keys leave the question open
+        assert!(!handle_confirm_clear_column_key(
+            &mut app,
+            KeyCode::Char('x')
+        ));
+        assert!(handle_confirm_clear_column_key(
+            &mut app,
+            KeyCode::Char('y')
+        ));
+
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(app.columns.len(), 1);
+        assert_eq!(app.columns[0].title, "To Do");
+        assert!(app.columns[0].tasks.is_empty());
+        assert_eq!(app.columns[0].selected_task, None);
+
+        // An empty column has nothing to confirm
+        app.request_clear_column();
+        assert!(app.input_mode == InputMode::Normal);
+    }
+
+    #[test]
+    fn test_cancelling_clear_column_keeps_tasks() {
+        let mut app = app_with_one_task();
+
+        app.request_clear_column();
+        assert!(handle_confirm_clear_column_key(
+            &mut app,
+            KeyCode::Char('n')
+        ));
+        assert!(app.input_mode == InputMode::Normal);
+        assert_eq!(app.columns[0].tasks.len(), 1);
+        assert_eq!(app.columns[0].selected_task, Some(0));
+
+        app.request_clear_column();
+        assert!(handle_confirm_clear_column_key(&mut app, KeyCode::Esc));
+        assert_eq!(app.columns[0].tasks.len(), 1);
+    }
+
+    #[test]
+    fn test_enter_opens_configured_action() {
+        let mut app = app_with_one_task();
+        assert_eq!(app.config.enter_action, EnterAction::Detail);
+        app.activate_selected_task();
+        assert!(app.input_mode == InputMode::TaskDetail);
+        assert_eq!(app.task_detail_lines()[0], "Delete me");
+
+        let mut app = app_with_one_task();
+        app.config.enter_action = EnterAction::Rename;
+        app.activate_selected_task();
+        assert!(app.input_mode == InputMode::RenamingTask);
+        assert_eq!(app.input_text, "Delete me");
+    }
+
+    #[test]
+    fn test_ctrl_c_asks_before_dropping_unsaved_changes() {
+        let mut app = app_with_one_task();
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert!(is_quit_key(&ctrl_c));
+
+        app.dirty = true;
+        assert!(!handle_quit_key(&mut app));
+        assert!(matches!(app.input_mode, InputMode::ConfirmQuit(_)));
+        // A second Ctrl+C at the prompt quits
+        assert!(handle_quit_key(&mut app));
+
+        let mut saved = app_with_one_task();
+        assert!(handle_quit_key(&mut saved));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod colors;
 pub mod input_handler;
 pub mod popups;
 pub mod render;
@@ -1,4 +1,7 @@
-use crate::kanban::models::{App, InputMode};
+use crate::kanban::models::{
+    App, BoardEntry, CREATE_NEW_BOARD_LABEL, Column, ColumnMatch, InputMode, MAX_PRIORITY_VALUE,
+};
+use crate::kanban::ui::task_formatter::{display_width, wrap_text};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
@@ -6,21 +9,51 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
 };
 
-/// Draw a simple input popup with a title and input field
+// Popups take a share of the terminal's width but stay within these bounds,
+// as far as the terminal allows
+const MIN_POPUP_WIDTH: u16 = 24;
+const MAX_POPUP_WIDTH: u16 = 100;
+
+/// An area `pct_x` percent as wide and `pct_y` percent as tall as `size`
+/// (percentages above 100 count as 100), centered in it
+pub fn centered_rect(pct_x: u16, pct_y: u16, size: Rect) -> Rect {
+    let scale = |length: u16, pct: u16| (u32::from(length) * u32::from(pct.min(100)) / 100) as u16;
+    center_rect(scale(size.width, pct_x), scale(size.height, pct_y), size)
+}
+
+/// Area of a popup `height` rows tall and `pct_x` percent of the terminal
+/// wide, kept between the minimum and maximum popup widths. On a terminal
+/// too small for it the popup shrinks to fit.
+pub fn popup_rect(pct_x: u16, height: u16, size: Rect) -> Rect {
+    let width = centered_rect(pct_x, 100, size)
+        .width
+        .clamp(MIN_POPUP_WIDTH, MAX_POPUP_WIDTH);
+    center_rect(width, height, size)
+}
+
+// Center a `width` x `height` box in `size`, shrunk to fit inside it
+fn center_rect(width: u16, height: u16, size: Rect) -> Rect {
+    let width = width.min(size.width);
+    let height = height.min(size.height);
+    Rect::new(
+        size.x + (size.width - width) / 2,
+        size.y + (size.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Draw a simple input popup with a title and input field, `pct_x` percent
+/// of the terminal wide
 pub fn draw_input_popup(
     f: &mut Frame,
     app: &App,
     size: Rect,
     title: &str,
-    width: u16,
+    pct_x: u16,
     height: u16,
 ) {
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(width)) / 2,
-        (size.height.saturating_sub(height)) / 2,
-        width.min(size.width),
-        height.min(size.height),
-    );
+    let popup_area = popup_rect(pct_x, height, size);
 
     f.render_widget(Clear, popup_area);
 
@@ -41,63 +74,473 @@ pub fn draw_input_popup(
     f.render_widget(input, input_area);
 
     f.set_cursor_position(Position {
-        x: input_area.x + app.input_text.len() as u16,
+        x: input_area.x + input_cursor_offset(&app.input_text),
         y: input_area.y,
     });
 }
 
+// Cursor column just past the typed text. Measured in display width
+// rather than bytes so it stays at the end of wide or non-ASCII names.
+fn input_cursor_offset(text: &str) -> u16 {
+    display_width(text) as u16
+}
+
+/// Draw the move-by-name prompt with the columns the typed letters still
+/// match listed under it
+pub fn draw_move_by_name_popup(f: &mut Frame, app: &App, size: Rect) {
+    let candidates = match app.match_destination_column(&app.input_text, false) {
+        ColumnMatch::Unique(idx) => vec![idx],
+        ColumnMatch::Ambiguous(candidates) => candidates,
+        ColumnMatch::NoMatch => Vec::new(),
+    };
+    let height = std::cmp::min(candidates.len() as u16, 10).max(1) + 4;
+    draw_input_popup(
+        f,
+        app,
+        size,
+        "Move Task to Column (Tab to complete)",
+        INPUT_POPUP_PCT,
+        height,
+    );
+
+    let popup_area = popup_rect(INPUT_POPUP_PCT, height, size);
+    let list_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 3,
+        popup_area.width.saturating_sub(2),
+        popup_area.height.saturating_sub(4),
+    );
+    let items: Vec<ListItem> = if candidates.is_empty() {
+        vec![ListItem::new("No matching column").style(Style::default().fg(Color::DarkGray))]
+    } else {
+        candidates
+            .iter()
+            .map(|&idx| ListItem::new(app.columns[idx].title.clone()))
+            .collect()
+    };
+    f.render_widget(List::new(items), list_area);
+}
+
 /// Draw the confirmation popup for deleting a column
 pub fn draw_confirm_delete_column(f: &mut Frame, app: &App, size: Rect) {
-    let popup_width = 50;
-    let popup_height = 3;
-
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(popup_width)) / 2,
-        (size.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(size.width),
-        popup_height.min(size.height),
+    let column_name = app
+        .columns
+        .get(app.active_column)
+        .map(|col| col.title.as_str())
+        .unwrap_or("");
+
+    draw_confirm_popup(
+        f,
+        size,
+        "Confirm Delete Column",
+        &format!("Delete column '{}' ? (y/n)", column_name),
     );
+}
+
+/// Draw the confirmation popup for removing every task from the active column
+pub fn draw_confirm_clear_column(f: &mut Frame, app: &App, size: Rect) {
+    let (column_name, task_count) = app
+        .columns
+        .get(app.active_column)
+        .map(|col| (col.title.as_str(), col.tasks.len()))
+        .unwrap_or(("", 0));
+
+    draw_confirm_popup(
+        f,
+        size,
+        "Confirm Clear Column",
+        &format!(
+            "Remove all {} task(s) from '{}' ? (y/n)",
+            task_count, column_name
+        ),
+    );
+}
+
+/// Draw the confirmation popup for deleting the selected task
+pub fn draw_confirm_delete_task(f: &mut Frame, app: &App, size: Rect) {
+    let task_title = app.selected_task_title().unwrap_or("");
+
+    draw_confirm_popup(
+        f,
+        size,
+        "Confirm Delete Task",
+        &format!("Delete task '{}' ? (y/n)", task_title),
+    );
+}
+
+/// Draw the confirmation popup for reverting to the backup file
+pub fn draw_confirm_revert(f: &mut Frame, size: Rect) {
+    draw_confirm_popup(
+        f,
+        size,
+        "Confirm Revert",
+        "Revert to the previously saved version? (y/n)",
+    );
+}
+
+/// Ask whether to replay edits from a journal the last session left behind
+pub fn draw_confirm_replay_journal(f: &mut Frame, size: Rect) {
+    draw_confirm_popup(
+        f,
+        size,
+        "Unsaved Edits Found",
+        "Replay edits from the journal that were never saved? (y/n)",
+    );
+}
+
+/// Draw the read-only popup describing which file backs the current board
+pub fn draw_board_info_popup(f: &mut Frame, app: &App, size: Rect) {
+    let lines = board_info_popup_lines(app, size);
+    let scroll = app.info_scroll.min(max_info_scroll(app, size));
+
+    draw_text_popup(f, size, "Board Info", &lines[scroll..]);
+}
+
+/// Everything the info popup shows on a terminal of `size`: where the
+/// board lives, then its description, wrapped to the popup's width
+pub fn board_info_popup_lines(app: &App, size: Rect) -> Vec<String> {
+    let kanban_dir = std::env::var("KANBAN_DIR").ok();
+    let mut lines = app.board_info_lines(kanban_dir.as_deref());
+    if !app.description.trim().is_empty() {
+        lines.push(String::new());
+        lines.push("Description:".to_string());
+        lines.push(app.description.clone());
+    }
+    wrap_text(&lines.join("\n"), text_popup_width(size))
+}
+
+/// Every key of the board view with what it does, shown by the key help
+/// popup. The status line only names a few of them.
+const NORMAL_MODE_KEYS: [(&str, &str); 51] = [
+    ("h / l", "previous / next column (Tab / Shift+Tab too)"),
+    ("j / k", "next / previous task"),
+    ("Enter", "open the selected task"),
+    ("gc / gt", "jump to a column / a task"),
+    ("gn", "jump to the first column with tasks"),
+    ("go", "open the task's URL"),
+    ("#", "jump to a task by ID"),
+    ("m", "move the task to another column"),
+    ("M", "move the task by column name"),
+    ("J / K", "move the task down / up"),
+    ("B", "move the task to the emptiest column"),
+    ("T", "send the task to another board"),
+    ("S", "split the task"),
+    ("at / Insert", "add a task"),
+    ("ac", "add a column"),
+    ("ab / aa", "insert a column before / after this one"),
+    ("ad", "duplicate the column"),
+    ("rt / rc", "rename the task / the column"),
+    ("dt / Delete", "delete the task"),
+    ("dc", "delete the column"),
+    ("dx", "clear the column"),
+    ("wl", "set or clear the column's WIP limit"),
+    ("pi / pu / pf", "edit impact / urgency / effort"),
+    ("sc / sb", "sort the column / board by priority"),
+    ("sd", "flip the sort direction"),
+    ("za", "fold the column to the selected priority and above"),
+    ("zh / zl", "scroll the columns"),
+    ("ze", "hide / show empty columns"),
+    ("zc", "one line per task on / off"),
+    ("/", "filter tasks"),
+    ("G", "group tasks by tag"),
+    ("P", "list tasks by priority"),
+    ("yc / yp", "copy the column (with priority)"),
+    ("C", "import tasks from CSV"),
+    ("X", "open the trash"),
+    ("F", "start / stop a focus timer"),
+    ("D", "finished tasks"),
+    ("i", "board info (was ? before the key help)"),
+    ("I", "board stats"),
+    ("u", "undo"),
+    ("Ctrl+S", "save"),
+    ("W", "save all boards"),
+    ("R", "revert to the version before the last Ctrl+S"),
+    ("b", "pick another board"),
+    ("Ctrl+6", "back to the previous board"),
+    ("?", "this help"),
+    ("q", "quit"),
+    ("", ""),
+    ("In the tag view", ""),
+    ("h / l", "previous / next tag"),
+    ("j / k", "next / previous task with the tag"),
+];
+
+/// Draw the list of every key, scrolled like the board info popup
+pub fn draw_key_help_popup(f: &mut Frame, app: &App, size: Rect) {
+    let lines = key_help_lines(text_popup_width(size));
+    let scroll = app.info_scroll.min(max_info_scroll(app, size));
+
+    draw_text_popup(f, size, "Keys", &lines[scroll..]);
+}
+
+/// Furthest the info or key help popup can scroll on a terminal of `size`:
+/// the first line shown once the last one is at the bottom of the popup
+pub fn max_info_scroll(app: &App, size: Rect) -> usize {
+    let line_count = match app.input_mode {
+        InputMode::KeyHelp => key_help_lines(text_popup_width(size)).len(),
+        _ => board_info_popup_lines(app, size).len(),
+    };
+    line_count.saturating_sub(text_popup_rows(size))
+}
+
+/// The key help popup's lines, keys lined up in a column and actions
+/// wrapped to `width` under the start of the action
+pub fn key_help_lines(width: usize) -> Vec<String> {
+    // Headings have no action and don't count towards the key column
+    let key_width = NORMAL_MODE_KEYS
+        .iter()
+        .filter(|(_, action)| !action.is_empty())
+        .map(|(key, _)| key.len())
+        .max()
+        .unwrap_or(0);
+    let indent = key_width + 4;
+    NORMAL_MODE_KEYS
+        .iter()
+        .flat_map(|(key, action)| match *action {
+            "" => vec![key.to_string()],
+            action => wrap_text(action, width.saturating_sub(indent))
+                .into_iter()
+                .enumerate()
+                .map(|(idx, line)| match idx {
+                    0 => format!("  {:width$}  {}", key, line, width = key_width),
+                    _ => format!("{:indent$}{}", "", line),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Draw the selected task's details, wrapped to the popup's width
+pub fn draw_task_detail_popup(f: &mut Frame, app: &App, size: Rect) {
+    let lines = wrap_text(&app.task_detail_lines().join("\n"), text_popup_width(size));
+    draw_text_popup(f, size, "Task", &lines);
+}
+
+/// Draw the statistics computed when the popup was opened
+pub fn draw_board_stats_popup(f: &mut Frame, app: &App, size: Rect) {
+    let lines = app
+        .board_stats
+        .as_ref()
+        .map(|stats| stats.lines())
+        .unwrap_or_default();
+
+    draw_text_popup(f, size, "Board Stats", &lines);
+}
+
+/// Draw the trash viewer: deleted tasks and columns, newest first
+pub fn draw_trash_popup(f: &mut Frame, app: &App, size: Rect) {
+    let popup_height = std::cmp::min(app.trash.len() as u16 + 4, 20);
+    let popup_area = popup_rect(TEXT_POPUP_PCT, popup_height, size);
 
     f.render_widget(Clear, popup_area);
 
     let popup_block = Block::default()
-        .title("Confirm Delete Column")
+        .title(format!(
+            "Trash (kept {} days)",
+            app.config.trash_retention_days
+        ))
         .borders(Borders::ALL)
         .style(Style::default().bg(Color::Rgb(38, 38, 38))); // #262626 for popup bg
 
     f.render_widget(&popup_block, popup_area);
 
     let inner = popup_block.inner(popup_area);
+    let list_height = inner.height.saturating_sub(1);
 
+    let items: Vec<ListItem> = if app.trash.is_empty() {
+        vec![ListItem::new("The trash is empty").style(Style::default().fg(Color::DarkGray))]
+    } else {
+        app.trash
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let style = if i == app.selected_trash_index {
+                    Style::default()
+                        .fg(Color::White)
+                        .bg(Color::Rgb(82, 82, 82)) // #525252 for selection
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(entry.label()).style(style)
+            })
+            .collect()
+    };
+    let list = List::new(items).block(Block::default());
+    f.render_widget(list, Rect::new(inner.x, inner.y, inner.width, list_height));
+
+    let help = Paragraph::new(
+        app.status_message
+            .as_deref()
+            .unwrap_or("Enter: Restore | Esc: Close"),
+    )
+    .style(Style::default().fg(Color::DarkGray))
+    .alignment(Alignment::Center);
+    f.render_widget(
+        help,
+        Rect::new(inner.x, inner.y + list_height, inner.width, 1),
+    );
+}
+
+/// Draw the first-run help shown over the board picker
+pub fn draw_onboarding_popup(f: &mut Frame, size: Rect) {
+    let kanban_dir = std::env::var("KANBAN_DIR").ok();
+    draw_text_popup(
+        f,
+        size,
+        "Welcome to tkan",
+        &onboarding_lines(kanban_dir.as_deref()),
+    );
+}
+
+/// The first-run help: where boards are kept, how to make one and the keys
+/// needed to get going
+pub fn onboarding_lines(kanban_dir: Option<&str>) -> Vec<String> {
+    let mut lines = vec![match kanban_dir {
+        Some(dir) => format!("Boards are saved as text files in {}", dir),
+        None => "KANBAN_DIR is not set, so boards can't be saved.".to_string(),
+    }];
+    if kanban_dir.is_none() {
+        lines.push("Set it to a folder for your boards and restart.".to_string());
+    }
+    lines.extend(
+        [
+            "",
+            "Pick [Create New Board] and press Enter to make your first board.",
+            "",
+            "Once it's open:",
+            "  ac / at    add a column / a task",
+            "  h j k l    move between columns and tasks",
+            "  m          move the selected task to another column",
+            "  u          undo",
+            "  b          back to this board list",
+            "  q          quit",
+        ]
+        .map(String::from),
+    );
+    lines
+}
+
+/// Draw the list of finished tasks, in the order they were completed
+pub fn draw_done_summary_popup(f: &mut Frame, app: &App, size: Rect) {
+    let mut lines = app.done_summary_lines();
+    if lines.is_empty() {
+        lines.push("Nothing finished yet".to_string());
+    }
+
+    draw_text_popup(f, size, "Done", &lines);
+}
+
+// Share of the terminal's width taken by text, input and list popups
+const TEXT_POPUP_PCT: u16 = 90;
+const INPUT_POPUP_PCT: u16 = 90;
+const LIST_POPUP_PCT: u16 = 60;
+const BOARD_POPUP_PCT: u16 = 75;
+
+// Columns of text a text popup has room for: its width inside the borders
+fn text_popup_width(size: Rect) -> usize {
+    popup_rect(TEXT_POPUP_PCT, 0, size).width.saturating_sub(2) as usize
+}
+
+// Lines of text a text popup has room for: the terminal minus the borders,
+// the blank line and the "Press any key" hint
+fn text_popup_rows(size: Rect) -> usize {
+    size.height.saturating_sub(4) as usize
+}
+
+/// Draw a read-only popup showing some lines of text, closed by any key
+fn draw_text_popup(f: &mut Frame, size: Rect, title: &str, lines: &[String]) {
+    let popup_height = lines.len() as u16 + 4;
+    let popup_area = popup_rect(TEXT_POPUP_PCT, popup_height, size);
+
+    f.render_widget(Clear, popup_area);
+
+    let popup_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Color::Rgb(38, 38, 38))); // #262626 for popup bg
+
+    f.render_widget(&popup_block, popup_area);
+
+    let inner = popup_block.inner(popup_area);
+
+    let mut text = lines.join("\n");
+    text.push_str("\n\nPress any key to close");
+
+    let info = Paragraph::new(text)
+        .style(Style::default().bg(Color::Rgb(38, 38, 38))) // #262626 for text bg
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(info, inner);
+}
+
+/// Draw the confirmation popup for moving a task into a full column
+pub fn draw_confirm_wip_override(f: &mut Frame, app: &App, size: Rect, target: usize) {
+    let Some(column) = app.columns.get(target) else {
+        return;
+    };
+    let limit = column.wip_limit.unwrap_or(column.tasks.len());
+
+    draw_confirm_popup(
+        f,
+        size,
+        "WIP Limit Reached",
+        &format!(
+            "{} is at its WIP limit of {}. Move anyway? y/n",
+            column.title, limit
+        ),
+    );
+}
+
+/// Draw the confirmation popup for reloading a board changed on disk
+pub fn draw_confirm_reload(f: &mut Frame, size: Rect) {
+    draw_confirm_popup(
+        f,
+        size,
+        "Board Changed on Disk",
+        "Reload and discard unsaved changes? (y/n)",
+    );
+}
+
+/// Draw the confirmation popup for quitting with unsaved changes
+pub fn draw_confirm_quit(f: &mut Frame, app: &App, size: Rect) {
+    draw_confirm_popup(f, size, "Unsaved Changes", &confirm_quit_message(app));
+}
+
+/// The quit prompt, summarizing what would be lost
+pub fn confirm_quit_message(app: &App) -> String {
+    let summary = app.unsaved_summary();
+    if summary.is_empty() {
+        "Some changes couldn't be saved. Quit anyway? (y/n)".to_string()
+    } else {
+        format!("{}. Quit anyway? (y/n)", summary.join("; "))
+    }
+}
+
+/// Draw the confirmation popup for merging two same-named columns
+pub fn draw_confirm_merge_columns(f: &mut Frame, app: &App, size: Rect, first: usize) {
     let column_name = app
         .columns
-        .get(app.active_column)
+        .get(first)
         .map(|col| col.title.as_str())
         .unwrap_or("");
 
-    let text = Paragraph::new(format!("Delete column '{}' ? (y/n)", column_name))
-        .style(Style::default().fg(Color::Red).bg(Color::Rgb(38, 38, 38))) // #262626 for text bg
-        .alignment(Alignment::Center);
-
-    f.render_widget(text, inner);
+    draw_confirm_popup(
+        f,
+        size,
+        "Duplicate Columns",
+        &format!("Merge the two '{}' columns? (y/n)", column_name),
+    );
 }
 
-/// Draw the column selection popup for moving tasks
-pub fn draw_column_selection_popup(f: &mut Frame, app: &App, size: Rect) {
-    let popup_width = 50;
-    let popup_height = std::cmp::min(app.columns.len() as u16 + 4, 15); // Max height of 15
-
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(popup_width)) / 2,
-        (size.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(size.width),
-        popup_height.min(size.height),
-    );
+/// Draw a one-line y/n confirmation popup
+fn draw_confirm_popup(f: &mut Frame, size: Rect, title: &str, message: &str) {
+    let popup_area = confirm_popup_rect(message, size);
 
     f.render_widget(Clear, popup_area);
 
     let popup_block = Block::default()
-        .title("Move Task to Column")
+        .title(title)
         .borders(Borders::ALL)
         .style(Style::default().bg(Color::Rgb(38, 38, 38))); // #262626 for popup bg
 
@@ -105,27 +548,71 @@ pub fn draw_column_selection_popup(f: &mut Frame, app: &App, size: Rect) {
 
     let inner = popup_block.inner(popup_area);
 
-    // Calculate the available height for the column list
-    let list_height = inner.height.saturating_sub(2);
+    let text = Paragraph::new(message.to_string())
+        .style(Style::default().fg(Color::Red).bg(Color::Rgb(38, 38, 38))) // #262626 for text bg
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
 
-    // Create a list of columns with their indices, starting from 1
-    let list_items: Vec<ListItem> = app
-        .columns
+    f.render_widget(text, inner);
+}
+
+/// Area of a confirmation popup: wide enough for its message on one line
+/// where the terminal allows, otherwise as wide as the terminal and tall
+/// enough for the wrapped message
+fn confirm_popup_rect(message: &str, size: Rect) -> Rect {
+    let width = (display_width(message) as u16 + 4).max(50).min(size.width);
+    let inner_width = usize::from(width.saturating_sub(2)).max(1);
+    let rows = wrap_text(message, inner_width).len().max(1) as u16;
+    center_rect(width, rows + 2, size)
+}
+
+/// One line of the move/jump popups: the 1-based index, title and task count
+fn column_popup_line(index: usize, column: &Column) -> String {
+    format!("{}: {} ({})", index + 1, column.title, column.tasks.len())
+}
+
+/// The numbered column list shared by the move and jump popups. The active
+/// column is yellow and columns at their WIP limit are red.
+fn column_list_items(app: &App) -> Vec<ListItem<'static>> {
+    app.columns
         .iter()
         .enumerate()
         .map(|(i, col)| {
-            // Format column index starting from 1
-            let text = format!("{}: {}", i + 1, col.title);
-
-            ListItem::new(text).style(if i == app.active_column {
+            let style = if app.full_column_limit(i).is_some() {
+                Style::default().fg(Color::Red).bg(Color::Rgb(38, 38, 38))
+            } else if i == app.active_column {
                 Style::default()
                     .fg(Color::Yellow)
                     .bg(Color::Rgb(38, 38, 38))
             } else {
                 Style::default().bg(Color::Rgb(38, 38, 38))
-            })
+            };
+
+            ListItem::new(column_popup_line(i, col)).style(style)
         })
-        .collect();
+        .collect()
+}
+
+/// Draw the column selection popup for moving tasks
+pub fn draw_column_selection_popup(f: &mut Frame, app: &App, size: Rect) {
+    let popup_height = std::cmp::min(app.columns.len() as u16 + 4, 15); // Max height of 15
+    let popup_area = popup_rect(LIST_POPUP_PCT, popup_height, size);
+
+    f.render_widget(Clear, popup_area);
+
+    let popup_block = Block::default()
+        .title("Move Task to Column")
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Color::Rgb(38, 38, 38))); // #262626 for popup bg
+
+    f.render_widget(&popup_block, popup_area);
+
+    let inner = popup_block.inner(popup_area);
+
+    // Calculate the available height for the column list
+    let list_height = inner.height.saturating_sub(2);
+
+    let list_items = column_list_items(app);
 
     let columns_list = List::new(list_items)
         .block(Block::default().style(Style::default().bg(Color::Rgb(38, 38, 38))));
@@ -146,15 +633,8 @@ pub fn draw_column_selection_popup(f: &mut Frame, app: &App, size: Rect) {
 
 /// Draw the jump column popup
 pub fn draw_jump_column_popup(f: &mut Frame, app: &App, size: Rect) {
-    let popup_width = 50;
     let popup_height = std::cmp::min(app.columns.len() as u16 + 4, 15); // Max height of 15
-
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(popup_width)) / 2,
-        (size.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(size.width),
-        popup_height.min(size.height),
-    );
+    let popup_area = popup_rect(LIST_POPUP_PCT, popup_height, size);
 
     f.render_widget(Clear, popup_area);
 
@@ -170,24 +650,7 @@ pub fn draw_jump_column_popup(f: &mut Frame, app: &App, size: Rect) {
     // Calculate the available height for the column list
     let list_height = inner.height.saturating_sub(2);
 
-    // Create a list of columns with their indices, starting from 1
-    let list_items: Vec<ListItem> = app
-        .columns
-        .iter()
-        .enumerate()
-        .map(|(i, col)| {
-            // Format column index starting from 1
-            let text = format!("{}: {}", i + 1, col.title);
-
-            ListItem::new(text).style(if i == app.active_column {
-                Style::default()
-                    .fg(Color::Yellow)
-                    .bg(Color::Rgb(38, 38, 38))
-            } else {
-                Style::default().bg(Color::Rgb(38, 38, 38))
-            })
-        })
-        .collect();
+    let list_items = column_list_items(app);
 
     let columns_list = List::new(list_items)
         .block(Block::default().style(Style::default().bg(Color::Rgb(38, 38, 38))));
@@ -215,15 +678,9 @@ pub fn draw_board_selection(f: &mut Frame, app: &App, size: Rect) {
     f.render_widget(background, size);
 
     // Create a centered popup
-    let popup_width = 60;
-    let popup_height = std::cmp::min(20, app.available_boards.len() as u16 + 6);
-
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(popup_width)) / 2,
-        (size.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(size.width),
-        popup_height.min(size.height),
-    );
+    let entries = app.board_entries();
+    let popup_height = std::cmp::min(20, entries.len() as u16 + 6);
+    let popup_area = popup_rect(BOARD_POPUP_PCT, popup_height, size);
 
     // Create popup block
     let popup_block = Block::default()
@@ -258,44 +715,41 @@ pub fn draw_board_selection(f: &mut Frame, app: &App, size: Rect) {
     f.render_widget(desc_text, popup_chunks[0]);
 
     // Render the list of boards
-    if !app.available_boards.is_empty() {
-        let board_items: Vec<ListItem> = app
-            .available_boards
-            .iter()
-            .enumerate()
-            .map(|(i, board_name)| {
-                let style = if app.selected_board_index == Some(i) {
-                    Style::default()
-                        .fg(Color::White)
-                        .bg(Color::Rgb(82, 82, 82)) // #525252 for selection
-                        .add_modifier(Modifier::BOLD)
-                } else if i == app.available_boards.len() - 1 {
-                    // Special style for "Create New Board" option
-                    Style::default().fg(Color::Green).bg(Color::Rgb(38, 38, 38)) // #262626 for bg
-                } else {
-                    Style::default().bg(Color::Rgb(38, 38, 38)) // #262626 for bg
-                };
-
-                // Special formatting for "Create New Board" option
-                let text = if i == app.available_boards.len() - 1 {
-                    format!("➕ {}", board_name)
-                } else {
-                    format!("📋 {}", board_name)
-                };
+    let board_items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let style = if app.selected_board_index == Some(i) {
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Rgb(82, 82, 82)) // #525252 for selection
+                    .add_modifier(Modifier::BOLD)
+            } else if *entry == BoardEntry::CreateNew {
+                // Special style for "Create New Board" option
+                Style::default().fg(Color::Green).bg(Color::Rgb(38, 38, 38)) // #262626 for bg
+            } else {
+                Style::default().bg(Color::Rgb(38, 38, 38)) // #262626 for bg
+            };
 
-                ListItem::new(text).style(style)
-            })
-            .collect();
+            ListItem::new(board_entry_text(entry, app.config.ascii_only)).style(style)
+        })
+        .collect();
 
-        let boards_list = List::new(board_items)
-            .block(Block::default().style(Style::default().bg(Color::Rgb(38, 38, 38)))) // #262626 for block bg
-            .highlight_style(Style::default().bg(Color::Rgb(82, 82, 82)).fg(Color::White)); // #525252 for highlight
+    let boards_list = List::new(board_items)
+        .block(Block::default().style(Style::default().bg(Color::Rgb(38, 38, 38)))) // #262626 for block bg
+        .highlight_style(Style::default().bg(Color::Rgb(82, 82, 82)).fg(Color::White)); // #525252 for highlight
 
-        f.render_widget(boards_list, popup_chunks[1]);
-    }
+    f.render_widget(boards_list, popup_chunks[1]);
 
     // Render help text
-    let help_text = "↑↓: Navigate | Enter: Select | Esc: Quit";
+    let help_text = app
+        .status_message
+        .as_deref()
+        .unwrap_or(if app.config.ascii_only {
+            "Up/Down: Navigate | J/K: Reorder | Enter: Select | Esc: Quit"
+        } else {
+            "↑↓: Navigate | J/K: Reorder | Enter: Select | Esc: Quit"
+        });
     let help = Paragraph::new(help_text)
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
@@ -303,6 +757,17 @@ pub fn draw_board_selection(f: &mut Frame, app: &App, size: Rect) {
     f.render_widget(help, popup_chunks[2]);
 }
 
+/// A line of the board picker, with an icon marking boards and the create
+/// option. `ascii_only` swaps the emoji for bracketed ASCII.
+fn board_entry_text(entry: &BoardEntry, ascii_only: bool) -> String {
+    match (entry, ascii_only) {
+        (BoardEntry::Board(board_name), false) => format!("📋 {}", board_name),
+        (BoardEntry::Board(board_name), true) => format!("[B] {}", board_name),
+        (BoardEntry::CreateNew, false) => format!("➕ {}", CREATE_NEW_BOARD_LABEL),
+        (BoardEntry::CreateNew, true) => format!("[+] {}", CREATE_NEW_BOARD_LABEL),
+    }
+}
+
 /// Draw the new board creation popup
 pub fn draw_new_board_popup(f: &mut Frame, app: &App, size: Rect) {
     // Set background color
@@ -311,15 +776,7 @@ pub fn draw_new_board_popup(f: &mut Frame, app: &App, size: Rect) {
         .borders(Borders::NONE);
     f.render_widget(background, size);
 
-    let popup_width = 60;
-    let popup_height = 5;
-
-    let popup_area = Rect::new(
-        (size.width.saturating_sub(popup_width)) / 2,
-        (size.height.saturating_sub(popup_height)) / 2,
-        popup_width.min(size.width),
-        popup_height.min(size.height),
-    );
+    let popup_area = popup_rect(BOARD_POPUP_PCT, 5, size);
 
     // Clear the area first to ensure clean rendering
     f.render_widget(Clear, popup_area);
@@ -337,7 +794,7 @@ pub fn draw_new_board_popup(f: &mut Frame, app: &App, size: Rect) {
         .style(Style::default().bg(Color::Rgb(38, 38, 38))) // #262626 for input background
         .block(
             Block::default()
-                .title("Enter board name:")
+                .title("Enter board name (use \"category / name\" for a folder):")
                 .style(Style::default().bg(Color::Rgb(38, 38, 38))),
         ) // #262626 for input block
         .wrap(Wrap { trim: true });
@@ -346,7 +803,7 @@ pub fn draw_new_board_popup(f: &mut Frame, app: &App, size: Rect) {
 
     // Position cursor for typing
     f.set_cursor_position(Position {
-        x: input_area.x + app.input_text.len() as u16,
+        x: input_area.x + input_cursor_offset(&app.input_text),
         y: input_area.y + 1, // +1 to account for the title
     });
 }
@@ -364,25 +821,272 @@ pub fn draw_popup(f: &mut Frame, app: &App, size: Rect) {
             draw_column_selection_popup(f, app, size);
         }
         InputMode::AddingColumn => {
-            draw_input_popup(f, app, size, "New Column", 70, 5);
+            draw_input_popup(f, app, size, "New Column", INPUT_POPUP_PCT, 5);
+        }
+        InputMode::InsertingColumn(_) => {
+            draw_input_popup(f, app, size, "Insert Column", INPUT_POPUP_PCT, 5);
         }
         InputMode::AddingTask => {
-            draw_input_popup(f, app, size, "New Task", 70, 5);
+            draw_input_popup(f, app, size, "New Task", INPUT_POPUP_PCT, 5);
         }
         InputMode::RenamingColumn => {
-            draw_input_popup(f, app, size, "Rename Column", 70, 5);
+            draw_input_popup(f, app, size, "Rename Column", INPUT_POPUP_PCT, 5);
         }
         InputMode::RenamingTask => {
-            draw_input_popup(f, app, size, "Rename Task", 70, 5);
+            draw_input_popup(f, app, size, "Rename Task", INPUT_POPUP_PCT, 5);
+        }
+        InputMode::JumpToTaskId => {
+            draw_input_popup(f, app, size, "Jump to Task ID", 50, 5);
+        }
+        InputMode::ImportingCsv => {
+            draw_input_popup(
+                f,
+                app,
+                size,
+                "Import CSV (path, or path: column)",
+                INPUT_POPUP_PCT,
+                5,
+            );
+        }
+        InputMode::SplittingTask => {
+            draw_input_popup(f, app, size, "Split Off New Task", INPUT_POPUP_PCT, 5);
+        }
+        InputMode::SendingTaskToBoard => {
+            draw_input_popup(
+                f,
+                app,
+                size,
+                "Send Task To (board: column)",
+                INPUT_POPUP_PCT,
+                5,
+            );
+        }
+        InputMode::Filtering => {
+            draw_input_popup(
+                f,
+                app,
+                size,
+                "Filter (tag:x priority>n priority<n due:today done, - to negate)",
+                INPUT_POPUP_PCT,
+                5,
+            );
+        }
+        InputMode::ConfirmReload => {
+            draw_confirm_reload(f, size);
+        }
+        InputMode::ConfirmQuit(_) => {
+            draw_confirm_quit(f, app, size);
+        }
+        InputMode::TrashViewer => {
+            draw_trash_popup(f, app, size);
+        }
+        InputMode::ConfirmMergeColumns(first, _) => {
+            draw_confirm_merge_columns(f, app, size, first);
+        }
+        InputMode::ConfirmReplayJournal => {
+            draw_confirm_replay_journal(f, size);
+        }
+        InputMode::ConfirmDeleteTask => {
+            draw_confirm_delete_task(f, app, size);
         }
         InputMode::ConfirmDeleteColumn => {
             draw_confirm_delete_column(f, app, size);
         }
+        InputMode::ConfirmClearColumn => {
+            draw_confirm_clear_column(f, app, size);
+        }
+        InputMode::ConfirmRevert => {
+            draw_confirm_revert(f, size);
+        }
         InputMode::JumpToColumnMode => {
             draw_jump_column_popup(f, app, size);
         }
+        InputMode::MovingTaskByName => {
+            draw_move_by_name_popup(f, app, size);
+        }
+        InputMode::EditingWipLimit => {
+            draw_input_popup(f, app, size, "WIP limit (empty for none)", 40, 3);
+        }
+        InputMode::EditingPriority(field) => {
+            let title = format!("{} (0-{})", field.label(), MAX_PRIORITY_VALUE);
+            draw_input_popup(f, app, size, &title, 40, 3);
+        }
+        InputMode::ConfirmWipOverride(target) => {
+            draw_confirm_wip_override(f, app, size, target);
+        }
+        InputMode::DoneSummary => {
+            draw_done_summary_popup(f, app, size);
+        }
+        InputMode::BoardInfo => {
+            draw_board_info_popup(f, app, size);
+        }
+        InputMode::KeyHelp => {
+            draw_key_help_popup(f, app, size);
+        }
+        InputMode::BoardStats => {
+            draw_board_stats_popup(f, app, size);
+        }
+        InputMode::TaskDetail => {
+            draw_task_detail_popup(f, app, size);
+        }
         InputMode::Normal | InputMode::MoveMode | InputMode::JumpToTaskMode => {
             // No popups for these modes
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::Task;
+
+    #[test]
+    fn test_key_help_lines_up_keys() {
+        let lines = key_help_lines(80);
+        assert!(lines.contains(&"  ?             this help".to_string()));
+        assert!(
+            lines.contains(&"  i             board info (was ? before the key help)".to_string())
+        );
+        // Every key sits in the same column, so the actions line up
+        let action_starts: Vec<usize> = NORMAL_MODE_KEYS
+            .iter()
+            .zip(&lines)
+            .filter(|((_, action), _)| !action.is_empty())
+            .map(|((_, action), line)| line.find(action).unwrap())
+            .collect();
+        assert!(action_starts.iter().all(|&start| start == action_starts[0]));
+    }
+
+    #[test]
+    fn test_text_popups_wrap_to_the_popup_width() {
+        // A 40-column terminal leaves 34 columns inside the popup
+        let size = Rect::new(0, 0, 40, 24);
+        assert_eq!(text_popup_width(size), 34);
+
+        let lines = key_help_lines(34);
+        assert!(lines.iter().all(|line| display_width(line) <= 34));
+        // Wrapped actions carry on under the start of the action
+        let za = lines.iter().position(|line| line.contains("za ")).unwrap();
+        assert!(lines[za + 1].starts_with(&" ".repeat(16)));
+
+        let mut app = App::new("Test Board");
+        app.description = "word ".repeat(40);
+        let lines = board_info_popup_lines(&app, size);
+        assert!(lines.iter().all(|line| display_width(line) <= 34));
+        // The scroll limit counts the wrapped lines
+        app.input_mode = InputMode::BoardInfo;
+        assert_eq!(max_info_scroll(&app, size), lines.len().saturating_sub(20));
+    }
+
+    #[test]
+    fn test_info_scroll_stops_with_the_last_line_at_the_bottom() {
+        let mut app = App::new("Test Board");
+        app.input_mode = InputMode::KeyHelp;
+        // 20 rows leave 16 for text, so the key list scrolls until its
+        // last 16 lines are showing
+        let size = Rect::new(0, 0, 80, 20);
+        assert_eq!(
+            max_info_scroll(&app, size),
+            key_help_lines(text_popup_width(size)).len() - 16
+        );
+        // A terminal taller than the list doesn't scroll at all
+        assert_eq!(max_info_scroll(&app, Rect::new(0, 0, 80, 200)), 0);
+    }
+
+    #[test]
+    fn test_centered_rect() {
+        let size = Rect::new(0, 0, 80, 24);
+        assert_eq!(centered_rect(50, 50, size), Rect::new(20, 6, 40, 12));
+        assert_eq!(centered_rect(100, 100, size), size);
+        assert_eq!(centered_rect(150, 0, size), Rect::new(0, 12, 80, 0));
+
+        // Odd leftovers go below and to the right; offsets follow `size`
+        let size = Rect::new(10, 5, 41, 11);
+        assert_eq!(centered_rect(50, 50, size), Rect::new(20, 8, 20, 5));
+    }
+
+    #[test]
+    fn test_popup_rect_scales_with_terminal() {
+        // An 80-column terminal gives the input popups most of its width
+        let size = Rect::new(0, 0, 80, 24);
+        assert_eq!(popup_rect(INPUT_POPUP_PCT, 5, size), Rect::new(4, 9, 72, 5));
+
+        // A 40-column one shrinks them, but not below the minimum width
+        let size = Rect::new(0, 0, 40, 24);
+        assert_eq!(popup_rect(INPUT_POPUP_PCT, 5, size).width, 36);
+        assert_eq!(popup_rect(10, 5, size).width, MIN_POPUP_WIDTH);
+
+        // Wide terminals stop at the maximum width
+        let size = Rect::new(0, 0, 300, 50);
+        assert_eq!(popup_rect(INPUT_POPUP_PCT, 5, size).width, MAX_POPUP_WIDTH);
+
+        // A terminal smaller than the popup gets all of itself
+        let size = Rect::new(0, 0, 20, 3);
+        assert_eq!(popup_rect(INPUT_POPUP_PCT, 5, size), size);
+    }
+
+    #[test]
+    fn test_confirm_popup_wraps_on_narrow_terminals() {
+        let message = "Revert to the previously saved version? (y/n)";
+        let wide = confirm_popup_rect(message, Rect::new(0, 0, 80, 24));
+        assert_eq!((wide.width, wide.height), (50, 3));
+
+        let narrow = confirm_popup_rect(message, Rect::new(0, 0, 30, 24));
+        assert_eq!(narrow.width, 30);
+        assert!(narrow.height > 3);
+    }
+
+    #[test]
+    fn test_input_cursor_offset_uses_display_width() {
+        assert_eq!(input_cursor_offset("Done"), 4);
+        // The coffee cup takes two columns
+        assert_eq!(input_cursor_offset("Café ☕"), 7);
+        assert_eq!(input_cursor_offset("Do\u{200B}ne"), 4);
+    }
+
+    #[test]
+    fn test_ascii_board_entries_have_no_emoji() {
+        let boards = ["Work".to_string(), "home / Chores".to_string()];
+        let entries = [
+            BoardEntry::Board(&boards[0]),
+            BoardEntry::Board(&boards[1]),
+            BoardEntry::CreateNew,
+        ];
+
+        for entry in &entries {
+            assert!(board_entry_text(entry, true).is_ascii());
+            assert!(!board_entry_text(entry, false).is_ascii());
+        }
+        assert_eq!(board_entry_text(&entries[0], true), "[B] Work");
+        assert_eq!(
+            board_entry_text(&BoardEntry::CreateNew, true),
+            format!("[+] {}", CREATE_NEW_BOARD_LABEL)
+        );
+    }
+
+    #[test]
+    fn test_column_popup_line_includes_task_count() {
+        let mut column = Column {
+            title: "In Progress".to_string(),
+            tasks: Vec::new(),
+            selected_task: None,
+            wip_limit: None,
+        };
+        assert_eq!(column_popup_line(0, &column), "1: In Progress (0)");
+
+        for title in ["a", "b", "c", "d"] {
+            column.tasks.push(Task {
+                id: 0,
+                title: title.to_string(),
+                description: None,
+                priority: None,
+                completed_at: None,
+                tags: Vec::new(),
+                due: None,
+                created: None,
+                focus_minutes: 0,
+            });
+        }
+        assert_eq!(column_popup_line(1, &column), "2: In Progress (4)");
+    }
+}
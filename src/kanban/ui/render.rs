@@ -1,119 +1,313 @@
-use crate::kanban::models::{App, InputMode};
-use crate::kanban::ui::popups;
-use crate::kanban::ui::task_formatter::format_task_with_wrapping;
+use crate::kanban::config::{Config, ScrollMode};
+use crate::kanban::models::{App, Column, InputMode, PrioritizedTask};
+use crate::kanban::ui::task_formatter::{priority_dot, priority_legend};
+use crate::kanban::ui::{colors, popups};
 use ratatui::{
     Frame,
-    layout::{Alignment, Constraint, Direction, Layout, Position},
+    layout::{Alignment, Constraint, Direction, Layout, Position, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, LineGauge, List, ListItem, ListState, Paragraph, Wrap},
 };
+use std::time::Instant;
 
 const COLUMN_WIDTH: u16 = 50;
-const COLUMN_MARGIN: u16 = 2;
 
-/// Draws the overall UI including the title, columns, tasks and any popups.
-pub fn draw_ui(f: &mut Frame, app: &App) {
-    let size = f.area();
+// Narrowest a column gets before its margins are dropped to make room
+const MIN_COLUMN_WIDTH: u16 = 12;
 
-    // Set the background color for the entire app
-    // let background = Block::default()
-    //     .style(Style::default().bg(Color::Rgb(22, 22, 22))) // #161616
-    //     .borders(Borders::NONE);
-    // f.render_widget(background, size);
+// Guidance shown in place of tasks/columns on an empty board, with the
+// plain ASCII forms used when `ascii_only` is set
+const EMPTY_COLUMN_MESSAGE: &str = "No tasks — press at to add one";
+const EMPTY_COLUMN_MESSAGE_ASCII: &str = "No tasks - press at to add one";
+const EMPTY_BOARD_MESSAGE: &str = "This board has no columns — press ac to add one";
+const EMPTY_BOARD_MESSAGE_ASCII: &str = "This board has no columns - press ac to add one";
+const NO_MATCHES_MESSAGE: &str = "No tasks match the filter";
 
-    // Clear the terminal with our background color
-    f.render_widget(Clear, size); // First clear any existing content
-    let background = Block::default()
-        .style(Style::default().bg(Color::Rgb(22, 22, 22))) // #161616
-        .borders(Borders::NONE);
-    f.render_widget(background, size);
+// The status line in the board view: the core keys, with `?` for the rest
+const NORMAL_MODE_HELP: &str = "h/j/k/l to navigate | Enter to open task | at to add task | m to move task | / to filter | u to undo | ? for all keys | q to quit";
 
-    // Check if we need to show the board selection popup
-    match app.input_mode {
-        InputMode::BoardSelection => {
-            popups::draw_board_selection(f, app, size);
-            return;
-        }
-        InputMode::AddingBoard => {
-            popups::draw_new_board_popup(f, app, size);
-            return;
-        }
-        _ => {}
+/// The placeholder to show inside a column, if it has no tasks to draw
+fn empty_column_placeholder(
+    column: &Column,
+    visible_count: usize,
+    ascii_only: bool,
+) -> Option<&'static str> {
+    if column.tasks.is_empty() {
+        Some(if ascii_only {
+            EMPTY_COLUMN_MESSAGE_ASCII
+        } else {
+            EMPTY_COLUMN_MESSAGE
+        })
+    } else if visible_count == 0 {
+        Some(NO_MATCHES_MESSAGE)
+    } else {
+        None
     }
+}
 
-    // Render the title.
-    let title = Paragraph::new(app.title.clone())
-        .style(Style::default().fg(Color::Cyan))
-        .alignment(Alignment::Center)
-        .block(Block::default());
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
-        .split(size);
-    f.render_widget(title, chunks[0]);
+/// The board name for the title bar, with `[+]` while there are unsaved
+/// changes and the board's place among the available boards. That list is
+/// only rescanned when the board picker opens, so a board missing from it
+/// (created or renamed since) shows just the count, and with no list at all
+/// (no KANBAN_DIR) the count is left out.
+pub fn board_title_text(title: &str, dirty: bool, available_boards: &[String]) -> String {
+    let mut text = title.to_string();
+    if dirty {
+        text.push_str(" [+]");
+    }
+    let total = available_boards.len();
+    let noun = if total == 1 { "board" } else { "boards" };
+    match available_boards.iter().position(|name| name == title) {
+        Some(index) => text.push_str(&format!("  ({} of {} {})", index + 1, total, noun)),
+        None if total > 0 => text.push_str(&format!("  ({} {})", total, noun)),
+        None => {}
+    }
+    text
+}
 
-    // Determine layout for the columns.
-    let available_width = chunks[1].width;
-    let column_with_margin = COLUMN_WIDTH + (COLUMN_MARGIN * 2);
-    let max_visible_columns = (available_width / column_with_margin).max(1) as usize;
-    let start_idx = if app.columns.len() <= max_visible_columns {
-        0
-    } else if app.active_column >= app.scroll_offset + max_visible_columns {
-        app.active_column + 1 - max_visible_columns
-    } else if app.active_column < app.scroll_offset {
-        app.active_column
+/// The title shown above a column, with its `(i/total)` index if enabled
+fn column_title_text(column: &Column, column_idx: usize, total: usize, show_index: bool) -> String {
+    if show_index {
+        format!("{} ({}/{})", column.title, column_idx + 1, total)
     } else {
-        app.scroll_offset
-    };
-    let visible_columns = max_visible_columns.min(app.columns.len() - start_idx);
+        column.title.clone()
+    }
+}
 
-    // Create layout constraints for each column.
-    let mut column_constraints = Vec::new();
+/// Vertical split of the screen into the title bar and the board
+fn screen_constraints(config: &Config) -> [Constraint; 2] {
+    [Constraint::Length(config.title_height), Constraint::Min(0)]
+}
+
+/// Horizontal constraints for the visible columns, each flanked by
+/// `margin` blank cells, with any leftover width taken up at the end
+fn column_constraints(
+    visible_columns: usize,
+    margin: u16,
+    available_width: u16,
+) -> Vec<Constraint> {
+    let (column_width, margin) = column_size(available_width, margin);
+    let mut constraints = Vec::new();
     for _ in 0..visible_columns {
-        column_constraints.push(Constraint::Length(COLUMN_MARGIN)); // left margin
-        column_constraints.push(Constraint::Length(COLUMN_WIDTH)); // column
-        column_constraints.push(Constraint::Length(COLUMN_MARGIN)); // right margin
+        constraints.push(Constraint::Length(margin)); // left margin
+        constraints.push(Constraint::Length(column_width)); // column
+        constraints.push(Constraint::Length(margin)); // right margin
     }
-    if !column_constraints.is_empty()
-        && available_width > column_with_margin * (column_constraints.len() / 3) as u16
-    {
-        column_constraints.push(Constraint::Min(0));
+    if needs_filler(visible_columns, margin, available_width) {
+        constraints.push(Constraint::Min(0));
     }
+    constraints
+}
+
+/// Width of a column and of its margins on a board `available_width`
+/// cells wide. A terminal narrower than one full column shrinks the
+/// column to fit, down to `MIN_COLUMN_WIDTH`; narrower still, the margins
+/// go and the column takes the whole width.
+fn column_size(available_width: u16, margin: u16) -> (u16, u16) {
+    let width_inside_margins = available_width.saturating_sub(margin.saturating_mul(2));
+    if width_inside_margins >= MIN_COLUMN_WIDTH {
+        (COLUMN_WIDTH.min(width_inside_margins), margin)
+    } else {
+        (COLUMN_WIDTH.min(available_width), 0)
+    }
+}
+
+/// How many columns fit side by side in `width` cells (at least one)
+pub fn max_visible_columns(width: u16, config: &Config) -> usize {
+    let (column_width, margin) = column_size(width, config.column_margin);
+    let column_with_margin = column_width as usize + margin as usize * 2;
+    (width as usize)
+        .checked_div(column_with_margin)
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Whether the columns leave spare width to fill. Computed in `usize` so
+/// many columns on a wide terminal can't overflow `u16`.
+fn needs_filler(visible_columns: usize, margin: u16, available_width: u16) -> bool {
+    let (column_width, margin) = column_size(available_width, margin);
+    let column_with_margin = column_width as usize + margin as usize * 2;
+    visible_columns > 0
+        && available_width as usize > column_with_margin.saturating_mul(visible_columns)
+}
+
+/// The part of a column's area its title and tasks are drawn in. With the
+/// active-column border enabled every column leaves room for it, so the
+/// layout doesn't shift as the active column changes.
+fn column_content_area(column_area: Rect, border: bool) -> Rect {
+    if border {
+        Block::default().borders(Borders::ALL).inner(column_area)
+    } else {
+        column_area
+    }
+}
+
+/// A column's task count and what it is measured against for its load
+/// bar: the WIP limit, or else the task count of the busiest column
+fn column_load(column: &Column, busiest: usize) -> (usize, usize) {
+    (column.tasks.len(), column.wip_limit.unwrap_or(busiest))
+}
+
+/// How full a column is, where 1.0 means at capacity. Can exceed 1.0 when a
+/// column is over its limit.
+fn load_ratio(count: usize, capacity: usize) -> f64 {
+    if capacity == 0 {
+        0.0
+    } else {
+        count as f64 / capacity as f64
+    }
+}
+
+/// Load bar color. Only a real WIP limit can make a column red; without one
+/// the busiest column is always "full", which isn't a warning.
+fn load_color(ratio: f64, has_limit: bool) -> Color {
+    if has_limit && ratio >= 1.0 {
+        Color::Red
+    } else if ratio >= 0.75 {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+/// Rows a task card takes: its text, the border when boxed, and the
+/// `spacing` blank lines below it
+fn task_card_height(text_height: usize, boxed: bool, spacing: u16) -> usize {
+    let border_rows = if boxed { 2 } else { 0 };
+    text_height + border_rows + spacing as usize
+}
+
+/// List items for unboxed task cards: each card followed by `spacing`
+/// blank items, matching `task_card_height`
+fn task_list_items<'a>(cards: Vec<(Text<'a>, Style)>, spacing: u16) -> Vec<ListItem<'a>> {
+    cards
+        .into_iter()
+        .flat_map(|(text, style)| {
+            std::iter::once(ListItem::new(text).style(style))
+                .chain((0..spacing).map(|_| ListItem::new("")))
+        })
+        .collect()
+}
+
+/// Blank rows to leave above a column's tasks so that `content_height`
+/// rows sit in the middle of a `viewport` rows tall area. Content that
+/// fills the viewport starts at the top as usual.
+fn centering_padding(content_height: usize, viewport: usize) -> usize {
+    viewport.saturating_sub(content_height) / 2
+}
+
+/// Index of the first task to draw so the selected task stays visible.
+/// `heights` are the rows each task takes (including its spacer line), and
+/// `previous` is the first task drawn last frame. In edge mode the view
+/// stays put until the selection leaves it, then moves just far enough.
+fn first_visible_task(
+    heights: &[usize],
+    selected: Option<usize>,
+    viewport: usize,
+    mode: ScrollMode,
+    previous: usize,
+) -> usize {
+    let Some(selected) = selected.filter(|&s| s < heights.len()) else {
+        return 0;
+    };
+
+    let start = match mode {
+        ScrollMode::Edge if selected < previous => selected,
+        ScrollMode::Edge if heights[previous..=selected].iter().sum::<usize>() <= viewport => {
+            previous
+        }
+        _ => {
+            // Rows we are willing to show above the selected task
+            let room_above = match mode {
+                ScrollMode::Edge => viewport.saturating_sub(heights[selected]),
+                ScrollMode::Center => viewport.saturating_sub(heights[selected]) / 2,
+            };
+
+            let mut start = selected;
+            let mut used = 0;
+            while start > 0 && used + heights[start - 1] <= room_above {
+                used += heights[start - 1];
+                start -= 1;
+            }
+            start
+        }
+    };
+
+    // Don't scroll further than needed to show the end of the column
+    let mut last_start = heights.len();
+    let mut tail = 0;
+    while last_start > 0 && tail + heights[last_start - 1] <= viewport {
+        tail += heights[last_start - 1];
+        last_start -= 1;
+    }
+
+    start.min(last_start)
+}
+
+/// Draws the board's columns side by side, scrolled to `scroll_offset`
+fn draw_columns(f: &mut Frame, app: &App, area: Rect) {
+    // Determine layout for the columns.
+    let available_width = area.width;
+    let margin = app.config.column_margin;
+    let max_visible_columns = max_visible_columns(available_width, &app.config);
+    // The event loop settles the offset (see App::sync_scroll); clamp it
+    // anyway so a stale one never leaves the screen half empty
+    let shown = app.shown_columns();
+    let start_idx = app
+        .scroll_offset
+        .min(shown.len().saturating_sub(max_visible_columns));
+    let visible_columns = max_visible_columns.min(shown.len() - start_idx);
+
+    // Create layout constraints for each column.
     let columns_layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(column_constraints)
-        .split(chunks[1]);
+        .constraints(column_constraints(visible_columns, margin, available_width))
+        .split(area);
+
+    // Without a WIP limit, load bars are relative to the busiest column
+    let busiest = app.columns.iter().map(|c| c.tasks.len()).max().unwrap_or(0);
 
     // Render each visible column.
-    for (layout_idx, column_idx) in (start_idx..app.columns.len())
-        .enumerate()
-        .take(visible_columns)
-    {
+    for (layout_idx, &column_idx) in shown[start_idx..].iter().enumerate().take(visible_columns) {
         let column = &app.columns[column_idx];
         let column_area = columns_layout[layout_idx * 3 + 1]; // Skip left margin.
+        let border = app.config.active_column_border;
+        if border && column_idx == app.active_column {
+            let frame = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow));
+            f.render_widget(frame, column_area);
+        }
+        let column_area = column_content_area(column_area, border);
         let style = if column_idx == app.active_column {
             Style::default().fg(Color::Yellow)
         } else {
             Style::default()
         };
 
-        let title_with_index = format!(
-            "{} ({}/{})",
-            column.title,
-            column_idx + 1,
-            app.columns.len()
+        let mut title_with_index = column_title_text(
+            column,
+            column_idx,
+            app.columns.len(),
+            app.config.show_column_index,
         );
+        if let Some(threshold) = app.view_state.column_folds.get(&column.title) {
+            title_with_index.push_str(&format!(" [>={:.1}]", threshold));
+        }
         let title_text = Paragraph::new(title_with_index)
             .alignment(Alignment::Center)
             .style(style);
         let horizontal_line = Block::default()
             .borders(Borders::TOP)
             .border_style(Style::default().fg(Color::Blue));
+        let load_bar_rows = if app.config.show_load_bar { 1 } else { 0 };
         let column_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(
                 [
                     Constraint::Length(1),
+                    Constraint::Length(load_bar_rows),
                     Constraint::Length(1),
                     Constraint::Min(1),
                 ]
@@ -122,13 +316,43 @@ pub fn draw_ui(f: &mut Frame, app: &App) {
             .split(column_area);
 
         f.render_widget(title_text, column_layout[0]);
-        f.render_widget(horizontal_line, column_layout[1]);
+        if app.config.show_load_bar {
+            let (count, capacity) = column_load(column, busiest);
+            let ratio = load_ratio(count, capacity);
+            let bar = LineGauge::default()
+                .filled_style(Style::default().fg(load_color(ratio, column.wip_limit.is_some())))
+                .unfilled_style(Style::default().fg(Color::DarkGray))
+                .label(format!("{}/{}", count, capacity))
+                .ratio(ratio.min(1.0));
+            f.render_widget(bar, column_layout[1]);
+        }
+        f.render_widget(horizontal_line, column_layout[2]);
+
+        let visible = app.visible_task_indices(column_idx);
+        if let Some(placeholder) =
+            empty_column_placeholder(column, visible.len(), app.config.ascii_only)
+        {
+            let placeholder_text = Paragraph::new(placeholder)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center);
+            f.render_widget(placeholder_text, column_layout[3]);
+            continue;
+        }
 
-        let tasks: Vec<ListItem> = column
-            .tasks
+        // Boxed cards lose a cell on each side to their border. Compact
+        // rows are never boxed and sit directly under each other.
+        let compact = app.config.compact_tasks;
+        let boxed = app.config.boxed_tasks && !compact;
+        let spacing = if compact { 0 } else { app.config.task_spacing };
+        let text_width = if boxed {
+            column_area.width.saturating_sub(2)
+        } else {
+            column_area.width
+        };
+        let task_items: Vec<(Text, Style, usize)> = visible
             .iter()
-            .enumerate()
-            .flat_map(|(i, task)| {
+            .map(|&i| {
+                let task = &column.tasks[i];
                 // Get jump label for this task if in jump task mode
                 let jump_label = if app.input_mode == InputMode::JumpToTaskMode {
                     app.get_jump_label_for_task(column_idx, i)
@@ -137,41 +361,410 @@ pub fn draw_ui(f: &mut Frame, app: &App) {
                 };
 
                 // Format task with optional jump label
-                let formatted_task = format_task_with_wrapping(
+                let formatted_task = app.task_text_cache.format(
+                    app.file_path.as_deref(),
                     task,
-                    column_area.width,
+                    text_width,
                     jump_label,
                     app.input_mode == InputMode::JumpToTaskMode,
+                    &app.config,
                 );
 
-                // Apply appropriate styling
-                let style = if column.selected_task == Some(i) {
-                    Style::default()
-                        .fg(Color::White)
-                        .bg(Color::Blue)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default().bg(Color::Rgb(38, 38, 38))
+                let style = task_item_style(app, column_idx, i);
+                let height = task_card_height(formatted_task.height(), boxed, spacing);
+                (formatted_task, style, height)
+            })
+            .collect();
+
+        // Scroll the column so the selected task stays in view
+        let heights: Vec<usize> = task_items.iter().map(|(_, _, height)| *height).collect();
+        let selected_position = column
+            .selected_task
+            .and_then(|selected| visible.iter().position(|&i| i == selected));
+        let mut task_scroll = app.task_scroll.borrow_mut();
+        let scroll = task_scroll.entry(column_idx).or_default();
+        *scroll = first_visible_task(
+            &heights,
+            selected_position,
+            column_layout[3].height as usize,
+            app.config.scroll_mode,
+            *scroll,
+        );
+        let start = *scroll;
+        drop(task_scroll);
+
+        // Optionally push a short column's tasks down to the middle; the
+        // last card's spacer lines don't count as content
+        let mut tasks_area = column_layout[3];
+        if app.config.center_short_columns {
+            let content_height = heights
+                .iter()
+                .sum::<usize>()
+                .saturating_sub(spacing as usize);
+            let padding = centering_padding(content_height, tasks_area.height as usize) as u16;
+            tasks_area.y += padding;
+            tasks_area.height -= padding;
+        }
+
+        if boxed {
+            // Lay the boxes out by hand, clipping the last one at the bottom
+            let area = tasks_area;
+            let mut y = area.y;
+            for (text, style, height) in task_items.into_iter().skip(start) {
+                if y >= area.bottom() {
+                    break;
+                }
+                let card_area = Rect {
+                    y,
+                    height: ((height - spacing as usize) as u16).min(area.bottom() - y),
+                    ..area
                 };
+                let card = Paragraph::new(text)
+                    .style(style)
+                    .block(Block::default().borders(Borders::ALL).style(style));
+                f.render_widget(card, card_area);
+                y = y.saturating_add(height as u16);
+            }
+        } else {
+            let cards = task_items
+                .into_iter()
+                .skip(start)
+                .map(|(text, style, _)| (text, style))
+                .collect();
+            let tasks = task_list_items(cards, spacing);
 
-                let task_item = ListItem::new(formatted_task).style(style);
-                vec![task_item, ListItem::new("")]
+            let tasks_list = List::new(tasks).block(Block::default());
+            f.render_widget(tasks_list, tasks_area);
+        }
+    }
+}
+
+/// Background style of a task in a column: highlighted briefly after it was
+/// moved or created, in the configured selection style when selected
+pub fn task_item_style(app: &App, column_idx: usize, task_idx: usize) -> Style {
+    if app.is_task_recently_changed(column_idx, task_idx) {
+        // Briefly flag a task that was just moved or created
+        Style::default()
+            .fg(Color::White)
+            .bg(Color::Rgb(120, 90, 20))
+            .add_modifier(Modifier::BOLD)
+    } else if app.columns[column_idx].selected_task == Some(task_idx) {
+        app.config.selection.style()
+    } else {
+        Style::default().bg(Color::Rgb(38, 38, 38))
+    }
+}
+
+/// Draws the tag view: one read-only virtual column per primary tag, with
+/// the tasks the board view would show. The selection is still the real
+/// one, so it is highlighted wherever the selected task lands and every
+/// edit applies to the task's real column.
+fn draw_tag_view(f: &mut Frame, app: &App, area: Rect) {
+    let groups = app.tag_view_groups();
+    let selected = app.columns.get(app.active_column).and_then(|column| {
+        column
+            .selected_task
+            .map(|task_idx| (app.active_column, task_idx))
+    });
+
+    // Scroll so the group holding the selected task is on screen
+    let max_visible = max_visible_columns(area.width, &app.config);
+    let selected_group = groups
+        .iter()
+        .position(|(_, tasks)| selected.is_some_and(|selected| tasks.contains(&selected)))
+        .unwrap_or(0);
+    let start_idx = (selected_group + 1).saturating_sub(max_visible);
+    let visible_groups = max_visible.min(groups.len() - start_idx.min(groups.len()));
+
+    let groups_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(column_constraints(
+            visible_groups,
+            app.config.column_margin,
+            area.width,
+        ))
+        .split(area);
+
+    let spacing = app.config.task_spacing;
+    for (layout_idx, (tag, tasks)) in groups
+        .iter()
+        .skip(start_idx)
+        .take(visible_groups)
+        .enumerate()
+    {
+        let group_area = groups_layout[layout_idx * 3 + 1]; // Skip left margin.
+        let group_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(1),
+            ])
+            .split(group_area);
+
+        let title = Paragraph::new(format!("#{} ({})", tag, tasks.len()))
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Cyan));
+        f.render_widget(title, group_layout[0]);
+        f.render_widget(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(Color::Blue)),
+            group_layout[1],
+        );
+
+        let cards: Vec<(Text, Style)> = tasks
+            .iter()
+            .map(|&(column_idx, task_idx)| {
+                let task = &app.columns[column_idx].tasks[task_idx];
+                let text = app.task_text_cache.format(
+                    app.file_path.as_deref(),
+                    task,
+                    group_area.width,
+                    None,
+                    false,
+                    &app.config,
+                );
+                (text, task_item_style(app, column_idx, task_idx))
             })
             .collect();
 
-        let tasks_list = List::new(tasks).block(Block::default());
-        f.render_widget(tasks_list, column_layout[2]);
+        // Scroll each group like a column, so its selected task stays in view
+        let heights: Vec<usize> = cards
+            .iter()
+            .map(|(text, _)| task_card_height(text.height(), false, spacing))
+            .collect();
+        let selected_position = tasks.iter().position(|&task| selected == Some(task));
+        let mut tag_scroll = app.tag_scroll.borrow_mut();
+        let scroll = tag_scroll.entry(tag.clone()).or_default();
+        *scroll = first_visible_task(
+            &heights,
+            selected_position,
+            group_layout[2].height as usize,
+            app.config.scroll_mode,
+            *scroll,
+        );
+        let start = *scroll;
+        drop(tag_scroll);
+
+        let items = task_list_items(cards.into_iter().skip(start).collect(), spacing);
+        f.render_widget(List::new(items), group_layout[2]);
+    }
+}
+
+/// One row of the priority view: rank, score, title and source column
+pub fn priority_row_text(rank: usize, row: &PrioritizedTask, title: &str) -> String {
+    let score = row
+        .score
+        .map_or_else(|| "-".to_string(), |score| format!("{:.2}", score));
+    // Multi-line titles share the row's single line
+    let title = title.lines().collect::<Vec<_>>().join(" ");
+    format!("{:>3}. {:>6}  {}  [{}]", rank, score, title, row.column)
+}
+
+/// Draws the priority view: every task in one list, most important first,
+/// with the column it lives in. Like the tag view it highlights the real
+/// selection, so priority edits apply to the task in its column.
+fn draw_priority_view(f: &mut Frame, app: &App, area: Rect) {
+    let rows = app.priority_view_tasks();
+    let selected = app
+        .columns
+        .get(app.active_column)
+        .and_then(|column| column.selected_task);
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let task = &app.columns[row.column_idx].tasks[row.task_idx];
+            let style = if row.column_idx == app.active_column && selected == Some(row.task_idx) {
+                app.config.selection.style()
+            } else {
+                Style::default().bg(Color::Rgb(38, 38, 38))
+            };
+            ListItem::new(priority_row_text(i + 1, row, &task.title)).style(style)
+        })
+        .collect();
+
+    let mut state = ListState::default().with_selected(
+        rows.iter()
+            .position(|row| row.column_idx == app.active_column && selected == Some(row.task_idx)),
+    );
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::Blue)),
+    );
+    let margin = app.config.column_margin;
+    let list_area = Rect {
+        x: area.x + margin.min(area.width),
+        width: area.width.saturating_sub(margin * 2),
+        ..area
+    };
+    f.render_stateful_widget(list, list_area, &mut state);
+}
+
+/// Draws the overall UI including the title, columns, tasks and any popups.
+pub fn draw_ui(f: &mut Frame, app: &App) {
+    draw_screen(f, app);
+    // The theme is RGB throughout; map it down for terminals without truecolor
+    if !colors::use_truecolor(app.config.color_mode) {
+        colors::downgrade_buffer(f.buffer_mut());
+    }
+}
+
+fn draw_screen(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Set the background color for the entire app
+    // let background = Block::default()
+    //     .style(Style::default().bg(Color::Rgb(22, 22, 22))) // #161616
+    //     .borders(Borders::NONE);
+    // f.render_widget(background, size);
+
+    // Clear the terminal with our background color
+    f.render_widget(Clear, size); // First clear any existing content
+    let background = Block::default()
+        .style(Style::default().bg(Color::Rgb(22, 22, 22))) // #161616
+        .borders(Borders::NONE);
+    f.render_widget(background, size);
+
+    // Check if we need to show the board selection popup
+    match app.input_mode {
+        InputMode::BoardSelection => {
+            popups::draw_board_selection(f, app, size);
+            if app.show_onboarding {
+                popups::draw_onboarding_popup(f, size);
+            }
+            return;
+        }
+        InputMode::AddingBoard => {
+            popups::draw_new_board_popup(f, app, size);
+            return;
+        }
+        _ => {}
+    }
+
+    // Render the title.
+    let title_style = if app.is_recently_saved() {
+        // Flash the title bar after an explicit save
+        Style::default().fg(Color::Black).bg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::Cyan)
+    };
+    let mut title_text = board_title_text(&app.title, app.dirty, &app.available_boards);
+    if app.read_only {
+        title_text.push_str("  [read-only]");
+    }
+    if let Some(filter) = &app.filter {
+        title_text.push_str(&format!("  [filter: {}]", filter.expression));
+    }
+    if app.tag_view {
+        title_text.push_str("  [by tag]");
+    }
+    if app.priority_view {
+        title_text.push_str("  [by priority]");
+    }
+    let hidden_columns = app.columns.len() - app.shown_columns().len();
+    if hidden_columns > 0 {
+        title_text.push_str(&format!("  [{} empty hidden]", hidden_columns));
+    }
+    if let Some(timer) = &app.focus_timer {
+        title_text.push_str(&format!(
+            "  [focus {}]",
+            timer.countdown_label(Instant::now())
+        ));
+    }
+    let title = Paragraph::new(title_text)
+        .style(title_style)
+        .alignment(Alignment::Center)
+        .block(Block::default());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(screen_constraints(&app.config))
+        .split(size);
+    f.render_widget(title, chunks[0]);
+
+    // Explain the priority dot colors in the corner of the title bar, unless
+    // the bar is a single row that the title already occupies
+    if app.config.show_legend && app.config.show_priority_dot && app.config.title_height > 1 {
+        let mut spans = Vec::new();
+        for (label, color) in priority_legend(app.config.priority_formula) {
+            spans.push(Span::styled(
+                format!("{} ", priority_dot(&app.config)),
+                Style::default().fg(color),
+            ));
+            spans.push(Span::styled(
+                format!("{}  ", label),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        let legend = Paragraph::new(Line::from(spans)).alignment(Alignment::Right);
+        // Use the bottom row of the title area so it never overlaps the title
+        let legend_area = Rect {
+            y: chunks[0].bottom().saturating_sub(1),
+            height: 1.min(chunks[0].height),
+            ..chunks[0]
+        };
+        f.render_widget(legend, legend_area);
+    }
+
+    // Without any columns there is nothing to lay out, so just explain what to do
+    if app.columns.is_empty() {
+        let message = if app.config.ascii_only {
+            EMPTY_BOARD_MESSAGE_ASCII
+        } else {
+            EMPTY_BOARD_MESSAGE
+        };
+        let hint = Paragraph::new(message)
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+        f.render_widget(hint, chunks[1]);
+    }
+
+    if app.priority_view {
+        draw_priority_view(f, app, chunks[1]);
+    } else if app.tag_view {
+        draw_tag_view(f, app, chunks[1]);
+    } else {
+        draw_columns(f, app, chunks[1]);
     }
 
     // Render help text.
     let help_text = match app.input_mode {
-        InputMode::Normal => {
-            "Use 'h'/'l' to navigate columns | 'j'/'k' to navigate tasks | 'm' to move task | 'gc' to jump to column | 'gt' to jump to task | ac to add column | at to add task | dt to delete task | dc to delete column | b for board selection | Ctrl+S to save | 'q' to quit"
+        InputMode::Normal => NORMAL_MODE_HELP,
+        InputMode::AddingColumn | InputMode::InsertingColumn(_) => {
+            "Enter column name | Enter to confirm | Esc to cancel"
+        }
+        InputMode::AddingTask if app.config.sticky_add => {
+            "Enter task name | Enter to add and continue | Esc to finish"
         }
-        InputMode::AddingColumn => "Enter column name | Enter to confirm | Esc to cancel",
         InputMode::AddingTask => "Enter task name | Enter to confirm | Esc to cancel",
         InputMode::MoveMode => "Press 0-9 to jump to that column | Esc to cancel",
-        InputMode::ConfirmDeleteColumn => "Press y to delete | n to cancel",
+        InputMode::ConfirmDeleteColumn | InputMode::ConfirmDeleteTask => {
+            "Press y to delete | n to cancel"
+        }
+        InputMode::ConfirmClearColumn => "Press y to remove all tasks | n to cancel",
+        InputMode::ConfirmRevert => "Press y to revert | n to cancel",
+        InputMode::ConfirmMergeColumns(_, _) => "Press y to merge | n to keep both",
+        InputMode::ConfirmReplayJournal => "Press y to replay the edits | n to discard them",
+        InputMode::ConfirmReload => "Press y to reload from disk | n to keep your changes",
+        InputMode::ConfirmQuit(_) => "Press y to quit without saving | n to go back",
+        InputMode::TrashViewer => "j/k to choose | Enter to restore | Esc to close",
+        InputMode::ConfirmWipOverride(_) => "Press y to move anyway | n to cancel",
+        InputMode::BoardInfo | InputMode::KeyHelp => "j/k to scroll | any other key to close",
+        InputMode::BoardStats | InputMode::DoneSummary | InputMode::TaskDetail => {
+            "Press any key to close"
+        }
+        InputMode::EditingWipLimit => {
+            "Enter a WIP limit, empty for none | Enter to confirm | Esc to cancel"
+        }
+        InputMode::EditingPriority(_) => {
+            "Enter a value from 0 to 10 | Enter to confirm | Esc to cancel"
+        }
+        InputMode::MovingTaskByName => {
+            "Type letters of a column name; moves on a unique match | Tab to complete | Enter to move | Esc to cancel"
+        }
         InputMode::ColumnSelectionMode => {
             "Press number to move task to that column | Esc to cancel"
         }
@@ -181,15 +774,31 @@ pub fn draw_ui(f: &mut Frame, app: &App) {
         }
         InputMode::RenamingColumn => "Edit column name | Enter to confirm | Esc to cancel",
         InputMode::RenamingTask => "Edit task name | Enter to confirm | Esc to cancel",
+        InputMode::JumpToTaskId => "Enter task ID | Enter to jump | Esc to cancel",
+        InputMode::ImportingCsv => {
+            "Enter CSV path, optionally ': column' | Enter to import | Esc to cancel"
+        }
+        InputMode::SplittingTask => "Enter title for the new task | Enter to split | Esc to cancel",
+        InputMode::SendingTaskToBoard => {
+            "Enter target as 'board: column' | Enter to send | Esc to cancel"
+        }
+        InputMode::Filtering => "Enter filter | Enter to apply (empty clears) | Esc to cancel",
         _ => "", // BoardSelection and AddingBoard are handled separately
     };
-    let help = Paragraph::new(help_text)
-        .style(
+    // A pending status message takes the place of the help text
+    let help = match &app.status_message {
+        Some(message) => Paragraph::new(message.clone()).style(
+            Style::default()
+                .fg(Color::Yellow)
+                .bg(Color::Rgb(22, 22, 22)),
+        ),
+        None => Paragraph::new(help_text).style(
             Style::default()
                 .fg(Color::DarkGray)
                 .bg(Color::Rgb(22, 22, 22)),
-        ) // #161616 for bg
-        .alignment(Alignment::Center);
+        ), // #161616 for bg
+    }
+    .alignment(Alignment::Center);
     let help_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
@@ -205,3 +814,340 @@ pub fn draw_ui(f: &mut Frame, app: &App) {
 fn draw_popup(f: &mut Frame, app: &App, size: ratatui::layout::Rect) {
     popups::draw_popup(f, app, size);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::Task;
+    use crate::kanban::ui::task_formatter::format_task_with_wrapping;
+    use ratatui::{buffer::Buffer, widgets::Widget};
+
+    #[test]
+    fn test_layout_constraints_follow_config() {
+        let default = Config::default();
+        assert_eq!(
+            screen_constraints(&default),
+            [Constraint::Length(3), Constraint::Min(0)]
+        );
+        assert_eq!(
+            column_constraints(2, default.column_margin, 120),
+            vec![
+                Constraint::Length(2),
+                Constraint::Length(COLUMN_WIDTH),
+                Constraint::Length(2),
+                Constraint::Length(2),
+                Constraint::Length(COLUMN_WIDTH),
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ]
+        );
+
+        let compact = Config::parse("title_height = 1\ncolumn_margin = 0");
+        assert_eq!(
+            screen_constraints(&compact),
+            [Constraint::Length(1), Constraint::Min(0)]
+        );
+        // Two columns fill 100 cells exactly, so there is no filler
+        assert_eq!(
+            column_constraints(2, compact.column_margin, 100),
+            vec![
+                Constraint::Length(0),
+                Constraint::Length(COLUMN_WIDTH),
+                Constraint::Length(0),
+                Constraint::Length(0),
+                Constraint::Length(COLUMN_WIDTH),
+                Constraint::Length(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_centering_padding() {
+        // Two blank rows above and below three rows of tasks
+        assert_eq!(centering_padding(3, 7), 2);
+        // An odd leftover row goes below the tasks
+        assert_eq!(centering_padding(4, 7), 1);
+        // Content that fills or overflows the column isn't moved
+        assert_eq!(centering_padding(7, 7), 0);
+        assert_eq!(centering_padding(20, 7), 0);
+        assert_eq!(centering_padding(0, 0), 0);
+    }
+
+    #[test]
+    fn test_board_title_text() {
+        let boards: Vec<String> = ["Home", "My Board", "Work"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+
+        assert_eq!(
+            board_title_text("My Board", true, &boards),
+            "My Board [+]  (2 of 3 boards)"
+        );
+        assert_eq!(
+            board_title_text("My Board", false, &boards),
+            "My Board  (2 of 3 boards)"
+        );
+        assert_eq!(
+            board_title_text("Home", false, &boards[..1]),
+            "Home  (1 of 1 board)"
+        );
+        // A stale list that doesn't know this board yet
+        assert_eq!(
+            board_title_text("Fresh", true, &boards),
+            "Fresh [+]  (3 boards)"
+        );
+        // No KANBAN_DIR, so no boards were scanned
+        assert_eq!(board_title_text("Scratch", false, &[]), "Scratch");
+    }
+
+    #[test]
+    fn test_normal_mode_help_fits_a_status_line() {
+        assert!(NORMAL_MODE_HELP.len() <= 160);
+        assert!(NORMAL_MODE_HELP.contains("? for all keys"));
+    }
+
+    #[test]
+    fn test_column_width_is_clamped_to_narrow_terminals() {
+        // Wide enough: the full column with its margins
+        assert_eq!(column_size(120, 2), (COLUMN_WIDTH, 2));
+        // Narrower than one column: it shrinks to fit inside the margins
+        assert_eq!(column_size(30, 2), (26, 2));
+        assert_eq!(
+            column_constraints(1, 2, 30),
+            vec![
+                Constraint::Length(2),
+                Constraint::Length(26),
+                Constraint::Length(2),
+            ]
+        );
+        // Too narrow for margins: the column gets every cell there is
+        assert_eq!(column_size(14, 2), (14, 0));
+        assert_eq!(column_size(0, 2), (0, 0));
+
+        let config = Config::default();
+        assert_eq!(max_visible_columns(30, &config), 1);
+        assert_eq!(max_visible_columns(0, &config), 1);
+        assert_eq!(max_visible_columns(108, &config), 2);
+
+        // Cards still render in a column squeezed to a couple of cells
+        let task = Task {
+            id: 1,
+            title: "Narrow".to_string(),
+            description: None,
+            priority: None,
+            completed_at: None,
+            tags: Vec::new(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        };
+        let text = format_task_with_wrapping(&task, 2, None, false, &config);
+        assert!(text.lines.len() > 1);
+    }
+
+    #[test]
+    fn test_column_content_area_leaves_room_for_border() {
+        let area = Rect::new(2, 3, COLUMN_WIDTH, 20);
+        assert_eq!(
+            column_content_area(area, true),
+            Rect::new(3, 4, COLUMN_WIDTH - 2, 18)
+        );
+        assert_eq!(column_content_area(area, false), area);
+    }
+
+    #[test]
+    fn test_filler_decision_does_not_overflow() {
+        assert!(needs_filler(2, 2, 120));
+        assert!(!needs_filler(2, 2, 108));
+        assert!(!needs_filler(0, 2, 120));
+        // 2000 columns of 54 cells would wrap around in u16 arithmetic
+        assert!(!needs_filler(2000, 2, u16::MAX));
+        assert!(!needs_filler(usize::MAX, 10, u16::MAX));
+    }
+
+    #[test]
+    fn test_column_load_ratio() {
+        let mut column = Column {
+            title: "Doing".to_string(),
+            tasks: Vec::new(),
+            selected_task: None,
+            wip_limit: Some(4),
+        };
+        assert_eq!(column_load(&column, 10), (0, 4));
+        assert_eq!(load_ratio(3, 4), 0.75);
+        assert_eq!(load_ratio(5, 4), 1.25);
+        assert_eq!(load_ratio(0, 0), 0.0);
+
+        // Without a limit the busiest column sets the scale
+        column.wip_limit = None;
+        assert_eq!(column_load(&column, 10), (0, 10));
+
+        assert_eq!(load_color(0.5, true), Color::Green);
+        assert_eq!(load_color(0.75, true), Color::Yellow);
+        assert_eq!(load_color(1.0, true), Color::Red);
+        assert_eq!(load_color(1.0, false), Color::Yellow);
+    }
+
+    #[test]
+    fn test_task_card_height() {
+        // Three text rows plus the spacer, and two border rows when boxed
+        assert_eq!(task_card_height(3, false, 1), 4);
+        assert_eq!(task_card_height(3, true, 1), 6);
+        assert_eq!(task_card_height(3, false, 0), 3);
+        assert_eq!(task_card_height(3, true, 2), 7);
+    }
+
+    #[test]
+    fn test_task_list_items_follow_spacing() {
+        let cards = |count: usize| -> Vec<(Text, Style)> {
+            (0..count)
+                .map(|i| (Text::from(format!("Task {}", i)), Style::default()))
+                .collect()
+        };
+        assert_eq!(task_list_items(cards(4), 0).len(), 4);
+        assert_eq!(task_list_items(cards(4), 1).len(), 8);
+        assert_eq!(task_list_items(cards(3), 3).len(), 12);
+        assert!(task_list_items(cards(0), 2).is_empty());
+    }
+
+    #[test]
+    fn test_column_title_index_suffix() {
+        let column = Column {
+            title: "Doing".to_string(),
+            tasks: Vec::new(),
+            selected_task: None,
+            wip_limit: None,
+        };
+
+        assert_eq!(column_title_text(&column, 1, 3, true), "Doing (2/3)");
+        assert_eq!(column_title_text(&column, 1, 3, false), "Doing");
+    }
+
+    // The first task drawn each frame while the selection visits `path`
+    fn scroll_starts(heights: &[usize], path: &[usize], mode: ScrollMode) -> Vec<usize> {
+        let mut start = 0;
+        path.iter()
+            .map(|&selected| {
+                start = first_visible_task(heights, Some(selected), 12, mode, start);
+                start
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_edge_scrolling_follows_selection() {
+        // Ten tasks of three rows each in a column with room for four
+        let heights = vec![3; 10];
+        let down: Vec<usize> = (0..10).collect();
+        let starts = scroll_starts(&heights, &down, ScrollMode::Edge);
+
+        assert_eq!(starts, vec![0, 0, 0, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            first_visible_task(&heights, None, 12, ScrollMode::Edge, 3),
+            0
+        );
+    }
+
+    #[test]
+    fn test_edge_scrolling_keeps_view_while_moving_up() {
+        let heights = vec![3; 10];
+        let path: Vec<usize> = (0..10).chain((0..10).rev()).collect();
+        let starts = scroll_starts(&heights, &path, ScrollMode::Edge);
+
+        // Back up from the bottom, the view holds until the selection
+        // reaches its top edge, then follows it there
+        assert_eq!(starts[10..], [6, 6, 6, 6, 5, 4, 3, 2, 1, 0]);
+
+        // Turning around mid-column doesn't jump the view either
+        let starts = scroll_starts(&heights, &[0, 1, 2, 3, 4, 5, 4, 3, 2, 3], ScrollMode::Edge);
+        assert_eq!(starts, vec![0, 0, 0, 0, 1, 2, 2, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_centered_scrolling_follows_selection() {
+        let heights = vec![3; 10];
+        let down: Vec<usize> = (0..10).collect();
+        let starts = scroll_starts(&heights, &down, ScrollMode::Center);
+
+        // One task stays above the selection until the end of the column
+        assert_eq!(starts, vec![0, 0, 1, 2, 3, 4, 5, 6, 6, 6]);
+    }
+
+    #[test]
+    fn test_selected_task_uses_configured_style() {
+        let mut app = App::new("Test Board");
+        app.config = Config::parse("[selection]\nfg = black\nbg = yellow\nmodifier = italic");
+        app.columns[0].selected_task = Some(1);
+
+        let selected = task_item_style(&app, 0, 1);
+        assert_eq!(selected, app.config.selection.style());
+        assert_ne!(task_item_style(&app, 0, 0), selected);
+
+        // The style reaches the cells the selected task's item is drawn in
+        let area = Rect::new(0, 0, 12, 1);
+        let mut buffer = Buffer::empty(area);
+        let item = ListItem::new("Fix bug").style(selected);
+        Widget::render(List::new(vec![item]), area, &mut buffer);
+        let cell = &buffer[(0, 0)];
+        assert_eq!(cell.fg, Color::Black);
+        assert_eq!(cell.bg, Color::Yellow);
+        assert!(cell.modifier.contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_priority_row_text() {
+        let row = PrioritizedTask {
+            column_idx: 1,
+            task_idx: 0,
+            column: "Doing".to_string(),
+            score: Some(2.5),
+        };
+        assert_eq!(
+            priority_row_text(1, &row, "Fix\ncrash"),
+            "  1.   2.50  Fix crash  [Doing]"
+        );
+        let unscored = PrioritizedTask { score: None, ..row };
+        assert_eq!(
+            priority_row_text(12, &unscored, "Later"),
+            " 12.      -  Later  [Doing]"
+        );
+    }
+
+    #[test]
+    fn test_empty_column_placeholder() {
+        let mut column = Column {
+            title: "To Do".to_string(),
+            tasks: Vec::new(),
+            selected_task: None,
+            wip_limit: None,
+        };
+        assert_eq!(
+            empty_column_placeholder(&column, 0, false),
+            Some(EMPTY_COLUMN_MESSAGE)
+        );
+        assert_eq!(
+            empty_column_placeholder(&column, 0, true),
+            Some(EMPTY_COLUMN_MESSAGE_ASCII)
+        );
+        assert!(EMPTY_COLUMN_MESSAGE_ASCII.is_ascii());
+        assert!(EMPTY_BOARD_MESSAGE_ASCII.is_ascii());
+
+        column.tasks.push(Task {
+            id: 0,
+            title: "First task".to_string(),
+            description: None,
+            priority: None,
+            completed_at: None,
+            tags: Vec::new(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        });
+        assert_eq!(empty_column_placeholder(&column, 1, false), None);
+        assert_eq!(
+            empty_column_placeholder(&column, 0, true),
+            Some(NO_MATCHES_MESSAGE)
+        );
+    }
+}
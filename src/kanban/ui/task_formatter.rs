@@ -1,69 +1,238 @@
-use crate::kanban::models::Task;
+use crate::kanban::config::Config;
+use crate::kanban::models::{MAX_PRIORITY_VALUE, Task};
+use crate::ops::crud::{Priority, PriorityFormula};
 use ratatui::style::Modifier;
 use ratatui::{
     style::{Color, Style},
     text::{Line, Span, Text},
 };
+use std::cell::RefCell;
+use std::collections::HashMap;
+use unicode_width::UnicodeWidthChar;
 
-/// Calculates a priority color based on the priority value
-fn get_priority_color(priority: Option<u8>) -> Color {
-    match priority {
-        Some(p) if p >= 8 => Color::Red,    // High priority
-        Some(p) if p >= 5 => Color::Yellow, // Medium priority
-        Some(p) if p >= 3 => Color::Green,  // Normal priority
-        Some(_) => Color::Blue,             // Low priority
-        None => Color::DarkGray,            // No priority
+// The priority color bands, highest first: each starts at a fraction of
+// the top score and runs up to the next
+const PRIORITY_BANDS: [(f32, &str, Color); 4] = [
+    (0.8, "High", Color::Red),
+    (0.5, "Medium", Color::Yellow),
+    (0.3, "Normal", Color::Green),
+    (f32::NEG_INFINITY, "Low", Color::Blue),
+];
+
+/// Color for a priority given as a fraction of its top value
+fn get_priority_color(level: Option<f32>) -> Color {
+    match level {
+        Some(level) => PRIORITY_BANDS
+            .iter()
+            .find(|(start, _, _)| level >= *start)
+            .map_or(Color::Blue, |&(_, _, color)| color),
+        None => Color::DarkGray, // No priority
+    }
+}
+
+/// Color of a task's priority dot: its score under the configured formula,
+/// banded like the legend. Tasks without a score are dim.
+pub fn task_priority_color(task: &Task, formula: PriorityFormula) -> Color {
+    get_priority_color(
+        task.computed_priority(formula)
+            .map(|score| score / formula.max_score()),
+    )
+}
+
+/// The symbol drawn as the priority dot, plain ASCII if the config asks
+pub fn priority_dot(config: &Config) -> &'static str {
+    if config.ascii_only { "*" } else { "●" }
+}
+
+/// Three cells showing impact, urgency and effort, each as a block whose
+/// height (or, in ASCII mode, density) grows with the value and whose color
+/// follows the priority bands. A task without a priority gets three dim
+/// placeholders so cards stay aligned.
+pub fn priority_bar(priority: Option<&Priority>, config: &Config) -> Vec<Span<'static>> {
+    let levels: &[char] = if config.ascii_only {
+        &['.', ':', '-', '=', '+', '*', '#', '%', '@']
+    } else {
+        &['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█']
+    };
+    let Some(priority) = priority else {
+        let placeholder = if config.ascii_only { "..." } else { "···" };
+        return vec![Span::styled(
+            placeholder,
+            Style::default().fg(get_priority_color(None)),
+        )];
+    };
+
+    [priority.impact, priority.urgency, priority.effort]
+        .iter()
+        .map(|&value| {
+            let value = value.min(MAX_PRIORITY_VALUE);
+            let level = value as usize * (levels.len() - 1) / MAX_PRIORITY_VALUE as usize;
+            Span::styled(
+                levels[level].to_string(),
+                Style::default().fg(get_priority_color(Some(
+                    f32::from(value) / f32::from(MAX_PRIORITY_VALUE),
+                ))),
+            )
+        })
+        .collect()
+}
+
+/// Columns a string takes up on screen, which for emoji and other wide
+/// characters is more than its length in bytes. Zero-width and control
+/// characters count as 0.
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+// Split off the longest prefix of `text` that fits in `width` columns,
+// always taking at least one character so wrapping makes progress
+fn split_at_width(text: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    for (idx, c) in text.char_indices() {
+        used += char_width(c);
+        if used > width && idx > 0 {
+            return text.split_at(idx);
+        }
     }
+    (text, "")
+}
+
+/// Word-wrap `text` into lines at most `width` columns wide. Each line of
+/// `text` is a paragraph of its own and blank lines between paragraphs are
+/// kept; a word too long for a line is broken across lines.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let mut word = word;
+            while !word.is_empty() {
+                let needed = if line.is_empty() {
+                    display_width(word)
+                } else {
+                    display_width(&line) + 1 + display_width(word)
+                };
+                if needed <= width {
+                    if !line.is_empty() {
+                        line.push(' ');
+                    }
+                    line.push_str(word);
+                    break;
+                }
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                    continue;
+                }
+                let (head, rest) = split_at_width(word, width);
+                lines.push(head.to_string());
+                word = rest;
+            }
+        }
+        if !line.is_empty() || paragraph.trim().is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Width available for task text: the column width minus padding, scaled
+/// to `text_width_percent` of that (100 uses the whole inner width)
+fn effective_text_width(
+    max_width: u16,
+    horizontal_padding: usize,
+    text_width_percent: u8,
+) -> usize {
+    let inner_width = (max_width as usize).saturating_sub(horizontal_padding * 2);
+    (inner_width * text_width_percent.clamp(1, 100) as usize / 100).max(1)
+}
+
+/// Labels for each priority color band, highest first, for the legend.
+/// The bands start at scores of the configured formula.
+pub fn priority_legend(formula: PriorityFormula) -> Vec<(String, Color)> {
+    PRIORITY_BANDS
+        .iter()
+        .map(|&(start, name, color)| {
+            let label = if start.is_finite() {
+                format!("{} {}+", name, start * formula.max_score())
+            } else {
+                name.to_string()
+            };
+            (label, color)
+        })
+        .chain(std::iter::once((
+            "None".to_string(),
+            get_priority_color(None),
+        )))
+        .collect()
 }
 
 /// Formats task text with wrapping given a maximum width.
 /// This function returns a `Text` object that can be rendered in the UI.
 /// If a jump_label is provided, it will be displayed next to the task.
+/// Display options (like the priority dot) come from `config`.
 pub fn format_task_with_wrapping(
     task: &Task,
     max_width: u16,
     jump_label: Option<char>,
     show_jump_labels: bool,
+    config: &Config,
 ) -> Text<'static> {
     let task_text = &task.title;
     let indent = "";
     let horizontal_padding: usize = 2;
-    let effective_width = max_width as usize - (horizontal_padding * 2);
-    let max_chars_first_line = effective_width;
-    let max_chars_other_lines = effective_width.saturating_sub(indent.len());
+    // Saturating, so a column squeezed to almost nothing still renders
+    let effective_width = (max_width as usize).saturating_sub(horizontal_padding * 2);
+    // Text may wrap narrower than the column; padding still fills the rest
+    let text_width = effective_text_width(max_width, horizontal_padding, config.task_text_width);
+    let max_chars_other_lines = text_width.saturating_sub(indent.len());
 
     let mut lines = Vec::new();
 
-    // Add an initial padding line with the priority dot
-    let priority_color = get_priority_color(task.priority);
-    let mut first_padding_line = vec![
-        Span::raw(" ".repeat(horizontal_padding)),
-        Span::raw(" ".repeat(effective_width - 1)),
-        Span::styled("●", Style::default().fg(priority_color)),
-        Span::raw(" ".repeat(horizontal_padding)),
-    ];
-    lines.push(Line::from(first_padding_line));
-
-    // Format the first line with jump label if provided
-    let first_line_text = if task_text.len() > max_chars_first_line {
-        task_text[..max_chars_first_line].to_string()
-    } else {
-        task_text.to_string()
-    };
+    // Add an initial padding line with the priority bar and dot, unless
+    // both are disabled
+    if config.show_priority_dot || config.show_priority_bar {
+        let mut indicators = Vec::new();
+        if config.show_priority_bar {
+            indicators.extend(priority_bar(task.priority.as_ref(), config));
+        }
+        if config.show_priority_dot {
+            if config.show_priority_bar {
+                indicators.push(Span::raw(" "));
+            }
+            let priority_color = task_priority_color(task, config.priority_formula);
+            indicators.push(Span::styled(
+                priority_dot(config),
+                Style::default().fg(priority_color),
+            ));
+        }
+        let indicators_width: usize = indicators.iter().map(Span::width).sum();
+
+        let mut first_padding_line = vec![
+            Span::raw(" ".repeat(horizontal_padding)),
+            Span::raw(" ".repeat(effective_width.saturating_sub(indicators_width))),
+        ];
+        first_padding_line.extend(indicators);
+        first_padding_line.push(Span::raw(" ".repeat(horizontal_padding)));
+        lines.push(Line::from(first_padding_line));
+    }
 
     // Calculate space needed for jump label display
     let jump_label_width = if show_jump_labels { 3 } else { 0 }; // "[a]" takes 3 chars
-    let available_text_width = effective_width.saturating_sub(jump_label_width);
+    let available_text_width = text_width.saturating_sub(jump_label_width);
 
-    // Adjust text to fit within available width after jump label
-    let adjusted_text = if first_line_text.len() > available_text_width && available_text_width > 0
-    {
-        first_line_text[..available_text_width].to_string()
-    } else {
-        first_line_text.clone()
-    };
+    // Each line of a multi-line title wraps on its own. The first fits
+    // beside the jump label; the rest wraps below.
+    let mut title_lines = task_text.split('\n');
+    let first_title_line = title_lines.next().unwrap_or("");
+    let (first_line_text, remaining_text) = split_at_width(first_title_line, available_text_width);
 
-    let text_padding = effective_width.saturating_sub(adjusted_text.len() + jump_label_width);
+    let text_padding =
+        effective_width.saturating_sub(display_width(first_line_text) + jump_label_width);
 
     // Create the first line with optional jump label
     let mut first_line_spans = vec![Span::raw(" ".repeat(horizontal_padding))];
@@ -83,28 +252,35 @@ pub fn format_task_with_wrapping(
         }
     }
 
-    first_line_spans.push(Span::raw(adjusted_text));
+    first_line_spans.push(Span::raw(first_line_text.to_string()));
     first_line_spans.push(Span::raw(" ".repeat(text_padding)));
     first_line_spans.push(Span::raw(" ".repeat(horizontal_padding)));
 
     lines.push(Line::from(first_line_spans));
 
-    // If the text is too long, add additional wrapped lines.
-    if task_text.len() > max_chars_first_line {
-        let remaining_text = task_text[max_chars_first_line..].to_string();
-        let mut position = 0;
-        while position < remaining_text.len() {
-            let end_pos = std::cmp::min(position + max_chars_other_lines, remaining_text.len());
-            let line_text = remaining_text[position..end_pos].to_string();
-            let line_padding = effective_width.saturating_sub(line_text.len() + indent.len());
+    // Add the rest of the first line wrapped, then any further lines of
+    // the title, each starting on a row of its own
+    let continuations = (!remaining_text.is_empty())
+        .then_some(remaining_text)
+        .into_iter()
+        .chain(title_lines);
+    for continuation in continuations {
+        let mut remaining_text = continuation;
+        loop {
+            let (line_text, rest) = split_at_width(remaining_text, max_chars_other_lines);
+            let line_padding =
+                effective_width.saturating_sub(display_width(line_text) + indent.len());
             lines.push(Line::from(vec![
                 Span::raw(" ".repeat(horizontal_padding)),
                 Span::raw(indent),
-                Span::raw(line_text),
+                Span::raw(line_text.to_string()),
                 Span::raw(" ".repeat(line_padding)),
                 Span::raw(" ".repeat(horizontal_padding)),
             ]));
-            position = end_pos;
+            if rest.is_empty() {
+                break;
+            }
+            remaining_text = rest;
         }
     }
 
@@ -113,3 +289,510 @@ pub fn format_task_with_wrapping(
 
     Text::from(lines)
 }
+
+/// Formats a task as a single row for the compact layout: the priority dot
+/// in the task's priority color, then the title cut to fit. A multi-line
+/// title shares the row.
+pub fn format_task_compact(
+    task: &Task,
+    max_width: u16,
+    jump_label: Option<char>,
+    show_jump_labels: bool,
+    config: &Config,
+) -> Text<'static> {
+    let horizontal_padding: usize = 1;
+    let mut spans = vec![Span::raw(" ".repeat(horizontal_padding))];
+    if show_jump_labels {
+        spans.push(match jump_label {
+            Some(label) => Span::styled(
+                format!("[{}]", label),
+                Style::default()
+                    .fg(Color::Magenta)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            None => Span::raw("   "),
+        });
+    }
+    let priority_color = task_priority_color(task, config.priority_formula);
+    spans.push(Span::styled(
+        priority_dot(config),
+        Style::default().fg(priority_color),
+    ));
+    spans.push(Span::raw(" "));
+
+    let used: usize = spans.iter().map(Span::width).sum();
+    let available = (max_width as usize).saturating_sub(used + horizontal_padding);
+    let title = task.title.lines().collect::<Vec<_>>().join(" ");
+    let (fitted, rest) = split_at_width(&title, available);
+    let ellipsis = if config.ascii_only { "~" } else { "…" };
+    let fitted = if rest.is_empty() {
+        fitted.to_string()
+    } else {
+        // Make room for the ellipsis that marks the cut
+        let (shorter, _) = split_at_width(fitted, available.saturating_sub(1));
+        format!("{}{}", shorter, ellipsis)
+    };
+    let padding = available.saturating_sub(display_width(&fitted));
+    spans.push(Span::raw(fitted));
+    spans.push(Span::raw(" ".repeat(padding + horizontal_padding)));
+
+    Text::from(Line::from(spans))
+}
+
+/// Formats a task in the layout the config asks for: one row each when
+/// `compact_tasks` is on, otherwise a wrapped card
+pub fn format_task(
+    task: &Task,
+    max_width: u16,
+    jump_label: Option<char>,
+    show_jump_labels: bool,
+    config: &Config,
+) -> Text<'static> {
+    if config.compact_tasks {
+        format_task_compact(task, max_width, jump_label, show_jump_labels, config)
+    } else {
+        format_task_with_wrapping(task, max_width, jump_label, show_jump_labels, config)
+    }
+}
+
+// Everything a task's formatted text depends on apart from the config
+#[derive(Clone, Debug, PartialEq)]
+struct TaskTextKey {
+    title: String,
+    priority: Option<(u8, u8, u8)>,
+    max_width: u16,
+    jump_label: Option<char>,
+    show_jump_labels: bool,
+    compact: bool,
+    formula: PriorityFormula,
+}
+
+// A task by the board file it is on and its ID
+type TaskTextId = (Option<String>, usize);
+
+/// Formatted task text kept between frames, so tasks that haven't changed
+/// aren't wrapped again on every draw. There is one entry per board file
+/// and task ID, rebuilt whenever the title, priority, width, jump label,
+/// layout or priority formula it was made from differs. The rest of the
+/// config is fixed while the app runs, so it isn't part of the key. Tasks
+/// without an ID yet (0) are never cached, as they would share one entry.
+#[derive(Default)]
+pub struct TaskTextCache {
+    entries: RefCell<HashMap<TaskTextId, (TaskTextKey, Text<'static>)>>,
+}
+
+impl TaskTextCache {
+    /// `format_task`, reusing the last result for this task of the board at
+    /// `board_path` when nothing it depends on has changed
+    pub fn format(
+        &self,
+        board_path: Option<&str>,
+        task: &Task,
+        max_width: u16,
+        jump_label: Option<char>,
+        show_jump_labels: bool,
+        config: &Config,
+    ) -> Text<'static> {
+        let key = TaskTextKey {
+            title: task.title.clone(),
+            priority: task
+                .priority
+                .as_ref()
+                .map(|prio| (prio.impact, prio.urgency, prio.effort)),
+            max_width,
+            jump_label,
+            show_jump_labels,
+            compact: config.compact_tasks,
+            formula: config.priority_formula,
+        };
+        if task.id == 0 {
+            return format_task(task, max_width, jump_label, show_jump_labels, config);
+        }
+        let id = (board_path.map(str::to_string), task.id);
+        let mut entries = self.entries.borrow_mut();
+        if let Some((cached_key, text)) = entries.get(&id)
+            && *cached_key == key
+        {
+            return text.clone();
+        }
+        let text = format_task(task, max_width, jump_label, show_jump_labels, config);
+        entries.insert(id, (key, text.clone()));
+        text
+    }
+
+    /// Drop every entry, e.g. when another board is opened
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_paragraphs() {
+        let text = "Tracks the release work for the spring launch.\n\nAsk Sam before moving anything to Done.";
+        assert_eq!(
+            wrap_text(text, 20),
+            vec![
+                "Tracks the release",
+                "work for the spring",
+                "launch.",
+                "",
+                "Ask Sam before",
+                "moving anything to",
+                "Done.",
+            ]
+        );
+        // Words longer than a line are broken, extra spaces are dropped
+        assert_eq!(
+            wrap_text("  see   https://example.com/x  ", 10),
+            vec!["see", "https://ex", "ample.com/", "x"]
+        );
+        assert!(wrap_text("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_multi_line_title_renders_each_line() {
+        let config = Config {
+            show_priority_dot: false,
+            ..Config::default()
+        };
+        let mut task = sample_task();
+        task.title = "Release list\nTag the build and push it".to_string();
+
+        let text = format_task_with_wrapping(&task, 20, None, false, &config);
+        let rows: Vec<String> = text
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .map(|row| row.trim().to_string())
+            .collect();
+        // Both title lines start a row; the second also wraps at 16 columns
+        assert_eq!(
+            rows,
+            vec!["Release list", "Tag the build an", "d push it", ""]
+        );
+    }
+
+    #[test]
+    fn test_compact_mode_is_one_line_per_task() {
+        let config = Config {
+            compact_tasks: true,
+            ..Config::default()
+        };
+        let mut task = sample_task();
+        for title in [
+            "Short",
+            "A title far too long to fit on a single row of this column",
+            "Release list\nTag the build",
+        ] {
+            task.title = title.to_string();
+            let text = format_task(&task, 24, None, false, &config);
+            assert_eq!(text.lines.len(), 1, "{:?}", title);
+            assert_eq!(text.width(), 24);
+        }
+
+        // The dot carries the priority color
+        let text = format_task(&task, 24, None, false, &config);
+        let dot = &text.lines[0].spans[1];
+        assert_eq!(dot.content, "●");
+        assert_eq!(
+            dot.style.fg,
+            Some(task_priority_color(&task, config.priority_formula))
+        );
+        let row: String = text.lines[0]
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(row.trim_end(), " ● Release list Tag th…");
+    }
+
+    #[test]
+    fn test_task_text_cache_reuses_and_invalidates() {
+        let cache = TaskTextCache::default();
+        let config = Config::default();
+        let mut task = sample_task();
+        task.id = 7;
+        let board = Some("work.txt");
+
+        let first = cache.format(board, &task, 20, None, false, &config);
+        assert_eq!(
+            first,
+            format_task_with_wrapping(&task, 20, None, false, &config)
+        );
+        assert_eq!(cache.format(board, &task, 20, None, false, &config), first);
+        assert_eq!(cache.entries.borrow().len(), 1);
+
+        // A new title replaces the entry rather than adding another
+        task.title = "Publish the release notes".to_string();
+        let renamed = cache.format(board, &task, 20, None, false, &config);
+        assert_ne!(renamed, first);
+        assert_eq!(
+            renamed,
+            format_task_with_wrapping(&task, 20, None, false, &config)
+        );
+        assert_eq!(cache.entries.borrow().len(), 1);
+
+        // So does a change of width
+        let wide = cache.format(board, &task, 40, None, false, &config);
+        assert_eq!(
+            wide,
+            format_task_with_wrapping(&task, 40, None, false, &config)
+        );
+    }
+
+    #[test]
+    fn test_task_text_cache_keeps_boards_and_unnumbered_tasks_apart() {
+        let cache = TaskTextCache::default();
+        let config = Config::default();
+        let mut task = sample_task();
+        task.id = 3;
+        let work = cache.format(Some("work.txt"), &task, 20, None, false, &config);
+
+        // The same ID on another board is another task
+        let mut other = sample_task();
+        other.id = 3;
+        other.title = "Water the plants".to_string();
+        let home = cache.format(Some("home.txt"), &other, 20, None, false, &config);
+        assert_ne!(home, work);
+        assert_eq!(cache.entries.borrow().len(), 2);
+
+        // Tasks without an ID all share 0, so they are formatted afresh
+        other.id = 0;
+        cache.format(Some("home.txt"), &other, 20, None, false, &config);
+        assert_eq!(cache.entries.borrow().len(), 2);
+
+        cache.clear();
+        assert!(cache.entries.borrow().is_empty());
+    }
+
+    fn sample_task() -> Task {
+        Task {
+            id: 0,
+            title: "Write the release notes".to_string(),
+            description: None,
+            priority: Some(Task::default_priority()),
+            completed_at: None,
+            tags: Vec::new(),
+            due: None,
+            created: None,
+            focus_minutes: 0,
+        }
+    }
+
+    #[test]
+    fn test_effective_text_width() {
+        // Default: the full column minus padding on both sides
+        assert_eq!(effective_text_width(50, 2, 100), 46);
+        // A custom inner width uses a fraction of that
+        assert_eq!(effective_text_width(50, 2, 50), 23);
+        assert_eq!(effective_text_width(50, 2, 200), 46);
+        // Never collapses to zero
+        assert_eq!(effective_text_width(4, 2, 100), 1);
+    }
+
+    #[test]
+    fn test_narrower_text_width_wraps_earlier() {
+        let task = sample_task();
+        let full = format_task_with_wrapping(&task, 20, None, false, &Config::default());
+
+        let config = Config {
+            task_text_width: 50,
+            ..Config::default()
+        };
+        let narrow = format_task_with_wrapping(&task, 20, None, false, &config);
+
+        assert!(narrow.lines.len() > full.lines.len());
+        // Lines are still padded to the full column width
+        assert_eq!(narrow.lines[1].width(), full.lines[1].width());
+    }
+
+    #[test]
+    fn test_priority_legend_matches_color_bands() {
+        let legend = priority_legend(PriorityFormula::Normalized);
+        let labels: Vec<&str> = legend.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["High 8+", "Medium 5+", "Normal 3+", "Low", "None"]
+        );
+        let ratio = priority_legend(PriorityFormula::Ratio);
+        assert_eq!(ratio[0].0, "High 80+");
+
+        // Both ends of each band get the color the legend shows
+        let bands = [(0.8, 1.0), (0.5, 0.79), (0.3, 0.49), (-0.25, 0.29)];
+        for ((low, high), (_, color)) in bands.iter().zip(&legend) {
+            assert_eq!(get_priority_color(Some(*low)), *color);
+            assert_eq!(get_priority_color(Some(*high)), *color);
+        }
+        assert_eq!(legend[4].1, Color::DarkGray);
+    }
+
+    #[test]
+    fn test_dot_color_follows_formula_score() {
+        let mut task = sample_task();
+        // Low impact but urgent and quick: high under the ratio formula
+        task.priority = Some(Priority {
+            impact: 4,
+            urgency: 10,
+            effort: 1,
+        });
+        assert_eq!(
+            task_priority_color(&task, PriorityFormula::Ratio),
+            Color::Green
+        );
+        assert_eq!(
+            task_priority_color(&task, PriorityFormula::Weighted),
+            Color::Yellow
+        );
+
+        // Editing urgency or effort changes the color, not just impact
+        task.priority.as_mut().unwrap().urgency = 1;
+        assert_eq!(
+            task_priority_color(&task, PriorityFormula::Weighted),
+            Color::Blue
+        );
+
+        task.priority = None;
+        assert_eq!(
+            task_priority_color(&task, PriorityFormula::Normalized),
+            Color::DarkGray
+        );
+    }
+
+    #[test]
+    fn test_ascii_only_task_has_no_symbols() {
+        let task = sample_task();
+        let config = Config {
+            ascii_only: true,
+            ..Config::default()
+        };
+        let text = format_task_with_wrapping(&task, 20, Some('a'), true, &config);
+
+        for line in &text.lines {
+            assert!(line.spans.iter().all(|span| span.content.is_ascii()));
+        }
+        assert!(text.lines[0].spans.iter().any(|span| span.content == "*"));
+    }
+
+    #[test]
+    fn test_padding_uses_display_width() {
+        let mut task = sample_task();
+        task.title = "Ship 🚀".to_string();
+        let plain = format_task_with_wrapping(&sample_task(), 50, None, false, &Config::default());
+        let wide = format_task_with_wrapping(&task, 50, None, false, &Config::default());
+
+        // The rocket takes two columns, so the line ends in the same place
+        assert_eq!(wide.lines[1].width(), plain.lines[1].width());
+    }
+
+    #[test]
+    fn test_display_width_ignores_zero_width_and_control_characters() {
+        assert_eq!(display_width("Plan"), 4);
+        assert_eq!(display_width("Pl\u{200B}an"), 4);
+        assert_eq!(display_width("Pl\u{7}an\t"), 4);
+        assert_eq!(display_width("🚀"), 2);
+    }
+
+    #[test]
+    fn test_wrapping_splits_on_display_width() {
+        let mut task = sample_task();
+        task.title = "ééééé\u{200B}ééééé🚀".to_string();
+        let text = format_task_with_wrapping(&task, 10, None, false, &Config::default());
+
+        // Multi-byte characters don't get cut in half, and every line is
+        // padded to the same width
+        let content: String = text.lines[1..]
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.trim()))
+            .collect();
+        assert_eq!(content, task.title);
+        for line in &text.lines[1..text.lines.len() - 1] {
+            assert_eq!(line.width(), 10);
+        }
+    }
+
+    #[test]
+    fn test_priority_bar_spans_from_breakdown() {
+        let priority = Priority {
+            impact: 10,
+            urgency: 5,
+            effort: 0,
+        };
+        let spans = priority_bar(Some(&priority), &Config::default());
+        let cells: Vec<&str> = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(cells, vec!["█", "▄", "▁"]);
+        assert_eq!(spans[0].style.fg, Some(Color::Red));
+        assert_eq!(spans[1].style.fg, Some(Color::Yellow));
+        assert_eq!(spans[2].style.fg, Some(Color::Blue));
+
+        let ascii = Config {
+            ascii_only: true,
+            ..Config::default()
+        };
+        let spans = priority_bar(Some(&priority), &ascii);
+        let cells: Vec<&str> = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(cells, vec!["@", "+", "."]);
+
+        // Without a priority the bar keeps its width but shows nothing
+        let spans = priority_bar(None, &Config::default());
+        assert_eq!(spans.iter().map(Span::width).sum::<usize>(), 3);
+        assert_eq!(spans[0].style.fg, Some(Color::DarkGray));
+    }
+
+    #[test]
+    fn test_priority_bar_shares_line_with_dot() {
+        let task = sample_task();
+        let config = Config {
+            show_priority_bar: true,
+            ..Config::default()
+        };
+        let with_bar = format_task_with_wrapping(&task, 30, None, false, &config);
+        let plain = format_task_with_wrapping(&task, 30, None, false, &Config::default());
+
+        assert_eq!(with_bar.lines.len(), plain.lines.len());
+        assert_eq!(with_bar.lines[0].width(), plain.lines[0].width());
+        assert!(
+            with_bar.lines[0]
+                .spans
+                .iter()
+                .any(|span| span.content == "●")
+        );
+
+        // The bar alone still gets its line
+        let config = Config {
+            show_priority_bar: true,
+            show_priority_dot: false,
+            ..Config::default()
+        };
+        let bar_only = format_task_with_wrapping(&task, 30, None, false, &config);
+        assert_eq!(bar_only.lines.len(), plain.lines.len());
+    }
+
+    #[test]
+    fn test_priority_dot_line_can_be_disabled() {
+        let task = sample_task();
+        let with_dot = format_task_with_wrapping(&task, 50, None, false, &Config::default());
+
+        let config = Config {
+            show_priority_dot: false,
+            ..Config::default()
+        };
+        let without_dot = format_task_with_wrapping(&task, 50, None, false, &config);
+
+        assert_eq!(without_dot.lines.len(), with_dot.lines.len() - 1);
+        assert!(
+            !without_dot
+                .lines
+                .iter()
+                .any(|line| line.spans.iter().any(|span| span.content.contains('●')))
+        );
+    }
+}
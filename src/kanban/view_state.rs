@@ -0,0 +1,279 @@
+use crate::kanban::models::App;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Order used by the priority sort commands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortDirection {
+    /// Most important first
+    Descending,
+    /// Least important first
+    Ascending,
+}
+
+impl SortDirection {
+    pub fn toggled(self) -> SortDirection {
+        match self {
+            SortDirection::Descending => SortDirection::Ascending,
+            SortDirection::Ascending => SortDirection::Descending,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortDirection::Descending => "descending",
+            SortDirection::Ascending => "ascending",
+        }
+    }
+
+    fn parse(value: &str) -> Option<SortDirection> {
+        match value.to_lowercase().as_str() {
+            "descending" | "desc" => Some(SortDirection::Descending),
+            "ascending" | "asc" => Some(SortDirection::Ascending),
+            _ => None,
+        }
+    }
+}
+
+/// How a board is being viewed, as opposed to what is on it.
+///
+/// Kept in a `<board>.view` file next to the board so that the board file
+/// only changes when tasks do. Uses the same `key = value` lines as the
+/// config file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewState {
+    pub sort_direction: SortDirection,
+    /// Folded columns by title, each showing only tasks whose computed
+    /// priority is at least the given threshold. Saved as one
+    /// `fold = <threshold> <column>` line per column.
+    pub column_folds: BTreeMap<String, f32>,
+    /// Title of the column that was active when the board was last left
+    pub active_column: Option<String>,
+    /// ID of the task that was selected in that column
+    pub selected_task: Option<usize>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        ViewState {
+            sort_direction: SortDirection::Descending,
+            column_folds: BTreeMap::new(),
+            active_column: None,
+            selected_task: None,
+        }
+    }
+}
+
+/// The view-state file for a board, e.g. `work.txt` -> `work.view`
+pub fn view_state_path(board_path: &str) -> PathBuf {
+    Path::new(board_path).with_extension("view")
+}
+
+impl ViewState {
+    /// Read a view-state file, falling back to defaults if it is missing
+    pub fn load(path: &Path) -> ViewState {
+        match fs::read_to_string(path) {
+            Ok(contents) => ViewState::parse(&contents),
+            Err(_) => ViewState::default(),
+        }
+    }
+
+    /// Parse view-state contents on top of the defaults
+    pub fn parse(contents: &str) -> ViewState {
+        let mut state = ViewState::default();
+        for line in contents.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                state.apply(key.trim(), value.trim());
+            }
+        }
+        state
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    // Apply a single entry, ignoring anything we don't understand
+    fn apply(&mut self, key: &str, value: &str) {
+        match key {
+            "sort_direction" => {
+                self.sort_direction = SortDirection::parse(value).unwrap_or(self.sort_direction)
+            }
+            "fold" => {
+                let Some((threshold, column)) = value.split_once(' ') else {
+                    return;
+                };
+                if let Ok(threshold) = threshold.parse() {
+                    self.column_folds
+                        .insert(column.trim().to_string(), threshold);
+                }
+            }
+            "active_column" if !value.is_empty() => self.active_column = Some(value.to_string()),
+            "selected_task" => self.selected_task = value.parse().ok(),
+            _ => {}
+        }
+    }
+}
+
+impl std::fmt::Display for ViewState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "sort_direction = {}", self.sort_direction.label())?;
+        for (column, threshold) in &self.column_folds {
+            writeln!(f, "fold = {} {}", threshold, column)?;
+        }
+        if let Some(column) = &self.active_column {
+            writeln!(f, "active_column = {}", column)?;
+        }
+        if let Some(id) = self.selected_task {
+            writeln!(f, "selected_task = {}", id)?;
+        }
+        Ok(())
+    }
+}
+
+impl App {
+    /// Load the current board's view state, or the defaults if it has none
+    pub fn load_view_state(&mut self) {
+        self.view_state = match &self.file_path {
+            Some(path) => ViewState::load(&view_state_path(path)),
+            None => ViewState::default(),
+        };
+    }
+
+    /// Write the view state next to the board file. This is best effort:
+    /// losing it only costs the user their view settings.
+    pub fn save_view_state(&self) {
+        if let Some(path) = &self.file_path {
+            let _ = self.view_state.save(&view_state_path(path));
+        }
+    }
+
+    /// Remember the active column and its selected task in the view state,
+    /// so the board reopens where the user left it
+    pub fn save_selection(&mut self) {
+        if self.file_path.is_none() {
+            return;
+        }
+        self.view_state.active_column = self
+            .columns
+            .get(self.active_column)
+            .map(|column| column.title.clone());
+        self.view_state.selected_task = self.selected_task().map(|task| task.id);
+        self.save_view_state();
+    }
+
+    /// Go back to the column and task saved by `save_selection`. If that
+    /// column is gone the selection stays as loaded; if only the task is
+    /// gone the column's first task is selected.
+    pub fn restore_selection(&mut self) {
+        let Some(column_idx) = self.view_state.active_column.as_ref().and_then(|title| {
+            self.columns
+                .iter()
+                .position(|column| &column.title == title)
+        }) else {
+            return;
+        };
+        self.activate_column(column_idx);
+        let column = &mut self.columns[column_idx];
+        let saved = self
+            .view_state
+            .selected_task
+            .and_then(|id| column.tasks.iter().position(|task| task.id == id));
+        column.selected_task = match saved {
+            Some(task_idx) => Some(task_idx),
+            None if column.tasks.is_empty() => None,
+            None => Some(0),
+        };
+    }
+
+    /// Flip the direction used by the priority sort commands
+    pub fn toggle_sort_direction(&mut self) {
+        self.view_state.sort_direction = self.view_state.sort_direction.toggled();
+        self.save_view_state();
+        self.status_message = Some(format!(
+            "Sorting by priority, {}",
+            self.view_state.sort_direction.label()
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kanban::models::InputMode;
+    use std::env;
+
+    #[test]
+    fn test_view_state_round_trip() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_view_state.view");
+
+        let mut state = ViewState {
+            sort_direction: SortDirection::Ascending,
+            ..ViewState::default()
+        };
+        state.column_folds.insert("In Progress".to_string(), 3.5);
+        state.column_folds.insert("To Do".to_string(), 12.0);
+        state.active_column = Some("In Progress".to_string());
+        state.selected_task = Some(7);
+        state.save(&temp_path).unwrap();
+        assert_eq!(ViewState::load(&temp_path), state);
+
+        fs::remove_file(&temp_path).unwrap();
+        assert_eq!(ViewState::load(&temp_path), ViewState::default());
+    }
+
+    #[test]
+    fn test_selection_survives_reopening() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_selection_board.txt");
+        let file_path = temp_path.to_str().unwrap().to_string();
+
+        let mut app = App::new("Selection Board");
+        app.file_path = Some(file_path.clone());
+        app.input_mode = InputMode::Normal;
+        let mut doing = app.columns[0].clone();
+        doing.title = "Doing".to_string();
+        doing.tasks.reverse();
+        app.columns.push(doing);
+        app.assign_missing_task_ids();
+        app.save_board().unwrap();
+        app.active_column = 1;
+        app.columns[0].selected_task = None;
+        app.columns[1].selected_task = Some(1);
+        let selected_id = app.selected_task().unwrap().id;
+        app.save_selection();
+
+        let mut reopened = App::new("Selection Board");
+        reopened.file_path = Some(file_path.clone());
+        reopened.load_board().unwrap();
+        reopened.restore_selection();
+        assert_eq!(reopened.active_column, 1);
+        assert_eq!(reopened.selected_task().unwrap().id, selected_id);
+        assert_eq!(reopened.columns[0].selected_task, None);
+
+        // A saved column that no longer exists leaves the selection alone
+        reopened.view_state.active_column = Some("Gone".to_string());
+        reopened.active_column = 0;
+        reopened.restore_selection();
+        assert_eq!(reopened.active_column, 0);
+
+        let _ = fs::remove_file(crate::kanban::storage::backup_path(&file_path));
+        fs::remove_file(view_state_path(&file_path)).unwrap();
+        fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn test_parse_view_state_ignores_bad_values() {
+        assert_eq!(
+            ViewState::parse("sort_direction = sideways\nunknown = 1").sort_direction,
+            SortDirection::Descending
+        );
+        assert_eq!(
+            ViewState::parse("sort_direction = asc").sort_direction,
+            SortDirection::Ascending
+        );
+    }
+}
@@ -3,30 +3,57 @@ mod ops;
 
 use kanban::{App, run_app};
 use ratatui::crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io;
+use std::io::{self, Read};
 
 fn main() -> Result<(), io::Error> {
+    // `--stdin` shows a board piped in on standard input, read-only
+    let piped_board = if std::env::args().skip(1).any(|arg| arg == "--stdin") {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Some(ops::crud::Board::from_reader(text.as_bytes()).map_err(io::Error::other)?)
+    } else {
+        None
+    };
+
     // Check for KANBAN_DIR environment variable
-    if std::env::var("KANBAN_DIR").is_err() {
-        eprintln!("Warning: KANBAN_DIR environment variable not set.");
-        eprintln!("Changes won't be saved. Set KANBAN_DIR to enable persistence.");
-        // We continue anyway, the app will work but without persistence
+    if piped_board.is_none() {
+        match kanban::storage::kanban_dir() {
+            Err(ops::error::KanbanError::DirMissing) => {
+                eprintln!("Warning: KANBAN_DIR environment variable not set.");
+                eprintln!("Changes won't be saved. Set KANBAN_DIR to enable persistence.");
+                // We continue anyway, the app will work but without persistence
+            }
+            // Same check and message as the board picker gives
+            Err(e) => {
+                eprintln!("Warning: {}", e);
+                eprintln!("Changes won't be saved. Point KANBAN_DIR at a folder for boards.");
+            }
+            Ok(_) => {}
+        }
     }
 
     // Terminal setup
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app - it will start in board selection mode automatically
-    let app = App::new("Kanban Board");
+    let mut app = App::new("Kanban Board");
+    if let Some(board) = piped_board {
+        app.open_read_only(board);
+    }
     let res = run_app(&mut terminal, app);
 
     // Restore terminal
@@ -34,7 +61,8 @@ fn main() -> Result<(), io::Error> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
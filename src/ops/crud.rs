@@ -1,5 +1,19 @@
+use crate::ops::error::KanbanError;
+use chrono::Local;
 use std::fs;
 use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+/// The header line that every board file starts with.
+const BOARD_HEADER: &str = "# TUI Kanban Board:";
+
+/// Version of the board file format read and written by this build,
+/// saved on a `Format:` header line. Files without one are version 1.
+/// Version 2 escapes line breaks and backslashes in task titles.
+pub const FORMAT_VERSION: u32 = 2;
+
+// The first format version whose task titles are escaped
+const ESCAPED_TITLES_VERSION: u32 = 2;
 
 /// Represents the priority breakdown: Impact, Urgency, and Effort (each scored 0–10).
 #[derive(Debug, Clone, PartialEq)]
@@ -10,15 +24,73 @@ pub struct Priority {
 }
 
 impl Priority {
-    /// Computes the overall priority as (impact * urgency) / effort.
+    /// Computes the overall priority with the default formula.
     /// Returns None if effort is 0.
     pub fn computed(&self) -> Option<f32> {
-        if self.effort == 0 {
-            None
-        } else {
-            let base_score = (self.impact as f32 + self.urgency as f32) / self.effort as f32;
-            let normalized_score = (base_score - 0.2) / 19.8;
-            Some(1.0 + 9.0 * normalized_score)
+        self.computed_with(PriorityFormula::default())
+    }
+
+    /// Computes the overall priority with the given scoring formula.
+    pub fn computed_with(&self, formula: PriorityFormula) -> Option<f32> {
+        let impact = self.impact as f32;
+        let urgency = self.urgency as f32;
+        let effort = self.effort as f32;
+        match formula {
+            PriorityFormula::Normalized => {
+                if self.effort == 0 {
+                    return None;
+                }
+                let base_score = (impact + urgency) / effort;
+                let normalized_score = (base_score - 0.2) / 19.8;
+                Some(1.0 + 9.0 * normalized_score)
+            }
+            PriorityFormula::Ratio => {
+                if self.effort == 0 {
+                    return None;
+                }
+                Some(impact * urgency / effort)
+            }
+            // Impact x confidence x ease, with urgency standing in for
+            // confidence and ease the inverse of effort, scaled to 0-10
+            PriorityFormula::Ice => Some(impact * urgency * (10.0 - effort).max(0.0) / 100.0),
+            // Impact and urgency count double, effort counts against
+            PriorityFormula::Weighted => Some((2.0 * impact + 2.0 * urgency - effort) / 4.0),
+        }
+    }
+}
+
+/// How a priority breakdown is turned into a single score. Picked with
+/// `priority_formula` in the config file.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PriorityFormula {
+    /// (impact + urgency) / effort, rescaled to roughly 1-10
+    #[default]
+    Normalized,
+    /// impact * urgency / effort
+    Ratio,
+    /// impact * urgency * (10 - effort) / 100
+    Ice,
+    /// (2 * impact + 2 * urgency - effort) / 4
+    Weighted,
+}
+
+impl PriorityFormula {
+    /// The highest score the formula gives for fields of at most 10, so
+    /// scores from different formulas can be put on the same color scale
+    pub fn max_score(self) -> f32 {
+        match self {
+            PriorityFormula::Ratio => 100.0,
+            PriorityFormula::Normalized | PriorityFormula::Ice | PriorityFormula::Weighted => 10.0,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<PriorityFormula> {
+        match value.to_lowercase().as_str() {
+            "normalized" | "default" => Some(PriorityFormula::Normalized),
+            "ratio" => Some(PriorityFormula::Ratio),
+            "ice" => Some(PriorityFormula::Ice),
+            "weighted" => Some(PriorityFormula::Weighted),
+            _ => None,
         }
     }
 }
@@ -32,6 +104,12 @@ pub struct Task {
     pub priority: Option<Priority>,
     pub tags: Vec<String>,
     pub created: Option<String>,
+    /// When the task was moved into a done column, if it is in one.
+    pub completed: Option<String>,
+    /// Date the task is due, as `YYYY-MM-DD`.
+    pub due: Option<String>,
+    /// Minutes spent on the task in completed focus sessions.
+    pub focus_minutes: u32,
 }
 
 /// A column in the Kanban board (e.g., "To Do", "In Progress", "Done").
@@ -39,6 +117,8 @@ pub struct Task {
 pub struct Column {
     pub name: String,
     pub tasks: Vec<Task>,
+    /// Maximum number of tasks the column should hold, if limited.
+    pub wip_limit: Option<usize>,
 }
 
 /// A Kanban board with metadata and a set of columns.
@@ -48,6 +128,11 @@ pub struct Board {
     pub date: String,
     pub description: String,
     pub columns: Vec<Column>,
+    /// Format version the board was read in; new boards have the current one.
+    pub format_version: u32,
+    /// Formula used for the `Computed:` score written with each task. The
+    /// score is only informational and isn't read back.
+    pub priority_formula: PriorityFormula,
 }
 
 impl Board {
@@ -58,6 +143,8 @@ impl Board {
             date: date.to_string(),
             description: description.to_string(),
             columns: Vec::new(),
+            format_version: FORMAT_VERSION,
+            priority_formula: PriorityFormula::default(),
         }
     }
 
@@ -66,17 +153,14 @@ impl Board {
         self.columns.push(Column {
             name: column_name.to_string(),
             tasks: Vec::new(),
+            wip_limit: None,
         });
     }
 
     /// Adds a task to the specified column.
-    pub fn add_task(&mut self, column_name: &str, task: Task) -> Result<(), String> {
-        if let Some(column) = self.columns.iter_mut().find(|c| c.name == column_name) {
-            column.tasks.push(task);
-            Ok(())
-        } else {
-            Err(format!("Column '{}' not found", column_name))
-        }
+    pub fn add_task(&mut self, column_name: &str, task: Task) -> Result<(), KanbanError> {
+        self.column_mut(column_name)?.tasks.push(task);
+        Ok(())
     }
 
     /// Updates an existing task identified by task_id in the specified column.
@@ -85,59 +169,71 @@ impl Board {
         column_name: &str,
         task_id: usize,
         updated_task: Task,
-    ) -> Result<(), String> {
-        if let Some(column) = self.columns.iter_mut().find(|c| c.name == column_name) {
-            if let Some(task) = column.tasks.iter_mut().find(|t| t.id == task_id) {
-                *task = updated_task;
-                Ok(())
-            } else {
-                Err(format!(
-                    "Task with id {} not found in column '{}'",
+    ) -> Result<(), KanbanError> {
+        let column = self.column_mut(column_name)?;
+        let task = column
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id)
+            .ok_or_else(|| {
+                KanbanError::NotFound(format!(
+                    "Task with id {} in column '{}'",
                     task_id, column_name
                 ))
-            }
-        } else {
-            Err(format!("Column '{}' not found", column_name))
-        }
+            })?;
+        *task = updated_task;
+        Ok(())
     }
 
     /// Deletes a task identified by task_id from the specified column.
-    pub fn delete_task(&mut self, column_name: &str, task_id: usize) -> Result<(), String> {
-        if let Some(column) = self.columns.iter_mut().find(|c| c.name == column_name) {
-            let orig_len = column.tasks.len();
-            column.tasks.retain(|t| t.id != task_id);
-            if column.tasks.len() < orig_len {
-                Ok(())
-            } else {
-                Err(format!(
-                    "Task with id {} not found in column '{}'",
-                    task_id, column_name
-                ))
-            }
+    pub fn delete_task(&mut self, column_name: &str, task_id: usize) -> Result<(), KanbanError> {
+        let column = self.column_mut(column_name)?;
+        let orig_len = column.tasks.len();
+        column.tasks.retain(|t| t.id != task_id);
+        if column.tasks.len() < orig_len {
+            Ok(())
         } else {
-            Err(format!("Column '{}' not found", column_name))
+            Err(KanbanError::NotFound(format!(
+                "Task with id {} in column '{}'",
+                task_id, column_name
+            )))
         }
     }
 
+    fn column_mut(&mut self, column_name: &str) -> Result<&mut Column, KanbanError> {
+        self.columns
+            .iter_mut()
+            .find(|c| c.name == column_name)
+            .ok_or_else(|| KanbanError::NotFound(format!("Column '{}'", column_name)))
+    }
+
     /// Saves the board to a plain text file in our TUI Kanban Format.
     ///
     /// Task lines now include the breakdown:
-    /// * [ID:<id>] Title | Impact: <impact> | Urgency: <urgency> | Effort: <effort> | Computed: <computed> | Tags: tag1,tag2 | Created: <created>
-    pub fn save_to_file(&self, file_path: &str) -> io::Result<()> {
+    /// Line breaks in a title are written as `\n` (and backslashes as `\\`)
+    /// so every task stays on one line.
+    /// * [ID:<id>] Title | Impact: <impact> | Urgency: <urgency> | Effort: <effort> | Computed: <computed> | Tags: tag1,tag2 | Created: <created> | Completed: <completed> | Due: <due> | Focus: <minutes>
+    ///
+    /// `Computed` is the score under the board's `priority_formula`.
+    pub fn save_to_file(&self, file_path: &str) -> Result<(), KanbanError> {
         let mut file = fs::File::create(file_path)?;
-        writeln!(file, "# TUI Kanban Board: {}", self.name)?;
-        writeln!(file, "Date: {}", self.date)?;
-        writeln!(file, "Description: {}", self.description)?;
+        writeln!(file, "{}", header_line(BOARD_HEADER, &self.name))?;
+        writeln!(file, "{}", header_line("Date:", &self.date))?;
+        writeln!(file, "{}", header_line("Description:", &self.description))?;
+        writeln!(file, "Format: {}", FORMAT_VERSION)?;
         writeln!(file)?;
         for column in &self.columns {
             writeln!(file, "== {} ==", column.name)?;
+            if let Some(limit) = column.wip_limit {
+                writeln!(file, "WIP: {}", limit)?;
+            }
             for task in &column.tasks {
-                let mut task_line = format!("* [ID:{}] {}", task.id, task.title);
+                let mut task_line = format!("* [ID:{}] {}", task.id, escape_title(&task.title));
                 if let Some(ref prio) = task.priority {
                     task_line.push_str(&format!(" | Impact: {}", prio.impact));
                     task_line.push_str(&format!(" | Urgency: {}", prio.urgency));
                     task_line.push_str(&format!(" | Effort: {}", prio.effort));
-                    if let Some(computed) = prio.computed() {
+                    if let Some(computed) = prio.computed_with(self.priority_formula) {
                         task_line.push_str(&format!(" | Computed: {:.2}", computed));
                     }
                 }
@@ -147,6 +243,15 @@ impl Board {
                 if let Some(ref created) = task.created {
                     task_line.push_str(&format!(" | Created: {}", created));
                 }
+                if let Some(ref completed) = task.completed {
+                    task_line.push_str(&format!(" | Completed: {}", completed));
+                }
+                if let Some(ref due) = task.due {
+                    task_line.push_str(&format!(" | Due: {}", due));
+                }
+                if task.focus_minutes > 0 {
+                    task_line.push_str(&format!(" | Focus: {}", task.focus_minutes));
+                }
                 writeln!(file, "{}", task_line)?;
             }
             writeln!(file)?;
@@ -157,20 +262,29 @@ impl Board {
     /// Loads a board from a plain text file in our TUI Kanban Format.
     ///
     /// It parses the Impact, Urgency, and Effort values (ignoring any computed value).
-    pub fn load_from_file(file_path: &str) -> io::Result<Board> {
-        let file = fs::File::open(file_path)?;
-        let reader = BufReader::new(file);
+    pub fn load_from_file(file_path: &str) -> Result<Board, KanbanError> {
+        let file = fs::File::open(file_path).map_err(|err| board_file_error(err, file_path))?;
+        Board::from_reader(BufReader::new(file))
+    }
 
+    /// Parses a board in our TUI Kanban Format from any reader, such as
+    /// standard input or an in-memory string. Lines it doesn't recognize,
+    /// including task lines before the first column header, are skipped so
+    /// a stray line in a hand-edited file doesn't lock the board. Text that
+    /// isn't UTF-8 is a parse error.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Board, KanbanError> {
         let mut board = Board {
             name: String::new(),
             date: String::new(),
             description: String::new(),
             columns: Vec::new(),
+            // Files from before the Format header are version 1
+            format_version: 1,
+            priority_formula: PriorityFormula::default(),
         };
         let mut current_column: Option<Column> = None;
 
-        for line_result in reader.lines() {
-            let line = line_result?;
+        for line in normalized_lines(reader)? {
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 continue;
@@ -187,6 +301,8 @@ impl Board {
                 board.date = trimmed["Date:".len()..].trim().to_string();
             } else if trimmed.starts_with("Description:") {
                 board.description = trimmed["Description:".len()..].trim().to_string();
+            } else if let Some(value) = trimmed.strip_prefix("Format:") {
+                board.format_version = value.trim().parse().unwrap_or(board.format_version);
             } else if trimmed.starts_with("==") && trimmed.ends_with("==") {
                 if let Some(col) = current_column.take() {
                     board.columns.push(col);
@@ -195,21 +311,26 @@ impl Board {
                 current_column = Some(Column {
                     name: col_name.to_string(),
                     tasks: Vec::new(),
+                    wip_limit: None,
                 });
+            } else if let Some(limit) = trimmed.strip_prefix("WIP:") {
+                if let Some(col) = current_column.as_mut() {
+                    col.wip_limit = limit.trim().parse().ok();
+                }
             } else if trimmed.starts_with("*") {
                 let mut parts = trimmed.split('|').map(|s| s.trim());
                 let first_part = parts.next().unwrap_or("");
-                let id_start = first_part.find("[ID:").map(|i| i + 4).unwrap_or(0);
-                let id_end = first_part.find(']').unwrap_or(first_part.len());
-                let id_str = &first_part[id_start..id_end];
-                let id: usize = id_str.parse().unwrap_or(0);
-                let title = first_part[id_end + 1..].trim().to_string();
+                let (id, title) =
+                    parse_task_head(first_part, board.format_version >= ESCAPED_TITLES_VERSION);
 
                 let mut impact: Option<u8> = None;
                 let mut urgency: Option<u8> = None;
                 let mut effort: Option<u8> = None;
                 let mut tags = Vec::new();
                 let mut created = None;
+                let mut completed = None;
+                let mut due = None;
+                let mut focus_minutes = 0;
 
                 for part in parts {
                     if part.starts_with("Impact:") {
@@ -223,9 +344,16 @@ impl Board {
                             .trim()
                             .split(',')
                             .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
                             .collect();
                     } else if part.starts_with("Created:") {
                         created = Some(part["Created:".len()..].trim().to_string());
+                    } else if let Some(value) = part.strip_prefix("Completed:") {
+                        completed = Some(value.trim().to_string());
+                    } else if let Some(value) = part.strip_prefix("Due:") {
+                        due = Some(value.trim().to_string());
+                    } else if let Some(value) = part.strip_prefix("Focus:") {
+                        focus_minutes = value.trim().parse().unwrap_or(0);
                     }
                     // Note: We ignore the "Computed:" field since it’s derived.
                 }
@@ -247,8 +375,12 @@ impl Board {
                     priority,
                     tags,
                     created,
+                    completed,
+                    due,
+                    focus_minutes,
                 };
 
+                // A task before the first column has nowhere to go
                 if let Some(col) = current_column.as_mut() {
                     col.tasks.push(task);
                 }
@@ -257,35 +389,142 @@ impl Board {
         if let Some(col) = current_column.take() {
             board.columns.push(col);
         }
+        // Files written by hand may leave the date out; treat the board as
+        // dated today so the next save writes a complete header
+        if board.date.is_empty() {
+            board.date = Local::now().format("%Y-%m-%d").to_string();
+        }
         Ok(board)
     }
 }
 
+// One header line: the label and the value on a single line, with no
+// trailing space when the value is empty. Line breaks in the value would
+// start a line the parser doesn't expect, so they become spaces.
+fn header_line(label: &str, value: &str) -> String {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if value.is_empty() {
+        label.to_string()
+    } else {
+        format!("{} {}", label, value)
+    }
+}
+
+/// Reads every line with line endings normalized. `lines`
+/// already drops the `\r` of a Windows `\r\n`; any carriage return left
+/// over (old Mac files, or a doubled `\r\r\n`) is treated as a line break
+/// too, so none can end up inside a parsed value. A line that isn't UTF-8
+/// is a parse error naming its (1-based) line number.
+fn normalized_lines<R: BufRead>(reader: R) -> Result<Vec<String>, KanbanError> {
+    let mut lines = Vec::new();
+    for (line_idx, line_result) in reader.lines().enumerate() {
+        let line = line_result.map_err(|err| match err.kind() {
+            io::ErrorKind::InvalidData => KanbanError::Parse {
+                line: line_idx + 1,
+                message: "not valid UTF-8 text".to_string(),
+            },
+            _ => KanbanError::Io(err),
+        })?;
+        lines.extend(line.split('\r').map(str::to_string));
+    }
+    Ok(lines)
+}
+
+/// Splits the first segment of a task line (`* [ID:<id>] Title`) into its id
+/// and title. `[ID:n]` only counts as an id when it is a well-formed prefix;
+/// otherwise the whole segment is the title and the id is 0. Titles are
+/// unescaped only when `escaped`, as older files wrote backslashes as-is.
+fn parse_task_head(first_part: &str, escaped: bool) -> (usize, String) {
+    let rest = first_part.strip_prefix('*').unwrap_or(first_part).trim();
+    let parsed = rest
+        .strip_prefix("[ID:")
+        .and_then(|after| after.split_once(']'))
+        .and_then(|(id_str, title)| Some((id_str.trim().parse().ok()?, title.trim())));
+    let (id, title) = parsed.unwrap_or((0, rest));
+    let title = if escaped {
+        unescape_title(title)
+    } else {
+        title.to_string()
+    };
+    (id, title)
+}
+
+// Titles may span several lines; keep each task on one line of the file
+// by writing line breaks as `\n` and backslashes as `\\`
+pub fn escape_title(title: &str) -> String {
+    title.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+// Undo `escape_title`. A backslash before anything else is kept as-is.
+// Only for text written by `escape_title`: a title from a version 1 file
+// such as `C:\new` would come back split over two lines.
+pub fn unescape_title(title: &str) -> String {
+    let mut result = String::with_capacity(title.len());
+    let mut chars = title.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.peek() {
+            Some('n') => {
+                chars.next();
+                result.push('\n');
+            }
+            Some('\\') => {
+                chars.next();
+                result.push('\\');
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Returns true if the file at `path` starts with the TUI Kanban Board header.
+pub fn is_board_file(path: &Path) -> bool {
+    let Ok(file) = fs::File::open(path) else {
+        return false;
+    };
+    let mut first_line = String::new();
+    if BufReader::new(file).read_line(&mut first_line).is_err() {
+        return false;
+    }
+    first_line.trim_start().starts_with(BOARD_HEADER)
+}
+
 /// Board-level CRUD functions using file storage.
-pub fn create_board(file_path: &str, board: &Board) -> io::Result<()> {
+pub fn create_board(file_path: &str, board: &Board) -> Result<(), KanbanError> {
     board.save_to_file(file_path)
 }
 
 /// Reads a board from a file.
-pub fn read_board(file_path: &str) -> io::Result<Board> {
+pub fn read_board(file_path: &str) -> Result<Board, KanbanError> {
     Board::load_from_file(file_path)
 }
 
 /// Updates a board by writing its current state to the file.
-pub fn update_board(file_path: &str, board: &Board) -> io::Result<()> {
+pub fn update_board(file_path: &str, board: &Board) -> Result<(), KanbanError> {
     board.save_to_file(file_path)
 }
 
 /// Deletes the board file.
-pub fn delete_board(file_path: &str) -> io::Result<()> {
-    fs::remove_file(file_path)
+pub fn delete_board(file_path: &str) -> Result<(), KanbanError> {
+    fs::remove_file(file_path).map_err(|err| board_file_error(err, file_path))
+}
+
+// A missing board file is NotFound for every board operation, not an Io error
+fn board_file_error(err: io::Error, file_path: &str) -> KanbanError {
+    match err.kind() {
+        io::ErrorKind::NotFound => KanbanError::NotFound(format!("Board file '{}'", file_path)),
+        _ => KanbanError::Io(err),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::env;
-    use std::path::Path;
 
     #[test]
     fn test_board_creation_and_add_column() {
@@ -313,6 +552,9 @@ mod tests {
             }),
             tags: vec!["tag1".to_string()],
             created: Some("2025-03-23".to_string()),
+            completed: None,
+            due: None,
+            focus_minutes: 0,
         };
 
         // Test adding a task.
@@ -331,6 +573,9 @@ mod tests {
             }),
             tags: vec!["tag1".to_string(), "tag2".to_string()],
             created: Some("2025-03-24".to_string()),
+            completed: None,
+            due: None,
+            focus_minutes: 0,
         };
         board.update_task("To Do", 1, updated_task.clone()).unwrap();
         assert_eq!(board.columns[0].tasks[0].title, "Updated task");
@@ -359,6 +604,82 @@ mod tests {
         assert!(priority_zero.computed().is_none());
     }
 
+    #[test]
+    fn test_priority_formulas() {
+        let priority = Priority {
+            impact: 8,
+            urgency: 6,
+            effort: 2,
+        };
+        let score = |formula| priority.computed_with(formula).unwrap();
+
+        assert_eq!(
+            score(PriorityFormula::Normalized),
+            1.0 + 9.0 * (7.0 - 0.2) / 19.8
+        );
+        assert_eq!(score(PriorityFormula::Ratio), 24.0);
+        assert_eq!(score(PriorityFormula::Ice), 3.84);
+        assert_eq!(score(PriorityFormula::Weighted), 6.5);
+        assert_eq!(
+            priority.computed(),
+            priority.computed_with(PriorityFormula::Normalized)
+        );
+
+        // Only the formulas that divide by effort need it to be non-zero
+        let no_effort = Priority {
+            impact: 5,
+            urgency: 4,
+            effort: 0,
+        };
+        assert!(no_effort.computed_with(PriorityFormula::Ratio).is_none());
+        assert_eq!(no_effort.computed_with(PriorityFormula::Ice), Some(2.0));
+        assert_eq!(
+            no_effort.computed_with(PriorityFormula::Weighted),
+            Some(4.5)
+        );
+
+        assert_eq!(PriorityFormula::parse("ICE"), Some(PriorityFormula::Ice));
+        assert_eq!(PriorityFormula::parse("rice"), None);
+    }
+
+    #[test]
+    fn test_multi_line_title_round_trip() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_multi_line_title.txt");
+        let file_path = temp_path.to_str().unwrap();
+
+        let title = "Release checklist\nTag v1.2 in C:\\builds";
+        let mut board = Board::new("Lines", "2025-03-24", "Multi-line titles");
+        board.add_column("To Do");
+        board
+            .add_task(
+                "To Do",
+                Task {
+                    id: 1,
+                    title: title.to_string(),
+                    priority: None,
+                    tags: Vec::new(),
+                    created: None,
+                    completed: None,
+                    due: None,
+                    focus_minutes: 0,
+                },
+            )
+            .unwrap();
+        board.save_to_file(file_path).unwrap();
+
+        // The task is still a single line in the file
+        let contents = fs::read_to_string(file_path).unwrap();
+        assert!(contents.contains("* [ID:1] Release checklist\\nTag v1.2 in C:\\\\builds"));
+        let loaded = Board::load_from_file(file_path).unwrap();
+        assert_eq!(loaded.columns[0].tasks[0].title, title);
+
+        // A lone backslash reads back unchanged
+        assert_eq!(unescape_title("a\\b"), "a\\b");
+
+        fs::remove_file(file_path).unwrap();
+    }
+
     #[test]
     fn test_save_and_load_board() {
         // Create a temporary file path in the system's temporary directory.
@@ -373,6 +694,7 @@ mod tests {
         );
         board.add_column("To Do");
         board.add_column("Done");
+        board.columns[0].wip_limit = Some(3);
 
         let task1 = Task {
             id: 1,
@@ -384,6 +706,9 @@ mod tests {
             }),
             tags: vec!["test".to_string()],
             created: Some("2025-03-23".to_string()),
+            completed: None,
+            due: None,
+            focus_minutes: 0,
         };
 
         let task2 = Task {
@@ -392,6 +717,9 @@ mod tests {
             priority: None,
             tags: vec![],
             created: None,
+            completed: Some("2025-03-24 17:30".to_string()),
+            due: Some("2025-04-01".to_string()),
+            focus_minutes: 75,
         };
 
         board.add_task("To Do", task1.clone()).unwrap();
@@ -409,11 +737,169 @@ mod tests {
         assert_eq!(loaded_board.columns[0].tasks[0].title, "Task 1");
         assert_eq!(loaded_board.columns[1].tasks.len(), 1);
         assert_eq!(loaded_board.columns[1].tasks[0].title, "Task 2");
+        assert_eq!(loaded_board.columns[0].wip_limit, Some(3));
+        assert_eq!(loaded_board.columns[0].tasks[0].completed, None);
+        assert_eq!(
+            loaded_board.columns[1].tasks[0].completed.as_deref(),
+            Some("2025-03-24 17:30")
+        );
+        assert_eq!(loaded_board.columns[1].wip_limit, None);
+        assert_eq!(loaded_board.columns[0].tasks[0].due, None);
+        assert_eq!(loaded_board.columns[0].tasks[0].focus_minutes, 0);
+        assert_eq!(loaded_board.columns[1].tasks[0].focus_minutes, 75);
+        assert_eq!(
+            loaded_board.columns[1].tasks[0].due.as_deref(),
+            Some("2025-04-01")
+        );
+
+        // The score is written with the board's formula
+        let contents = fs::read_to_string(file_path).unwrap();
+        assert!(contents.contains("| Computed: 1.82 |"));
+        board.priority_formula = PriorityFormula::Ratio;
+        board.save_to_file(file_path).unwrap();
+        let contents = fs::read_to_string(file_path).unwrap();
+        assert!(contents.contains("| Computed: 5.00 |"));
 
         // Clean up: delete the test file.
         fs::remove_file(file_path).unwrap();
     }
 
+    #[test]
+    fn test_board_from_reader() {
+        let text = "# TUI Kanban Board: Piped\nDate: 2025-03-24\nDescription: From stdin\n\n\
+                    == To Do ==\n\
+                    * [ID:1] Read the docs | Impact: 5 | Urgency: 5 | Effort: 3\n\n\
+                    == Done ==\n\
+                    * [ID:2] Set up | Tags: infra\n";
+
+        let board = Board::from_reader(text.as_bytes()).unwrap();
+
+        assert_eq!(board.name, "Piped");
+        assert_eq!(board.columns.len(), 2);
+        assert_eq!(board.columns[0].tasks[0].title, "Read the docs");
+        assert_eq!(
+            board.columns[0].tasks[0].priority.as_ref().unwrap().effort,
+            3
+        );
+        assert_eq!(board.columns[1].tasks[0].id, 2);
+        assert_eq!(board.columns[1].tasks[0].tags, vec!["infra"]);
+    }
+
+    #[test]
+    fn test_legacy_titles_keep_their_backslashes() {
+        // Written before titles were escaped, so there is no Format line
+        let text = "# TUI Kanban Board: Old\nDate: 2025-03-24\n\n\
+                    == To Do ==\n\
+                    * [ID:1] Fix C:\\new and C:\\\\share\n";
+        let board = Board::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(
+            board.columns[0].tasks[0].title,
+            "Fix C:\\new and C:\\\\share"
+        );
+
+        // Saved again, the title is escaped and survives the round trip
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_legacy_titles.txt");
+        let file_path = temp_path.to_str().unwrap();
+        board.save_to_file(file_path).unwrap();
+        let reloaded = Board::load_from_file(file_path).unwrap();
+        assert_eq!(
+            reloaded.columns[0].tasks[0].title,
+            board.columns[0].tasks[0].title
+        );
+
+        fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_board_without_date_or_description_headers() {
+        let text = "== To Do ==\n* [ID:1] Hand-written task\n";
+        let board = Board::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(board.date, Local::now().format("%Y-%m-%d").to_string());
+        assert_eq!(board.description, "");
+        assert_eq!(board.columns[0].tasks[0].title, "Hand-written task");
+
+        let mut temp_path = env::temp_dir();
+        temp_path.push("kantui_headerless_board.txt");
+        let file_path = temp_path.to_str().unwrap();
+        board.save_to_file(file_path).unwrap();
+
+        let saved = fs::read_to_string(file_path).unwrap();
+        let header: Vec<&str> = saved.lines().take(5).collect();
+        assert_eq!(
+            header,
+            vec![
+                BOARD_HEADER,
+                format!("Date: {}", board.date).as_str(),
+                "Description:",
+                "Format: 2",
+                "",
+            ]
+        );
+        // Saving again doesn't change anything
+        let reloaded = Board::load_from_file(file_path).unwrap();
+        assert_eq!(board.format_version, 1);
+        assert_eq!(
+            reloaded,
+            Board {
+                format_version: FORMAT_VERSION,
+                ..board
+            }
+        );
+        reloaded.save_to_file(file_path).unwrap();
+        assert_eq!(fs::read_to_string(file_path).unwrap(), saved);
+
+        fs::remove_file(file_path).unwrap();
+    }
+
+    #[test]
+    fn test_board_with_windows_line_endings() {
+        let text = "# TUI Kanban Board: Windows\r\nDate: 2025-03-24\r\nDescription: CRLF\r\n\r\n\
+                    == To Do ==\r\n\
+                    WIP: 3\r\n\
+                    * [ID:1] Port it | Tags: win,crlf\r\r\n\
+                    * [ID:2] Old Mac | Created: 2025-03-20\r* [ID:3] Next | Due: 2025-04-01\r\n";
+
+        let board = Board::from_reader(text.as_bytes()).unwrap();
+
+        assert_eq!(board.name, "Windows");
+        assert_eq!(board.date, "2025-03-24");
+        assert_eq!(board.description, "CRLF");
+        assert_eq!(board.columns[0].name, "To Do");
+        assert_eq!(board.columns[0].wip_limit, Some(3));
+        let tasks = &board.columns[0].tasks;
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[0].tags, vec!["win", "crlf"]);
+        assert_eq!(tasks[1].title, "Old Mac");
+        assert_eq!(tasks[1].created.as_deref(), Some("2025-03-20"));
+        assert_eq!(tasks[2].due.as_deref(), Some("2025-04-01"));
+        for task in tasks {
+            assert!(!task.title.contains('\r'));
+            assert!(task.tags.iter().all(|tag| !tag.contains('\r')));
+        }
+    }
+
+    #[test]
+    fn test_parse_task_head() {
+        assert_eq!(
+            parse_task_head("* [ID:42] Ship it", true),
+            (42, "Ship it".to_string())
+        );
+        assert_eq!(
+            parse_task_head("* [ID:7] [ID: not a number]", true),
+            (7, "[ID: not a number]".to_string())
+        );
+        // Malformed ids leave the whole segment as the title
+        assert_eq!(
+            parse_task_head("* [ID: not a number]", true),
+            (0, "[ID: not a number]".to_string())
+        );
+        assert_eq!(
+            parse_task_head("* ]weird title", true),
+            (0, "]weird title".to_string())
+        );
+    }
+
     #[test]
     fn test_board_crud_functions() {
         let mut temp_path = env::temp_dir();
@@ -440,4 +926,65 @@ mod tests {
         delete_board(file_path).unwrap();
         assert!(!Path::new(file_path).exists());
     }
+
+    #[test]
+    fn test_missing_board_file_is_not_found() {
+        let mut temp_path = env::temp_dir();
+        temp_path.push("test_board_missing.txt");
+        let file_path = temp_path.to_str().unwrap();
+
+        assert!(matches!(
+            read_board(file_path),
+            Err(KanbanError::NotFound(_))
+        ));
+        assert!(matches!(
+            delete_board(file_path),
+            Err(KanbanError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_task_outside_column_is_skipped() {
+        let text = "# TUI Kanban Board: Stray
+Date: 2025-03-24
+
+* [ID:1] Orphan
+== To Do ==
+* [ID:2] Kept
+";
+
+        let board = Board::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(board.columns.len(), 1);
+        let titles: Vec<&str> = board.columns[0]
+            .tasks
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["Kept"]);
+    }
+
+    #[test]
+    fn test_non_utf8_board_is_parse_error() {
+        let bytes: &[u8] = b"# TUI Kanban Board: Broken\n== To Do ==\n* \xff\xfe\n";
+
+        match Board::from_reader(bytes) {
+            Err(KanbanError::Parse { line, .. }) => assert_eq!(line, 3),
+            other => panic!("expected a parse error, got {:?}", other.map(|b| b.name)),
+        }
+    }
+
+    #[test]
+    fn test_missing_column_or_task_is_not_found() {
+        let mut board = Board::new("Test Board", "2025-03-24", "Test description");
+        board.add_column("To Do");
+
+        assert!(matches!(
+            board.delete_task("Doing", 1),
+            Err(KanbanError::NotFound(_))
+        ));
+        assert!(matches!(
+            board.delete_task("To Do", 1),
+            Err(KanbanError::NotFound(_))
+        ));
+    }
 }
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Everything that can go wrong loading, saving or editing boards.
+#[derive(Debug)]
+pub enum KanbanError {
+    /// A board file, column or task that doesn't exist
+    NotFound(String),
+    /// A board file line that couldn't be understood (1-based line number)
+    Parse { line: usize, message: String },
+    /// Any other failure reading or writing files
+    Io(io::Error),
+    /// KANBAN_DIR is not set, so there is nowhere to keep boards
+    DirMissing,
+    /// KANBAN_DIR names a file (or other non-directory), with its path
+    NotADirectory(String),
+    /// A name that is already taken
+    Collision(String),
+    /// The board has no file behind it, so it can't be loaded or saved
+    NoFilePath,
+    /// The board is a read-only view and can't be saved
+    ReadOnly,
+    /// A board name that can't be used, with the reason why
+    InvalidName(String),
+    /// A move into a column that is already at its WIP limit
+    WipLimit { column: String, limit: usize },
+}
+
+impl fmt::Display for KanbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KanbanError::NotFound(what) => write!(f, "{} not found", what),
+            KanbanError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            KanbanError::Io(err) => write!(f, "{}", err),
+            KanbanError::DirMissing => write!(f, "KANBAN_DIR environment variable not set"),
+            KanbanError::NotADirectory(path) => {
+                write!(f, "KANBAN_DIR is not a directory: {}", path)
+            }
+            KanbanError::Collision(what) => write!(f, "{} already exists", what),
+            KanbanError::NoFilePath => write!(f, "No file path set"),
+            KanbanError::ReadOnly => write!(f, "Board is read-only"),
+            KanbanError::InvalidName(reason) => write!(f, "Invalid board name: {}", reason),
+            KanbanError::WipLimit { column, limit } => {
+                write!(f, "{} is at its WIP limit of {}", column, limit)
+            }
+        }
+    }
+}
+
+impl Error for KanbanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KanbanError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for KanbanError {
+    fn from(err: io::Error) -> Self {
+        KanbanError::Io(err)
+    }
+}
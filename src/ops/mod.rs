@@ -1 +1,2 @@
 pub mod crud;
+pub mod error;